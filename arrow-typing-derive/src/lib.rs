@@ -0,0 +1,441 @@
+//! Derive macros for [`arrow-typing`](https://docs.rs/arrow-typing)
+//!
+//! This crate should not be used directly, but through the re-exports of
+//! `arrow-typing`, which also contain the documentation of the derive macros.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parenthesized, parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error,
+    Fields, Ident, LitInt, LitStr, Result,
+};
+
+/// Maximal number of fields of a struct element, or of variants of an enum
+/// element, which is the maximal arity of the tuples that are used to store them
+const MAX_FIELDS: usize = 12;
+
+/// Implement `ArrayElement` for a struct with named fields or an enum
+///
+/// See the documentation of `arrow_typing::types::structs` and
+/// `arrow_typing::types::union` for more information.
+#[proc_macro_derive(ArrayElement, attributes(array_element))]
+pub fn derive_array_element(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = if input.generics.params.is_empty() && input.generics.where_clause.is_none() {
+        match &input.data {
+            Data::Struct(_) => derive_struct(&input),
+            Data::Enum(_) => derive_enum(&input),
+            Data::Union(_) => Err(Error::new(
+                input.ident.span(),
+                "ArrayElement can only be derived for structs and enums",
+            )),
+        }
+    } else {
+        Err(Error::new(
+            input.generics.span(),
+            "ArrayElement cannot be derived for generic types",
+        ))
+    };
+    result.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Arrow-specific properties of a struct field or enum variant
+struct FieldAttributes {
+    /// Arrow field name
+    name: String,
+
+    /// Arrow field metadata
+    metadata: Vec<(LitStr, LitStr)>,
+
+    /// Union type id, for enum variants
+    type_id: Option<LitInt>,
+}
+//
+impl FieldAttributes {
+    /// Parse the `#[array_element(...)]` attributes of a struct field, or of an
+    /// enum variant if `is_variant` is true
+    fn parse(ident: &Ident, attrs: &[Attribute], is_variant: bool) -> Result<Self> {
+        let mut result = Self {
+            name: ident.to_string().trim_start_matches("r#").to_owned(),
+            metadata: Vec::new(),
+            type_id: None,
+        };
+        for attr in attrs {
+            if !attr.path().is_ident("array_element") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    result.name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("metadata") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    while !content.is_empty() {
+                        let key = content.parse::<LitStr>()?;
+                        content.parse::<syn::Token![=]>()?;
+                        let value = content.parse::<LitStr>()?;
+                        result.metadata.push((key, value));
+                        if !content.is_empty() {
+                            content.parse::<syn::Token![,]>()?;
+                        }
+                    }
+                    Ok(())
+                } else if is_variant && meta.path.is_ident("type_id") {
+                    result.type_id = Some(meta.value()?.parse::<LitInt>()?);
+                    Ok(())
+                } else if is_variant {
+                    Err(meta.error(
+                        "expected `rename = \"...\"`, `metadata(\"key\" = \"value\", ...)` \
+                         or `type_id = ...`",
+                    ))
+                } else {
+                    Err(meta.error(
+                        "expected `rename = \"...\"` or `metadata(\"key\" = \"value\", ...)`",
+                    ))
+                }
+            })?;
+        }
+        Ok(result)
+    }
+
+    /// Arrow field metadata, as a `&[(&str, &str)]` expression
+    fn metadata(&self) -> TokenStream2 {
+        let (keys, values): (Vec<_>, Vec<_>) = self.metadata.iter().cloned().unzip();
+        quote!(&[#((#keys, #values)),*])
+    }
+}
+
+/// Check that a struct or enum has an acceptable number of fields or variants
+fn check_len(ident: &Ident, len: usize, what: &str) -> Result<()> {
+    if len == 0 || len > MAX_FIELDS {
+        return Err(Error::new(
+            ident.span(),
+            format!(
+                "ArrayElement can only be derived for {what}s with 1 to {MAX_FIELDS} \
+                 {what_items}, but this {what} has {len}",
+                what_items = if what == "struct" {
+                    "fields"
+                } else {
+                    "variants"
+                }
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Implement `ArrayElement` for a struct, via `StructElement`
+fn derive_struct(input: &DeriveInput) -> Result<TokenStream2> {
+    // Check that the input is supported
+    let Data::Struct(data) = &input.data else {
+        unreachable!("derive_struct should only be called on structs")
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            input.ident.span(),
+            "ArrayElement can only be derived for structs with named fields",
+        ));
+    };
+    let fields = &fields.named;
+    check_len(&input.ident, fields.len(), "struct")?;
+
+    // Collect field properties
+    let attributes = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("fields should be named");
+            FieldAttributes::parse(ident, &field.attrs, false)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let idents = fields
+        .iter()
+        .map(|field| field.ident.as_ref().expect("fields should be named"))
+        .collect::<Vec<_>>();
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let visibilities = fields.iter().map(|field| &field.vis).collect::<Vec<_>>();
+    let indices = (0..fields.len()).map(syn::Index::from).collect::<Vec<_>>();
+    let names = attributes.iter().map(|attr| &attr.name);
+    let metadata = attributes.iter().map(FieldAttributes::metadata);
+
+    // Generate the conversions between fields and field values, spanned so that
+    // fields of an unsupported type are pointed at in compiler errors
+    let as_values = idents.iter().zip(&types).map(|(ident, ty)| {
+        quote_spanned! {ty.span()=>
+            <#ty as ::arrow_typing::types::structs::StructField>::as_value(&self.#ident)
+        }
+    });
+    let from_values = indices.iter().zip(&types).map(|(index, ty)| {
+        quote_spanned! {ty.span()=>
+            <#ty as ::arrow_typing::types::structs::StructField>::from_array_value(fields.#index)
+        }
+    });
+
+    // Generate the columnar slice type and the StructElement implementation
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let slice_ident = format_ident!("{ident}Slice");
+    let slice_doc = format!("Columnar slice of [`{ident}`]s, with one slice per field");
+    Ok(quote! {
+        #[doc = #slice_doc]
+        #[derive(Clone, Debug)]
+        #vis struct #slice_ident<'a> {
+            #(
+                #visibilities #idents: <#types as ::arrow_typing::ArrayElement>::Slice<'a>,
+            )*
+        }
+        //
+        impl<'a> ::arrow_typing::ElementSlice for #slice_ident<'a> {
+            fn slice_len(&self) -> ::core::result::Result<usize, ::arrow_typing::__private::ArrowError> {
+                ::arrow_typing::ElementSlice::slice_len(
+                    &<#ident as ::arrow_typing::types::structs::StructElement>::slice_fields(
+                        ::core::clone::Clone::clone(self),
+                    ),
+                )
+            }
+
+            fn slice_range(self, range: ::core::ops::Range<usize>) -> Self {
+                Self {
+                    #(
+                        #idents: ::arrow_typing::ElementSlice::slice_range(
+                            self.#idents,
+                            ::core::clone::Clone::clone(&range),
+                        ),
+                    )*
+                }
+            }
+        }
+        //
+        impl ::arrow_typing::types::structs::StructElement for #ident {
+            type Fields = (#(#types,)*);
+            type Slice<'a> = #slice_ident<'a>;
+            const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+            const FIELD_METADATA: &'static [&'static [(&'static str, &'static str)]] =
+                &[#(#metadata),*];
+
+            fn as_fields(&self) -> <Self::Fields as ::arrow_typing::ArrayElement>::Value<'_> {
+                (#(#as_values,)*)
+            }
+
+            fn from_fields(fields: ::arrow_typing::types::structs::FieldValues<'_, Self>) -> Self {
+                Self {
+                    #(#idents: #from_values,)*
+                }
+            }
+
+            fn slice_fields(slice: Self::Slice<'_>) -> <Self::Fields as ::arrow_typing::ArrayElement>::Slice<'_> {
+                (#(slice.#idents,)*)
+            }
+        }
+        //
+        // Structs are written and read by value when nested in other structs,
+        // which requires them to be cloneable. The higher-ranked bound defers
+        // this requirement to the point where the struct actually gets nested.
+        // Options of structs are covered by a blanket implementation instead,
+        // as the orphan rule does not allow implementing them here.
+        impl ::arrow_typing::types::structs::StructField for #ident
+        where
+            for<'a> #ident: ::core::clone::Clone,
+        {
+            #[inline]
+            fn as_value(&self) -> Self {
+                ::core::clone::Clone::clone(self)
+            }
+
+            #[inline]
+            fn from_array_value(value: Self) -> Self {
+                value
+            }
+        }
+    })
+}
+
+/// Implement `ArrayElement` for an enum, via `UnionElement`
+fn derive_enum(input: &DeriveInput) -> Result<TokenStream2> {
+    // Check that the input is supported
+    let Data::Enum(data) = &input.data else {
+        unreachable!("derive_enum should only be called on enums")
+    };
+    let variants = &data.variants;
+    check_len(&input.ident, variants.len(), "enum")?;
+    let mut dense = true;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("array_element") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("dense") {
+                dense = true;
+                Ok(())
+            } else if meta.path.is_ident("sparse") {
+                dense = false;
+                Ok(())
+            } else {
+                Err(meta.error("expected `dense` or `sparse`"))
+            }
+        })?;
+    }
+
+    // Collect variant properties
+    let attributes = variants
+        .iter()
+        .map(|variant| FieldAttributes::parse(&variant.ident, &variant.attrs, true))
+        .collect::<Result<Vec<_>>>()?;
+    let mut type_ids = Vec::with_capacity(variants.len());
+    for (index, (variant, attr)) in variants.iter().zip(&attributes).enumerate() {
+        let type_id = match &attr.type_id {
+            Some(lit) => lit
+                .base10_parse::<i8>()
+                .ok()
+                .filter(|&id| id >= 0)
+                .ok_or_else(|| {
+                    Error::new(lit.span(), "union type ids must be between 0 and 127")
+                })?,
+            None => index as i8,
+        };
+        if type_ids.contains(&type_id) {
+            return Err(Error::new(
+                variant.ident.span(),
+                format!("union type id {type_id} is used by several variants"),
+            ));
+        }
+        type_ids.push(type_id);
+    }
+    let mut types = Vec::with_capacity(variants.len());
+    let mut patterns = Vec::with_capacity(variants.len());
+    let mut values = Vec::with_capacity(variants.len());
+    for variant in variants {
+        let ident = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => {
+                types.push(quote!(::arrow_typing::types::primitive::Null));
+                patterns.push(quote!(Self::#ident));
+                values.push(quote!(::arrow_typing::types::primitive::Null));
+            }
+            Fields::Unnamed(fields) => {
+                let bindings = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("field{index}"))
+                    .collect::<Vec<_>>();
+                let field_types = fields.unnamed.iter().map(|field| &field.ty);
+                patterns.push(quote!(Self::#ident(#(#bindings),*)));
+                if let [binding] = &bindings[..] {
+                    types.push(quote!(#(#field_types)*));
+                    values.push(quote!(#binding));
+                } else {
+                    types.push(quote!((#(#field_types,)*)));
+                    values.push(quote!((#(#bindings,)*)));
+                }
+            }
+            Fields::Named(_) => {
+                return Err(Error::new(
+                    ident.span(),
+                    "ArrayElement cannot be derived for enums with named variant fields, \
+                     consider using a tuple variant that holds a struct instead",
+                ))
+            }
+        }
+    }
+    let names = attributes.iter().map(|attr| &attr.name);
+    let metadata = attributes.iter().map(FieldAttributes::metadata);
+    let mode = if dense {
+        quote!(::arrow_typing::__private::UnionMode::Dense)
+    } else {
+        quote!(::arrow_typing::__private::UnionMode::Sparse)
+    };
+
+    // Generate the conversions between enums and variant values, which are
+    // tuples with one option per variant
+    let num_variants = variants.len();
+    let into_variant = patterns
+        .iter()
+        .zip(&values)
+        .enumerate()
+        .map(|(index, (pattern, value))| {
+            let options = (0..num_variants).map(|other| {
+                if other == index {
+                    quote!(::core::option::Option::Some(#value))
+                } else {
+                    quote!(::core::option::Option::None)
+                }
+            });
+            quote!(#pattern => (#(#options,)*))
+        });
+    let from_variant = patterns
+        .iter()
+        .zip(&values)
+        .enumerate()
+        .map(|(index, (pattern, value))| {
+            let skipped = (0..index).map(|_| quote!(_));
+            quote!((#(#skipped,)* ::core::option::Option::Some(#value), ..) => #pattern)
+        });
+
+    // Generate the ArrayElement and UnionElement implementations
+    let ident = &input.ident;
+    Ok(quote! {
+        // SAFETY: Enums are not primitive types and are therefore not affected
+        //         by the safety precondition of ArrayElement
+        unsafe impl ::arrow_typing::ArrayElement for #ident {
+            type BuilderBackend = ::arrow_typing::types::union::UnionBackend<Self>;
+            type Value<'a> = Self;
+            type Slice<'a> = &'a [Self];
+            type PushResult = ::core::result::Result<(), ::arrow_typing::__private::ArrowError>;
+            type ExtendFromSliceResult =
+                ::core::result::Result<(), ::arrow_typing::__private::ArrowError>;
+            const NULLABLE: bool = false;
+        }
+        //
+        impl ::arrow_typing::types::union::UnionElement for #ident {
+            type Variants = (#(#types,)*);
+            const VARIANT_NAMES: &'static [&'static str] = &[#(#names),*];
+            const VARIANT_METADATA: &'static [&'static [(&'static str, &'static str)]] =
+                &[#(#metadata),*];
+            const TYPE_IDS: &'static [i8] = &[#(#type_ids),*];
+            const MODE: ::arrow_typing::__private::UnionMode = #mode;
+
+            fn into_variant<'a>(
+                self,
+            ) -> <Self::Variants as ::arrow_typing::types::union::UnionVariants>::Values<'a> {
+                match self {
+                    #(#into_variant,)*
+                }
+            }
+
+            fn from_value(value: <Self as ::arrow_typing::ArrayElement>::Value<'_>) -> Self {
+                value
+            }
+
+            fn from_value_ref<'b>(
+                value: &'b <Self as ::arrow_typing::ArrayElement>::Value<'_>,
+            ) -> &'b Self {
+                value
+            }
+
+            fn slice_values(slice: <Self as ::arrow_typing::ArrayElement>::Slice<'_>) -> &[Self] {
+                slice
+            }
+
+            fn from_variant(
+                variant: <Self::Variants as ::arrow_typing::types::union::UnionVariants>::ArrayValues<'_>,
+            ) -> Self {
+                #[allow(unreachable_patterns)]
+                match variant {
+                    #(#from_variant,)*
+                    _ => ::core::unreachable!("union values should have exactly one variant"),
+                }
+            }
+        }
+        //
+        impl ::arrow_typing::types::structs::StructField for #ident {
+            #[inline]
+            fn as_value(&self) -> Self {
+                ::core::clone::Clone::clone(self)
+            }
+
+            #[inline]
+            fn from_array_value(value: Self) -> Self {
+                value
+            }
+        }
+    })
+}
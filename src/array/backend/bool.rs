@@ -0,0 +1,110 @@
+//! Strong typing layer on top of [`BooleanArray`]
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{array::OptionArraySlice, validity::ValiditySlice};
+use arrow_array::{Array, BooleanArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+impl TypedBackend<bool> for BooleanArray {
+    type Value<'a> = bool;
+
+    /// Arrow stores booleans as a bitmap, which can be read out using the
+    /// same bit-packed view as validity bitmaps
+    type Slice<'a> = ValiditySlice<'a>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> bool {
+        self.value_unchecked(index)
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> ValiditySlice<'_> {
+        ValiditySlice::from_buffer(self.values()).slice(range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<bool>(self)
+    }
+}
+
+impl TypedBackend<Option<bool>> for BooleanArray {
+    type Value<'a> = Option<bool>;
+    type Slice<'a> = OptionArraySlice<'a, bool>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<bool> {
+        self.is_valid(index)
+            .then(|| unsafe { self.value_unchecked(index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, bool> {
+        OptionArraySlice {
+            values: TypedBackend::<bool>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        TypedArray, TypedBuilder,
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<bool>) {
+            let mut builder = TypedBuilder::<bool>::new();
+            builder.extend_from_slice(&values);
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+            prop_assert_eq!(array.as_slice(), &values[..]);
+        }
+
+        #[test]
+        fn read_options(values: Vec<Option<bool>>) {
+            let mut builder = TypedBuilder::<Option<bool>>::new();
+            builder.extend(values.iter().copied());
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+            let slice = array.as_slice();
+            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            for (value, expected) in slice.values.iter().zip(&values) {
+                if let Some(expected) = expected {
+                    prop_assert_eq!(value, *expected);
+                }
+            }
+        }
+
+        #[test]
+        fn try_from((values, offset, len) in vec_and_subslice::<Option<bool>>()) {
+            let mut builder = TypedBuilder::<Option<bool>>::new();
+            builder.extend(values.iter().copied());
+            let array = builder.finish().into_array_ref().slice(offset, len);
+            let values = &values[offset..offset + len];
+
+            let options = TypedArray::<Option<bool>>::try_from(array.clone())?;
+            check_values(&options, values)?;
+
+            let result = TypedArray::<bool>::try_from(array);
+            if values.iter().all(Option::is_some) {
+                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                let array = result?;
+                check_values(&array, &values)?;
+                prop_assert_eq!(array.as_slice(), &values[..]);
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+    }
+}
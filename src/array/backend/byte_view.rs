@@ -0,0 +1,165 @@
+//! Strong typing layer on top of [`GenericByteViewArray`]
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::OptionArraySlice,
+    types::bytes::{ByteViewSlice, ViewNativeType, ViewType},
+    ArrayElement,
+};
+use arrow_array::{types::ByteViewType, Array, GenericByteViewArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+impl<T: ViewType + ?Sized> TypedBackend<T> for GenericByteViewArray<T::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on ViewType to let rustc figure out that
+    //        T::Value<'a> is just &'a ViewNativeType<T> for view types.
+    for<'a> &'a ViewNativeType<T>: Into<T::Value<'a>>,
+{
+    type Value<'a> = T::Value<'a>;
+    type Slice<'a> = ByteViewSlice<'a, T::Arrow>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> T::Value<'_> {
+        self.value_unchecked(index).into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> ByteViewSlice<'_, T::Arrow> {
+        // SAFETY: The views and buffers of a GenericByteViewArray are validated
+        //         upon construction, see ByteViewType::validate()
+        unsafe { ByteViewSlice::new_unchecked(&self.views()[range], self.data_buffers()) }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<T>(self)?;
+        T::Arrow::validate(self.views(), self.data_buffers())
+    }
+}
+
+impl<T: ViewType> TypedBackend<Option<T>> for GenericByteViewArray<T::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on ViewType (see above)
+    for<'a> &'a ViewNativeType<T>: Into<T::Value<'a>>,
+    //
+    // FIXME: Remove these bounds once it becomes possible to blanket-impl
+    //        ArrayElement for Option<T: ViewType>, making them obvious.
+    Option<T>: ArrayElement,
+    for<'a> Option<T::Value<'a>>: Into<<Option<T> as ArrayElement>::Value<'a>>,
+{
+    type Value<'a> = <Option<T> as ArrayElement>::Value<'a>;
+    type Slice<'a> = OptionArraySlice<'a, T>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> <Option<T> as ArrayElement>::Value<'_> {
+        let opt: Option<T::Value<'_>> = self
+            .is_valid(index)
+            .then(|| unsafe { self.value_unchecked(index) }.into());
+        opt.into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, T> {
+        OptionArraySlice {
+            values: TypedBackend::<T>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        T::Arrow::validate(self.views(), self.data_buffers())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::bytes::{tests::TestByteArrayType, BinaryView, ByteViewTypeExt, Utf8View},
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::types::{BinaryViewType, ByteViewType, StringViewType};
+    use proptest::prelude::*;
+    use std::borrow::Borrow;
+
+    macro_rules! test_view_elements {
+        ($($mod_name:ident : $element:ty => $arrow:ty),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Owned = <<$arrow as ByteViewTypeExt>::ByteArray as TestByteArrayType>::Owned;
+                    type Native = <$arrow as ByteViewType>::Native;
+
+                    proptest! {
+                        #[test]
+                        fn read_values(values: Vec<Owned>) {
+                            let values = values.iter().map(Borrow::borrow).collect::<Vec<&Native>>();
+                            let mut builder = TypedBuilder::<$element>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), 0);
+                            let slice = array.as_slice();
+                            prop_assert_eq!(slice.len(), values.len());
+                            prop_assert_eq!(slice.iter().collect::<Vec<_>>(), values);
+                        }
+
+                        #[test]
+                        fn read_options(values: Vec<Option<Owned>>) {
+                            let values = values
+                                .iter()
+                                .map(|opt| opt.as_ref().map(Borrow::borrow))
+                                .collect::<Vec<Option<&Native>>>();
+                            let mut builder = TypedBuilder::<Option<$element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+                            let slice = array.as_slice();
+                            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+                            check_option_slice(&slice, &is_valid)?;
+                            prop_assert_eq!(slice.values.len(), values.len());
+                            for (value, expected) in slice.values.iter().zip(&values) {
+                                if let Some(expected) = expected {
+                                    prop_assert_eq!(value, *expected);
+                                }
+                            }
+                        }
+
+                        #[test]
+                        fn try_from((values, offset, len) in vec_and_subslice::<Option<Owned>>()) {
+                            let values = values
+                                .iter()
+                                .map(|opt| opt.as_ref().map(Borrow::borrow))
+                                .collect::<Vec<Option<&Native>>>();
+                            let mut builder = TypedBuilder::<Option<$element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish().into_array_ref().slice(offset, len);
+                            let values = &values[offset..offset + len];
+
+                            let options = TypedArray::<Option<$element>>::try_from(array.clone())?;
+                            check_values(&options, values)?;
+
+                            let result = TypedArray::<$element>::try_from(array);
+                            if values.iter().all(Option::is_some) {
+                                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                                let array = result?;
+                                check_values(&array, &values)?;
+                                prop_assert!(array.as_slice().iter().eq(values));
+                            } else {
+                                prop_assert!(result.is_err());
+                            }
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_view_elements!(
+        utf8_view: Utf8View => StringViewType,
+        binary_view: BinaryView => BinaryViewType
+    );
+}
@@ -0,0 +1,173 @@
+//! Strong typing layer on top of [`GenericByteArray`]
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::OptionArraySlice,
+    types::{
+        bytes::{ByteArrayTypeExt, ByteSlice, ByteType, NativeType},
+        slice_offsets,
+    },
+    ArrayElement,
+};
+use arrow_array::{types::ByteArrayType, Array, GenericByteArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+impl<T: ByteType + ?Sized> TypedBackend<T> for GenericByteArray<T::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on ByteType to let rustc figure out that
+    //        T::Value<'a> is just &'a NativeType<T> for byte types.
+    for<'a> &'a NativeType<T>: Into<T::Value<'a>>,
+{
+    type Value<'a> = T::Value<'a>;
+    type Slice<'a> = ByteSlice<'a, T::Arrow>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> T::Value<'_> {
+        self.value_unchecked(index).into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> ByteSlice<'_, T::Arrow> {
+        ByteSlice {
+            offsets: slice_offsets(self.value_offsets(), range),
+            // SAFETY: The data buffer of a GenericByteArray is validated as a
+            //         whole upon construction, see ByteArrayType::validate()
+            values: unsafe { T::Arrow::from_bytes_unchecked(self.value_data()) },
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<T>(self)?;
+        T::Arrow::validate(self.offsets(), self.values())
+    }
+}
+
+impl<T: ByteType> TypedBackend<Option<T>> for GenericByteArray<T::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on ByteType (see above)
+    for<'a> &'a NativeType<T>: Into<T::Value<'a>>,
+    //
+    // FIXME: Remove these bounds once it becomes possible to blanket-impl
+    //        ArrayElement for Option<T: ByteType>, making them obvious.
+    Option<T>: ArrayElement,
+    for<'a> Option<T::Value<'a>>: Into<<Option<T> as ArrayElement>::Value<'a>>,
+{
+    type Value<'a> = <Option<T> as ArrayElement>::Value<'a>;
+    type Slice<'a> = OptionArraySlice<'a, T>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> <Option<T> as ArrayElement>::Value<'_> {
+        let opt: Option<T::Value<'_>> = self
+            .is_valid(index)
+            .then(|| unsafe { self.value_unchecked(index) }.into());
+        opt.into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, T> {
+        OptionArraySlice {
+            values: TypedBackend::<T>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        T::Arrow::validate(self.offsets(), self.values())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::bytes::{tests::TestByteArrayType, LargeBinary, LargeUtf8},
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::types::{BinaryType, ByteArrayType, LargeBinaryType, LargeUtf8Type, Utf8Type};
+    use proptest::prelude::*;
+    use std::borrow::Borrow;
+
+    macro_rules! test_byte_elements {
+        ($($mod_name:ident : $element:ty, $value_element:ty => $arrow:ty),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Owned = <$arrow as TestByteArrayType>::Owned;
+                    type Native = <$arrow as ByteArrayType>::Native;
+
+                    proptest! {
+                        #[test]
+                        fn read_values(values: Vec<Owned>) {
+                            let values = values.iter().map(Borrow::borrow).collect::<Vec<&Native>>();
+                            let mut builder = TypedBuilder::<$value_element>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), 0);
+                            let slice = array.as_slice();
+                            prop_assert!(slice.validate().is_ok());
+                            prop_assert_eq!(slice.iter().collect::<Vec<_>>(), values);
+                        }
+
+                        #[test]
+                        fn read_options(values: Vec<Option<Owned>>) {
+                            let values = values
+                                .iter()
+                                .map(|opt| opt.as_ref().map(Borrow::borrow))
+                                .collect::<Vec<Option<&Native>>>();
+                            let mut builder = TypedBuilder::<Option<$element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+                            let slice = array.as_slice();
+                            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+                            check_option_slice(&slice, &is_valid)?;
+                            prop_assert_eq!(slice.values.len(), values.len());
+                            for (value, expected) in slice.values.iter().zip(&values) {
+                                if let Some(expected) = expected {
+                                    prop_assert_eq!(value, *expected);
+                                }
+                            }
+                        }
+
+                        #[test]
+                        fn try_from((values, offset, len) in vec_and_subslice::<Option<Owned>>()) {
+                            let values = values
+                                .iter()
+                                .map(|opt| opt.as_ref().map(Borrow::borrow))
+                                .collect::<Vec<Option<&Native>>>();
+                            let mut builder = TypedBuilder::<Option<$element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish().into_array_ref().slice(offset, len);
+                            let values = &values[offset..offset + len];
+
+                            let options = TypedArray::<Option<$element>>::try_from(array.clone())?;
+                            check_values(&options, values)?;
+
+                            let result = TypedArray::<$value_element>::try_from(array);
+                            if values.iter().all(Option::is_some) {
+                                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                                let array = result?;
+                                check_values(&array, &values)?;
+                                prop_assert!(array.as_slice().iter().eq(values));
+                            } else {
+                                prop_assert!(result.is_err());
+                            }
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_byte_elements!(
+        string: String, str => Utf8Type,
+        binary: Vec<u8>, [u8] => BinaryType,
+        large_utf8: LargeUtf8, LargeUtf8 => LargeUtf8Type,
+        large_binary: LargeBinary, LargeBinary => LargeBinaryType
+    );
+}
@@ -0,0 +1,135 @@
+//! Strong typing layer on top of decimal [`PrimitiveArray`]s
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::OptionArraySlice,
+    types::decimal::{Decimal128, Decimal256},
+};
+use arrow_array::{
+    types::{Decimal128Type, Decimal256Type},
+    Array, PrimitiveArray,
+};
+use arrow_buffer::i256;
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+macro_rules! impl_typed_backend {
+    ($($element:ty => $arrow:ty, $native:ty);*) => {
+        $(
+            impl TypedBackend<$element> for PrimitiveArray<$arrow> {
+                type Value<'a> = $native;
+                type Slice<'a> = &'a [$native];
+
+                #[inline]
+                unsafe fn value_unchecked(&self, index: usize) -> $native {
+                    unsafe { self.value_unchecked(index) }
+                }
+
+                fn as_subslice(&self, range: Range<usize>) -> &[$native] {
+                    &self.values()[range]
+                }
+
+                fn validate(&self) -> Result<(), ArrowError> {
+                    validate_non_null::<$element>(self)
+                }
+            }
+
+            impl TypedBackend<Option<$element>> for PrimitiveArray<$arrow> {
+                type Value<'a> = Option<$native>;
+                type Slice<'a> = OptionArraySlice<'a, $element>;
+
+                #[inline]
+                unsafe fn value_unchecked(&self, index: usize) -> Option<$native> {
+                    self.is_valid(index)
+                        .then(|| unsafe { self.value_unchecked(index) })
+                }
+
+                fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, $element> {
+                    OptionArraySlice {
+                        values: TypedBackend::<$element>::as_subslice(self, range.clone()),
+                        is_valid: validity_subslice(self, range),
+                    }
+                }
+
+                fn validate(&self) -> Result<(), ArrowError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+//
+impl_typed_backend!(
+    Decimal128 => Decimal128Type, i128;
+    Decimal256 => Decimal256Type, i256
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        builder::BuilderConfig,
+        tests::vec_and_subslice,
+        types::decimal::{Decimal128, Decimal256, DecimalConfig},
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::{Array, ArrayRef, Decimal128Array};
+    use arrow_buffer::i256;
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<i64>) {
+            let values = values.into_iter().map(i128::from).collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<Decimal128>::new();
+            builder.extend_from_slice(&values)?;
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+            prop_assert_eq!(array.as_slice(), &values[..]);
+        }
+
+        #[test]
+        fn read_options(values: Vec<Option<i64>>) {
+            let values = values
+                .into_iter()
+                .map(|v| v.map(i256::from))
+                .collect::<Vec<_>>();
+            let config = DecimalConfig::<Decimal256>::new(20, 4)?;
+            let mut builder =
+                TypedBuilder::<Option<Decimal256>>::with_config(BuilderConfig::with_backend(
+                    None, config,
+                ));
+            for &value in &values {
+                builder.push(value)?;
+            }
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&array.as_slice(), &is_valid)?;
+        }
+
+        #[test]
+        fn try_from((values, offset, len) in vec_and_subslice::<Option<i128>>()) {
+            let array = Decimal128Array::from(values.clone())
+                .with_precision_and_scale(38, 0)?;
+            let array: ArrayRef = Arc::new(array);
+            let array = array.slice(offset, len);
+            let values = &values[offset..offset + len];
+
+            prop_assert!(TypedArray::<Option<Decimal256>>::try_from(array.clone()).is_err());
+            let options = TypedArray::<Option<Decimal128>>::try_from(array.clone())?;
+            check_values(&options, values)?;
+
+            let result = TypedArray::<Decimal128>::try_from(array);
+            if values.iter().all(Option::is_some) {
+                let values = values.iter().flatten().copied().collect::<Vec<_>>();
+                check_values(&result?, &values)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Strong typing layer on top of [`DictionaryArray`]
+//!
+//! Dictionary arrays are read out as decoded values, and in bulk as the keys of
+//! the array along with the dictionary values.
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::{ArrayBackend, ArrayValue, OptionArraySlice},
+    types::dictionary::{Dictionary, DictionaryArraySlice, DictionaryKey, DictionaryValue},
+};
+use arrow_array::{Array, DictionaryArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Dictionary values of a dictionary array, if they have the expected type
+fn try_dictionary_values<K: DictionaryKey, V: DictionaryValue + ?Sized>(
+    array: &DictionaryArray<K::Arrow>,
+) -> Result<&ArrayBackend<V>, ArrowError>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    let values = array.values();
+    values
+        .as_any()
+        .downcast_ref::<ArrayBackend<V>>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "dictionary values of data type {} cannot be read as {}",
+                values.data_type(),
+                std::any::type_name::<V>()
+            ))
+        })
+}
+
+/// Dictionary values of a dictionary array that has been validated
+fn dictionary_values<K: DictionaryKey, V: DictionaryValue + ?Sized>(
+    array: &DictionaryArray<K::Arrow>,
+) -> &ArrayBackend<V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    try_dictionary_values::<K, V>(array).expect("dictionary values were checked by validate()")
+}
+
+/// Decoded value of the `index`-th element of a dictionary array, without
+/// bounds checking
+///
+/// # Safety
+///
+/// `index` must be smaller than the array's length, and the `index`-th element
+/// of the array must not be null.
+#[inline]
+unsafe fn decode_unchecked<K: DictionaryKey, V: DictionaryValue + ?Sized>(
+    array: &DictionaryArray<K::Arrow>,
+    index: usize,
+) -> ArrayValue<'_, V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    // SAFETY: Per the caller's guarantees, index is in bounds and the key at
+    //         this index is valid. Arrow guarantees that valid keys of a
+    //         dictionary array point to an existing dictionary value.
+    unsafe {
+        let key = array.keys().values().get_unchecked(index).as_usize();
+        dictionary_values::<K, V>(array).value_unchecked(key)
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> TypedBackend<Dictionary<K, V>>
+    for DictionaryArray<K::Arrow>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Value<'a> = ArrayValue<'a, V>;
+    type Slice<'a> = DictionaryArraySlice<'a, K, V>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> ArrayValue<'_, V> {
+        unsafe { decode_unchecked::<K, V>(self, index) }
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> DictionaryArraySlice<'_, K, V> {
+        DictionaryArraySlice::new(
+            &self.keys().values()[range],
+            dictionary_values::<K, V>(self),
+        )
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<Dictionary<K, V>>(self)?;
+        TypedBackend::<Option<Dictionary<K, V>>>::validate(self)
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> TypedBackend<Option<Dictionary<K, V>>>
+    for DictionaryArray<K::Arrow>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Value<'a> = Option<ArrayValue<'a, V>>;
+    type Slice<'a> = OptionArraySlice<'a, Dictionary<K, V>>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<ArrayValue<'_, V>> {
+        self.is_valid(index)
+            .then(|| unsafe { decode_unchecked::<K, V>(self, index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, Dictionary<K, V>> {
+        OptionArraySlice {
+            values: TypedBackend::<Dictionary<K, V>>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        try_dictionary_values::<K, V>(self)?.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::dictionary::Dictionary,
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::{
+        builder::StringDictionaryBuilder, types::Int8Type, ArrayRef, DictionaryArray, Int8Array,
+        StringArray,
+    };
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    /// Generate strings that are often repeated
+    fn repeated_strings() -> impl Strategy<Value = Vec<Option<String>>> {
+        any::<Vec<Option<u8>>>().prop_map(|values| {
+            values
+                .into_iter()
+                .map(|v| v.map(|v| format!("#{}", v % 8)))
+                .collect()
+        })
+    }
+
+    /// Build an array of optional dictionary-encoded strings
+    fn build_options(strings: &[Option<String>]) -> TypedArray<Option<Dictionary<i8, String>>> {
+        let mut builder = TypedBuilder::<Option<Dictionary<i8, String>>>::new();
+        for string in strings {
+            builder.push(string.as_deref()).unwrap();
+        }
+        builder.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<u8>) {
+            let values = values.into_iter().map(|v| v % 8).collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<Dictionary<u16, u8>>::new();
+            builder.extend_from_slice(&values)?;
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.len(), values.len());
+            prop_assert_eq!(slice.is_empty(), values.is_empty());
+            prop_assert!(slice.iter().eq(values.iter().copied()));
+            prop_assert!(slice.iter().rev().eq(values.iter().rev().copied()));
+            prop_assert_eq!(slice.get(values.len()), None);
+            let dictionary = slice.values();
+            for (&key, &value) in slice.keys().iter().zip(&values) {
+                prop_assert_eq!(dictionary[usize::from(key)], value);
+            }
+            let mut distinct = dictionary.to_vec();
+            distinct.sort_unstable();
+            distinct.dedup();
+            prop_assert_eq!(distinct.len(), dictionary.len());
+        }
+
+        #[test]
+        fn read_options(strings in repeated_strings()) {
+            let array = build_options(&strings);
+            check_values(&array, &strings)?;
+            prop_assert_eq!(array.null_count(), strings.iter().filter(|s| s.is_none()).count());
+            let slice = array.as_slice();
+            let is_valid = strings.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            prop_assert_eq!(slice.values.len(), strings.len());
+            for (index, string) in strings.iter().enumerate() {
+                if let Some(string) = string {
+                    prop_assert_eq!(slice.values.get(index), Some(string.as_str()));
+                }
+            }
+        }
+
+        #[test]
+        fn read_arrow(strings in repeated_strings()) {
+            let mut builder = StringDictionaryBuilder::<Int8Type>::new();
+            builder.extend(strings.iter().map(Option::as_deref));
+            let array: ArrayRef = Arc::new(builder.finish());
+            prop_assert!(TypedArray::<Option<Dictionary<i16, String>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<Dictionary<i8, Vec<u8>>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<String>>::try_from(array.clone()).is_err());
+            prop_assert_eq!(
+                TypedArray::<Dictionary<i8, str>>::try_from(array.clone()).is_ok(),
+                strings.iter().all(Option::is_some)
+            );
+            let array = TypedArray::<Option<Dictionary<i8, str>>>::try_from(array)?;
+            check_values(&array, &strings)?;
+        }
+
+        #[test]
+        fn try_from((strings, offset, len) in vec_and_subslice::<Option<u8>>()) {
+            let strings = strings
+                .into_iter()
+                .map(|v| v.map(|v| format!("#{}", v % 8)))
+                .collect::<Vec<_>>();
+            let array = build_options(&strings).into_array_ref().slice(offset, len);
+            let strings = &strings[offset..offset + len];
+
+            let options = TypedArray::<Option<Dictionary<i8, String>>>::try_from(array.clone())?;
+            check_values(&options, strings)?;
+            prop_assert_eq!(options.as_slice().values.keys().len(), len);
+
+            let result = TypedArray::<Dictionary<i8, String>>::try_from(array);
+            if strings.iter().all(Option::is_some) {
+                let strings = strings.iter().flatten().collect::<Vec<_>>();
+                check_values(&result?, &strings)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn null_dictionary_values() {
+        let keys = Int8Array::from(vec![0, 1, 0]);
+        let values = Arc::new(StringArray::from(vec![Some("a"), None]));
+        let array: ArrayRef = Arc::new(DictionaryArray::new(keys, values));
+        assert!(TypedArray::<Dictionary<i8, String>>::try_from(array.clone()).is_err());
+        assert!(TypedArray::<Option<Dictionary<i8, String>>>::try_from(array).is_err());
+    }
+}
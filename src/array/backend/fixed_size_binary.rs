@@ -0,0 +1,307 @@
+//! Strong typing layer on top of [`FixedSizeBinaryArray`]
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::OptionArraySlice,
+    types::fixed_size_binary::{FixedSizeBinary, FixedSizeBinarySlice},
+    ElementSlice,
+};
+use arrow_array::{Array, FixedSizeBinaryArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Check that a fixed-size binary array has the byte width of `[u8; N]`
+fn validate_byte_width<const N: usize>(array: &FixedSizeBinaryArray) -> Result<(), ArrowError> {
+    match usize::try_from(array.value_length()) {
+        Ok(width) if width == N => Ok(()),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "an array of [u8; {N}] cannot have a byte width of {}",
+            array.value_length()
+        ))),
+    }
+}
+
+impl<const N: usize> TypedBackend<[u8; N]> for FixedSizeBinaryArray {
+    type Value<'a> = [u8; N];
+    type Slice<'a> = &'a [[u8; N]];
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> [u8; N] {
+        // SAFETY: The caller guarantees that index is in bounds, and the byte
+        //         width of the array was checked to be N during validation
+        unsafe { *self.value_data().as_ptr().cast::<[u8; N]>().add(index) }
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> &[[u8; N]] {
+        let (values, _trailing_bytes) = self.value_data().as_chunks::<N>();
+        &values[..self.len()][range]
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_byte_width::<N>(self)?;
+        validate_non_null::<[u8; N]>(self)
+    }
+}
+
+impl<const N: usize> TypedBackend<Option<[u8; N]>> for FixedSizeBinaryArray {
+    type Value<'a> = Option<[u8; N]>;
+    type Slice<'a> = OptionArraySlice<'a, [u8; N]>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<[u8; N]> {
+        self.is_valid(index)
+            .then(|| unsafe { TypedBackend::<[u8; N]>::value_unchecked(self, index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, [u8; N]> {
+        OptionArraySlice {
+            values: TypedBackend::<[u8; N]>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_byte_width::<N>(self)
+    }
+}
+
+/// Check that a fixed-size binary array has a nonzero byte width
+fn validate_nonzero_byte_width(array: &FixedSizeBinaryArray) -> Result<(), ArrowError> {
+    if array.value_length() > 0 {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "an array of FixedSizeBinary cannot have a byte width of {}",
+            array.value_length()
+        )))
+    }
+}
+
+impl TypedBackend<FixedSizeBinary> for FixedSizeBinaryArray {
+    type Value<'a> = &'a [u8];
+    type Slice<'a> = FixedSizeBinarySlice<'a>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> &[u8] {
+        self.value_unchecked(index)
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> FixedSizeBinarySlice<'_> {
+        // The byte width of the array was checked to be nonzero during
+        // validation, and the value buffer is truncated to a whole number of
+        // values since arrow-rs tolerates trailing bytes.
+        let byte_width = self.value_length() as usize;
+        let values = &self.value_data()[..self.len() * byte_width];
+        FixedSizeBinarySlice::new_unchecked(byte_width, values).slice_range(range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_nonzero_byte_width(self)?;
+        validate_non_null::<FixedSizeBinary>(self)
+    }
+}
+
+impl TypedBackend<Option<FixedSizeBinary>> for FixedSizeBinaryArray {
+    type Value<'a> = Option<&'a [u8]>;
+    type Slice<'a> = OptionArraySlice<'a, FixedSizeBinary>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<&[u8]> {
+        self.is_valid(index)
+            .then(|| unsafe { self.value_unchecked(index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, FixedSizeBinary> {
+        OptionArraySlice {
+            values: TypedBackend::<FixedSizeBinary>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_nonzero_byte_width(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        builder::BuilderConfig,
+        tests::vec_and_subslice,
+        types::fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig, FixedSizeBinarySlice},
+        TypedArray, TypedBuilder,
+    };
+    use proptest::prelude::*;
+
+    macro_rules! test_byte_arrays {
+        ($($mod_name:ident : $width:literal),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Element = [u8; $width];
+
+                    proptest! {
+                        #[test]
+                        fn read_values(values: Vec<Element>) {
+                            let mut builder = TypedBuilder::<Element>::new();
+                            builder.extend_from_slice(&values);
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), 0);
+                            prop_assert_eq!(array.as_slice(), &values[..]);
+                        }
+
+                        #[test]
+                        fn read_options(values: Vec<Option<Element>>) {
+                            let mut builder = TypedBuilder::<Option<Element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+                            let slice = array.as_slice();
+                            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+                            check_option_slice(&slice, &is_valid)?;
+                            prop_assert_eq!(slice.values.len(), values.len());
+                            for (value, expected) in slice.values.iter().zip(&values) {
+                                if let Some(expected) = expected {
+                                    prop_assert_eq!(value, expected);
+                                }
+                            }
+                        }
+
+                        #[test]
+                        fn try_from((values, offset, len) in vec_and_subslice::<Option<Element>>()) {
+                            let mut builder = TypedBuilder::<Option<Element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish().into_array_ref().slice(offset, len);
+                            let values = &values[offset..offset + len];
+
+                            let options = TypedArray::<Option<Element>>::try_from(array.clone())?;
+                            check_values(&options, values)?;
+                            prop_assert!(TypedArray::<Option<[u8; 2]>>::try_from(array.clone()).is_err());
+
+                            let result = TypedArray::<Element>::try_from(array);
+                            if values.iter().all(Option::is_some) {
+                                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                                let array = result?;
+                                check_values(&array, &values)?;
+                                prop_assert_eq!(array.as_slice(), &values[..]);
+                            } else {
+                                prop_assert!(result.is_err());
+                            }
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_byte_arrays!(width1: 1, width16: 16, width32: 32);
+
+    /// Generate a byte width and a vector of values of that width
+    fn width_and_values<T: Arbitrary>(
+        make_value: impl Fn(Vec<u8>) -> T + Copy + 'static,
+    ) -> impl Strategy<Value = (usize, Vec<T>)> {
+        (1..=8usize).prop_flat_map(move |byte_width| {
+            (
+                Just(byte_width),
+                prop::collection::vec(
+                    prop::collection::vec(any::<u8>(), byte_width).prop_map(make_value),
+                    0..=32,
+                ),
+            )
+        })
+    }
+
+    /// Build an array of optional runtime-sized values
+    fn build_options(
+        byte_width: usize,
+        values: &[Option<Vec<u8>>],
+    ) -> TypedArray<Option<FixedSizeBinary>> {
+        let config = FixedSizeBinaryConfig::new(byte_width).unwrap();
+        let mut builder = TypedBuilder::<Option<FixedSizeBinary>>::with_config(
+            BuilderConfig::with_backend(None, config),
+        );
+        for value in values {
+            builder.push(value.as_deref()).unwrap();
+        }
+        builder.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn read_runtime_values((byte_width, values) in width_and_values(|v| v)) {
+            let config = FixedSizeBinaryConfig::new(byte_width).unwrap();
+            let mut builder = TypedBuilder::<FixedSizeBinary>::with_config(
+                BuilderConfig::with_backend(None, config),
+            );
+            let bytes = values.concat();
+            builder.extend_from_slice(FixedSizeBinarySlice::new(byte_width, &bytes)?)?;
+            let array = builder.finish();
+            let values = values.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.byte_width(), byte_width);
+            prop_assert_eq!(slice.len(), values.len());
+            prop_assert!(slice.iter().eq(values.iter().copied()));
+        }
+
+        #[test]
+        fn read_runtime_options((byte_width, values) in width_and_values(Some)) {
+            let array = build_options(byte_width, &values);
+            let values = values.iter().map(Option::as_deref).collect::<Vec<_>>();
+            check_values(&array, &values)?;
+            let slice = array.as_slice();
+            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            prop_assert_eq!(slice.values.len(), values.len());
+        }
+
+        #[test]
+        fn try_from_runtime(
+            (byte_width, values) in width_and_values(|v| v),
+            validity: Vec<bool>,
+            (offset, len) in (0..=32usize).prop_flat_map(|offset| (Just(offset), 0..=32usize)),
+        ) {
+            let values = values
+                .into_iter()
+                .enumerate()
+                .map(|(idx, value)| validity.get(idx).copied().unwrap_or(true).then_some(value))
+                .collect::<Vec<_>>();
+            let offset = offset.min(values.len());
+            let len = len.min(values.len() - offset);
+            let array = build_options(byte_width, &values)
+                .into_array_ref()
+                .slice(offset, len);
+            let values = values[offset..offset + len]
+                .iter()
+                .map(Option::as_deref)
+                .collect::<Vec<_>>();
+
+            let options = TypedArray::<Option<FixedSizeBinary>>::try_from(array.clone())?;
+            check_values(&options, &values)?;
+
+            let result = TypedArray::<FixedSizeBinary>::try_from(array);
+            if values.iter().all(Option::is_some) {
+                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                let array = result?;
+                check_values(&array, &values)?;
+                prop_assert!(array.as_slice().iter().eq(values));
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+
+        #[test]
+        fn read_arrays_as_runtime(values: Vec<[u8; 4]>) {
+            let mut builder = TypedBuilder::<[u8; 4]>::new();
+            builder.extend_from_slice(&values);
+            let array = builder.finish().into_array_ref();
+            let runtime = TypedArray::<FixedSizeBinary>::try_from(array)?;
+            prop_assert_eq!(runtime.as_slice().values(), values.as_flattened());
+        }
+    }
+}
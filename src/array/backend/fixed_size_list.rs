@@ -0,0 +1,462 @@
+//! Strong typing layer on top of [`FixedSizeListArray`]
+
+use super::{validate_masked_child, validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::{ArrayBackend, ArraySlice, OptionArraySlice},
+    types::{
+        fixed_size_list::{FixedSizeList, FixedSizeListArraySlice, FixedSizeListItem},
+        primitive::{NativeType, PrimitiveType},
+    },
+    ArrayElement,
+};
+use arrow_array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Items of a fixed-size list array, as the concrete array type used to store
+/// primitive Ts
+///
+/// # Panics
+///
+/// Panics if the items are not stored in the array type that is used for Ts.
+/// This cannot happen after [`validate_array_items()`] has been called.
+fn array_items<T: PrimitiveType>(array: &FixedSizeListArray) -> &PrimitiveArray<T::Arrow> {
+    array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T::Arrow>>()
+        .expect("list items should have been validated")
+}
+
+/// Check that a fixed-size list array is a valid array of `Option<[T; N]>`
+///
+/// Since arrow stores list items even for null lists, the items of null lists
+/// may be null even though `T` is not nullable.
+fn validate_array_items<T: FixedSizeListItem, const N: usize>(
+    array: &FixedSizeListArray,
+) -> Result<(), ArrowError> {
+    if usize::try_from(array.value_length()) != Ok(N) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "an array of [{}; {N}] cannot have a list size of {}",
+            std::any::type_name::<T>(),
+            array.value_length()
+        )));
+    }
+    let items = array.values();
+    let Some(items) = items
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T::Arrow>>()
+        .filter(|items| items.data_type() == &T::arrow_data_type())
+    else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list items of data type {} cannot be read as {}",
+            items.data_type(),
+            std::any::type_name::<T>()
+        )));
+    };
+    let items_masked = items.nulls().is_none_or(|item_nulls| {
+        array
+            .nulls()
+            .is_some_and(|nulls| nulls.expand(N).contains(item_nulls))
+    });
+    if items_masked {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "the items of non-null lists of {} cannot be null",
+            std::any::type_name::<T>()
+        )))
+    }
+}
+
+impl<T: FixedSizeListItem, const N: usize> TypedBackend<[T; N]> for FixedSizeListArray {
+    type Value<'a> = [T; N];
+    type Slice<'a> = &'a [[T; N]];
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> [T; N] {
+        // SAFETY: The caller guarantees that index is in bounds, and the list
+        //         size of the array was checked to be N during validation
+        unsafe {
+            *array_items::<T>(self)
+                .values()
+                .as_ptr()
+                .cast::<[T; N]>()
+                .add(index)
+        }
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> &[[T; N]] {
+        let items: &[NativeType<T>] = array_items::<T>(self).values();
+        // SAFETY: Primitive types are repr(transparent) wrappers over the
+        //         corresponding Arrow native types, so it is safe to transmute
+        //         &[NativeType<T>] into &[T].
+        let items = unsafe { std::mem::transmute::<&[NativeType<T>], &[T]>(items) };
+        let (lists, _trailing_items) = items.as_chunks::<N>();
+        &lists[..self.len()][range]
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<[T; N]>(self)?;
+        validate_array_items::<T, N>(self)
+    }
+}
+
+impl<T: FixedSizeListItem, const N: usize> TypedBackend<Option<[T; N]>> for FixedSizeListArray {
+    type Value<'a> = Option<[T; N]>;
+    type Slice<'a> = OptionArraySlice<'a, [T; N]>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<[T; N]> {
+        self.is_valid(index)
+            .then(|| unsafe { TypedBackend::<[T; N]>::value_unchecked(self, index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, [T; N]> {
+        OptionArraySlice {
+            values: TypedBackend::<[T; N]>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_array_items::<T, N>(self)
+    }
+}
+
+/// Items of a fixed-size list array, as the concrete array type used to store
+/// Ts
+///
+/// # Panics
+///
+/// Panics if the items are not stored in the array type that is used for Ts.
+/// This cannot happen after [`validate_items()`] has been called.
+fn items<T: ArrayElement + ?Sized>(array: &FixedSizeListArray) -> &ArrayBackend<T> {
+    array
+        .values()
+        .as_any()
+        .downcast_ref::<ArrayBackend<T>>()
+        .expect("list items should have been validated")
+}
+
+/// Check that a fixed-size list array is a valid array of
+/// `Option<FixedSizeList<T>>`
+fn validate_items<T: ArrayElement + ?Sized>(array: &FixedSizeListArray) -> Result<(), ArrowError>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    let Ok(list_size @ 1..) = usize::try_from(array.value_length()) else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "an array of FixedSizeList cannot have a list size of {}",
+            array.value_length()
+        )));
+    };
+    validate_masked_child::<T>(
+        array.values().as_ref(),
+        array.nulls().map(|nulls| nulls.expand(list_size)).as_ref(),
+    )
+}
+
+/// Bulk readout of the lists within `range` of a fixed-size list array
+fn list_subslice<T: ArrayElement + ?Sized>(
+    array: &FixedSizeListArray,
+    range: Range<usize>,
+) -> FixedSizeListArraySlice<'_, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    assert!(
+        range.start <= range.end && range.end <= array.len(),
+        "range is out of bounds"
+    );
+    FixedSizeListArraySlice::new(array.value_length() as usize, range, items::<T>(array))
+}
+
+/// Items of the `index`-th list of a fixed-size list array
+#[inline]
+fn list_items<T: ArrayElement + ?Sized>(
+    array: &FixedSizeListArray,
+    index: usize,
+) -> ArraySlice<'_, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    let list_size = array.value_length() as usize;
+    let start = index * list_size;
+    items::<T>(array).as_subslice(start..start + list_size)
+}
+
+impl<T: ArrayElement + ?Sized> TypedBackend<FixedSizeList<T>> for FixedSizeListArray
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    type Value<'a> = ArraySlice<'a, T>;
+    type Slice<'a> = FixedSizeListArraySlice<'a, T>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> ArraySlice<'_, T> {
+        list_items::<T>(self, index)
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> FixedSizeListArraySlice<'_, T> {
+        list_subslice(self, range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<FixedSizeList<T>>(self)?;
+        validate_items::<T>(self)
+    }
+}
+
+impl<T: ArrayElement + ?Sized> TypedBackend<Option<FixedSizeList<T>>> for FixedSizeListArray
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    type Value<'a> = Option<ArraySlice<'a, T>>;
+    type Slice<'a> = OptionArraySlice<'a, FixedSizeList<T>>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<ArraySlice<'_, T>> {
+        self.is_valid(index).then(|| list_items::<T>(self, index))
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, FixedSizeList<T>> {
+        OptionArraySlice {
+            values: TypedBackend::<FixedSizeList<T>>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_items::<T>(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        builder::BuilderConfig,
+        tests::vec_and_subslice,
+        types::fixed_size_list::{FixedSizeList, FixedSizeListConfig, FixedSizeListSlice},
+        OptionSlice, TypedArray, TypedBuilder,
+    };
+    use arrow_array::{Array, FixedSizeListArray, Int32Array};
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::{DataType, Field};
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    macro_rules! test_fixed_size_lists {
+        ($($mod_name:ident : [$item:ty; $size:literal]),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Element = [$item; $size];
+
+                    proptest! {
+                        #[test]
+                        fn read_values(values: Vec<Element>) {
+                            let mut builder = TypedBuilder::<Element>::new();
+                            builder.extend_from_slice(&values);
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), 0);
+                            prop_assert_eq!(array.as_slice(), &values[..]);
+                        }
+
+                        #[test]
+                        fn read_options(values: Vec<Option<Element>>) {
+                            let mut builder = TypedBuilder::<Option<Element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish();
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+                            let slice = array.as_slice();
+                            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+                            check_option_slice(&slice, &is_valid)?;
+                            prop_assert_eq!(slice.values.len(), values.len());
+                            for (value, expected) in slice.values.iter().zip(&values) {
+                                if let Some(expected) = expected {
+                                    prop_assert_eq!(value, expected);
+                                }
+                            }
+                        }
+
+                        #[test]
+                        fn try_from((values, offset, len) in vec_and_subslice::<Option<Element>>()) {
+                            let mut builder = TypedBuilder::<Option<Element>>::new();
+                            builder.extend(values.iter().copied());
+                            let array = builder.finish().into_array_ref().slice(offset, len);
+                            let values = &values[offset..offset + len];
+
+                            let options = TypedArray::<Option<Element>>::try_from(array.clone())?;
+                            check_values(&options, values)?;
+                            prop_assert!(TypedArray::<Option<[$item; 2]>>::try_from(array.clone()).is_err());
+                            prop_assert!(TypedArray::<Option<[f32; $size]>>::try_from(array.clone()).is_err());
+
+                            let result = TypedArray::<Element>::try_from(array);
+                            if values.iter().all(Option::is_some) {
+                                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                                let array = result?;
+                                check_values(&array, &values)?;
+                                prop_assert_eq!(array.as_slice(), &values[..]);
+                            } else {
+                                prop_assert!(result.is_err());
+                            }
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_fixed_size_lists!(i8x1: [i8; 1], u16x3: [u16; 3], i64x32: [i64; 32]);
+
+    proptest! {
+        #[test]
+        fn try_from_nullable_items(items: Vec<(Option<i32>, Option<i32>)>, lists_valid: Vec<bool>) {
+            let len = items.len().min(lists_valid.len());
+            let lists_valid = &lists_valid[..len];
+            let items = Int32Array::from_iter(items[..len].iter().flat_map(|&(a, b)| [a, b]));
+            let field = Arc::new(Field::new("item", DataType::Int32, true));
+            let nulls = NullBuffer::from(lists_valid);
+            let array = FixedSizeListArray::new(field, 2, Arc::new(items.clone()), Some(nulls));
+
+            let masked = items.iter().enumerate().all(|(idx, item)| item.is_some() || !lists_valid[idx / 2]);
+            prop_assert_eq!(
+                TypedArray::<Option<[i32; 2]>>::try_from(&array as &dyn Array).is_ok(),
+                masked
+            );
+        }
+    }
+
+    /// Build an array of optional runtime-sized lists of bytes
+    fn build_options(
+        list_size: usize,
+        lists: &[Option<Vec<u8>>],
+    ) -> TypedArray<Option<FixedSizeList<u8>>> {
+        let config = FixedSizeListConfig::new(list_size).unwrap();
+        let mut builder = TypedBuilder::<Option<FixedSizeList<u8>>>::with_config(
+            BuilderConfig::with_backend(None, config),
+        );
+        for list in lists {
+            builder.push(list.as_deref()).unwrap();
+        }
+        builder.finish()
+    }
+
+    /// Generate a list size and optional lists of that size
+    fn size_and_lists() -> impl Strategy<Value = (usize, Vec<Option<Vec<u8>>>)> {
+        (1..=8usize).prop_flat_map(|list_size| {
+            (
+                Just(list_size),
+                prop::collection::vec(
+                    prop::option::of(prop::collection::vec(any::<u8>(), list_size)),
+                    0..=32,
+                ),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn read_runtime_values((list_size, lists) in size_and_lists()) {
+            let lists = lists.into_iter().flatten().collect::<Vec<_>>();
+            let config = FixedSizeListConfig::new(list_size).unwrap();
+            let mut builder =
+                TypedBuilder::<FixedSizeList<u8>>::with_config(BuilderConfig::with_backend(None, config));
+            let items = lists.concat();
+            builder.extend_from_slice(FixedSizeListSlice::new(list_size, &items[..])?)?;
+            let array = builder.finish();
+            check_values(&array, &lists)?;
+            prop_assert_eq!(array.null_count(), 0);
+
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.len(), lists.len());
+            prop_assert_eq!(slice.is_empty(), lists.is_empty());
+            prop_assert_eq!(slice.list_size(), list_size);
+            prop_assert_eq!(slice.values(), &items[..]);
+            prop_assert!(slice.iter().eq(lists.iter().map(Vec::as_slice)));
+            prop_assert_eq!(slice.get(lists.len()), None);
+        }
+
+        #[test]
+        fn read_runtime_options((list_size, lists) in size_and_lists()) {
+            let array = build_options(list_size, &lists);
+            check_values(&array, &lists)?;
+            prop_assert_eq!(array.null_count(), lists.iter().filter(|l| l.is_none()).count());
+            let slice = array.as_slice();
+            let is_valid = lists.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            prop_assert_eq!(slice.values.len(), lists.len());
+            for (list, expected) in slice.values.iter().zip(&lists) {
+                if let Some(expected) = expected {
+                    prop_assert_eq!(list, &expected[..]);
+                }
+            }
+        }
+
+        #[test]
+        fn try_from_runtime(((list_size, lists), offset, len) in size_and_lists().prop_flat_map(|(list_size, lists)| {
+            let len = lists.len();
+            (Just((list_size, lists)), 0..=len).prop_flat_map(move |(lists, offset)| {
+                (Just(lists), Just(offset), 0..=len - offset)
+            })
+        })) {
+            let array = build_options(list_size, &lists).into_array_ref().slice(offset, len);
+            let lists = &lists[offset..offset + len];
+
+            let options = TypedArray::<Option<FixedSizeList<u8>>>::try_from(array.clone())?;
+            check_values(&options, lists)?;
+            prop_assert!(TypedArray::<Option<FixedSizeList<u16>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<FixedSizeList<Option<u8>>>>::try_from(array.clone()).is_ok());
+
+            let result = TypedArray::<FixedSizeList<u8>>::try_from(array);
+            if lists.iter().all(Option::is_some) {
+                let lists = lists.iter().flatten().collect::<Vec<_>>();
+                let array = result?;
+                check_values(&array, &lists)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+
+        #[test]
+        fn try_from_nested_nulls(lists: Vec<Option<[Option<[u8; 2]>; 3]>>) {
+            let inner_config = FixedSizeListConfig::<u8>::new(2).unwrap();
+            let config = FixedSizeListConfig::<Option<FixedSizeList<u8>>>::with_items(3, inner_config.clone()).unwrap();
+            let mut builder = TypedBuilder::<Option<FixedSizeList<Option<FixedSizeList<u8>>>>>::with_config(
+                BuilderConfig::with_backend(None, config),
+            );
+            for list in &lists {
+                let Some(list) = list else {
+                    builder.push(None)?;
+                    continue;
+                };
+                let values = list.iter().map(|inner| inner.unwrap_or_default()).collect::<Vec<_>>();
+                let is_valid = list.iter().map(Option::is_some).collect::<Vec<_>>();
+                builder.push(Some(OptionSlice {
+                    values: FixedSizeListSlice::new(2, values.as_flattened())?,
+                    is_valid: &is_valid,
+                }))?;
+            }
+            let array = builder.finish().into_array_ref();
+            prop_assert!(TypedArray::<Option<FixedSizeList<Option<FixedSizeList<u8>>>>>::try_from(array.clone()).is_ok());
+
+            let all_inner_valid = lists.iter().flatten().flatten().all(Option::is_some);
+            let array = TypedArray::<Option<FixedSizeList<FixedSizeList<u8>>>>::try_from(array);
+            prop_assert_eq!(array.is_ok(), all_inner_valid);
+            if let Ok(array) = array {
+                prop_assert_eq!(array.len(), lists.len());
+                for (list, expected) in array.iter().zip(&lists) {
+                    prop_assert_eq!(list.is_some(), expected.is_some());
+                    if let (Some(list), Some(expected)) = (list, expected) {
+                        prop_assert!(list.iter().eq(expected.iter().map(|inner| inner.as_ref().unwrap().as_slice())));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,329 @@
+//! Strong typing layer on top of [`GenericListArray`]
+
+use super::{validate_masked_child, validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::{ArrayBackend, ArraySlice, OptionArraySlice},
+    types::{
+        list::{GenericListArraySlice, LargeList, List},
+        slice_offsets,
+    },
+    ArrayElement,
+};
+use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Items of a list array, as the concrete array type used to store Ts
+///
+/// # Panics
+///
+/// Panics if the items are not stored in the array type that is used for Ts.
+/// This cannot happen after [`validate_items()`] has been called.
+fn items<T: ArrayElement + ?Sized, O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+) -> &ArrayBackend<T> {
+    array
+        .values()
+        .as_any()
+        .downcast_ref::<ArrayBackend<T>>()
+        .expect("list items should have been validated")
+}
+
+/// Check that the items of a list array are a valid array of T
+///
+/// Only the items that the lists of the array point to are checked, as a
+/// sliced list array still holds the items of the lists outside of its range.
+/// Since arrow lets null lists point to items too, the items of null lists may
+/// be null even though `T` is not nullable.
+fn validate_items<T: ArrayElement + ?Sized, O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+) -> Result<(), ArrowError>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    let items = array.values();
+    if !items.as_any().is::<ArrayBackend<T>>() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list items of data type {} cannot be read as {}",
+            items.data_type(),
+            std::any::type_name::<T>()
+        )));
+    }
+    let offsets = array.value_offsets();
+    let start = offsets[0].as_usize();
+    let end = offsets[offsets.len() - 1].as_usize();
+    let item_nulls = array.nulls().map(|nulls| {
+        let mut item_validity = BooleanBufferBuilder::new(end - start);
+        for (index, bounds) in offsets.windows(2).enumerate() {
+            item_validity.append_n((bounds[1] - bounds[0]).as_usize(), nulls.is_valid(index));
+        }
+        NullBuffer::new(item_validity.finish())
+    });
+    validate_masked_child::<T>(
+        items.slice(start, end - start).as_ref(),
+        item_nulls.as_ref(),
+    )
+}
+
+/// Bulk readout of the lists within `range` of a list array
+fn list_subslice<T: ArrayElement + ?Sized, O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    range: Range<usize>,
+) -> GenericListArraySlice<'_, T, O>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    GenericListArraySlice::new(
+        slice_offsets(array.value_offsets(), range),
+        items::<T, O>(array),
+    )
+}
+
+/// Items of the `index`-th list of a list array, without bounds checking
+///
+/// # Safety
+///
+/// `index` must be smaller than the array's length.
+#[inline]
+unsafe fn list_unchecked<T: ArrayElement + ?Sized, O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    index: usize,
+) -> ArraySlice<'_, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    let offsets = array.value_offsets();
+    // SAFETY: A list array of length N has N+1 offsets, and the caller
+    //         guarantees that index is smaller than N
+    let (start, end) = unsafe {
+        (
+            offsets.get_unchecked(index).as_usize(),
+            offsets.get_unchecked(index + 1).as_usize(),
+        )
+    };
+    items::<T, O>(array).as_subslice(start..end)
+}
+
+// Enable strongly typed readout of lists with 32-bit and 64-bit offsets
+macro_rules! impl_list_array {
+    ($($list:ident => $offset:ty),*) => {
+        $(
+            impl<T: ArrayElement + ?Sized> TypedBackend<$list<T>> for GenericListArray<$offset>
+            where
+                ArrayBackend<T>: TypedBackend<T>,
+            {
+                type Value<'a> = ArraySlice<'a, T>;
+                type Slice<'a> = GenericListArraySlice<'a, T, $offset>;
+
+                #[inline]
+                unsafe fn value_unchecked(&self, index: usize) -> ArraySlice<'_, T> {
+                    unsafe { list_unchecked::<T, $offset>(self, index) }
+                }
+
+                fn as_subslice(&self, range: Range<usize>) -> GenericListArraySlice<'_, T, $offset> {
+                    list_subslice(self, range)
+                }
+
+                fn validate(&self) -> Result<(), ArrowError> {
+                    validate_non_null::<$list<T>>(self)?;
+                    validate_items::<T, $offset>(self)
+                }
+            }
+
+            impl<T: ArrayElement + ?Sized> TypedBackend<Option<$list<T>>> for GenericListArray<$offset>
+            where
+                ArrayBackend<T>: TypedBackend<T>,
+            {
+                type Value<'a> = Option<ArraySlice<'a, T>>;
+                type Slice<'a> = OptionArraySlice<'a, $list<T>>;
+
+                #[inline]
+                unsafe fn value_unchecked(&self, index: usize) -> Option<ArraySlice<'_, T>> {
+                    self.is_valid(index)
+                        .then(|| unsafe { list_unchecked::<T, $offset>(self, index) })
+                }
+
+                fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, $list<T>> {
+                    OptionArraySlice {
+                        values: TypedBackend::<$list<T>>::as_subslice(self, range.clone()),
+                        is_valid: validity_subslice(self, range),
+                    }
+                }
+
+                fn validate(&self) -> Result<(), ArrowError> {
+                    validate_items::<T, $offset>(self)
+                }
+            }
+        )*
+    };
+}
+//
+impl_list_array!(List => i32, LargeList => i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::list::{LargeList, List, ListSlice},
+        OptionSlice, TypedArray, TypedBuilder,
+    };
+    use arrow_array::{Array, Int32Array, ListArray};
+    use arrow_buffer::{NullBuffer, OffsetBuffer};
+    use arrow_schema::{DataType, Field};
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    /// Build an array of optional lists of bytes
+    fn build_options(lists: &[Option<Vec<u8>>]) -> TypedArray<Option<List<u8>>> {
+        let mut builder = TypedBuilder::<Option<List<u8>>>::new();
+        builder.extend(lists.iter().map(Option::as_deref));
+        builder.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(lists: Vec<Vec<u8>>) {
+            let mut builder = TypedBuilder::<List<u8>>::new();
+            builder.extend(lists.iter().map(Vec::as_slice));
+            let array = builder.finish();
+            check_values(&array, &lists)?;
+            prop_assert_eq!(array.null_count(), 0);
+
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.len(), lists.len());
+            prop_assert_eq!(slice.is_empty(), lists.is_empty());
+            prop_assert_eq!(slice.values(), &lists.concat()[..]);
+            prop_assert!(slice.iter().eq(lists.iter().map(Vec::as_slice)));
+            prop_assert_eq!(slice.get(lists.len()), None);
+            let mut builder = TypedBuilder::<List<u8>>::new();
+            builder.extend_from_slice(ListSlice { offsets: slice.offsets(), values: slice.values() })?;
+            prop_assert!(builder.finish().iter().eq(array.iter()));
+        }
+
+        #[test]
+        fn read_options(lists: Vec<Option<Vec<u8>>>) {
+            let array = build_options(&lists);
+            check_values(&array, &lists)?;
+            prop_assert_eq!(array.null_count(), lists.iter().filter(|l| l.is_none()).count());
+            let slice = array.as_slice();
+            let is_valid = lists.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            prop_assert_eq!(slice.values.len(), lists.len());
+            for (list, expected) in slice.values.iter().zip(&lists) {
+                if let Some(expected) = expected {
+                    prop_assert_eq!(list, &expected[..]);
+                }
+            }
+        }
+
+        #[test]
+        fn read_large(lists: Vec<Option<Vec<u8>>>) {
+            let mut builder = TypedBuilder::<Option<LargeList<u8>>>::new();
+            builder.extend(lists.iter().map(Option::as_deref));
+            let array = builder.finish();
+            check_values(&array, &lists)?;
+            let array = array.into_array_ref();
+            prop_assert!(TypedArray::<Option<List<u8>>>::try_from(array.clone()).is_err());
+            let array = TypedArray::<Option<LargeList<u8>>>::try_from(array)?;
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.values.len(), lists.len());
+            prop_assert_eq!(*slice.values.offsets().last().unwrap_or(&0), lists.iter().flatten().map(Vec::len).sum::<usize>() as i64);
+        }
+
+        #[test]
+        fn read_nested(
+            lists in prop::collection::vec(
+                prop::collection::vec(prop::collection::vec(any::<Option<u8>>(), 0..8), 0..8),
+                0..8,
+            ),
+        ) {
+            let mut builder = TypedBuilder::<List<List<Option<u8>>>>::new();
+            for list in &lists {
+                let offsets = std::iter::once(0)
+                    .chain(list.iter().scan(0, |end, inner| {
+                        *end += inner.len() as i32;
+                        Some(*end)
+                    }))
+                    .collect::<Vec<_>>();
+                let items = list.concat();
+                let values = items.iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+                let is_valid = items.iter().map(Option::is_some).collect::<Vec<_>>();
+                builder.push(ListSlice {
+                    offsets: &offsets,
+                    values: OptionSlice { values: &values, is_valid: &is_valid },
+                })?;
+            }
+            let array = builder.finish();
+            prop_assert_eq!(array.len(), lists.len());
+            for (lists, expected) in array.iter().zip(&lists) {
+                prop_assert_eq!(lists.len(), expected.len());
+                for (list, expected) in lists.iter().zip(expected) {
+                    let is_valid = expected.iter().map(Option::is_some).collect::<Vec<_>>();
+                    check_option_slice(&list, &is_valid)?;
+                    for ((value, expected), valid) in list.values.iter().zip(expected).zip(&is_valid) {
+                        if *valid {
+                            prop_assert_eq!(Some(*value), *expected);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn try_from((lists, offset, len) in vec_and_subslice::<Option<Vec<u8>>>()) {
+            let array = build_options(&lists).into_array_ref().slice(offset, len);
+            let lists = &lists[offset..offset + len];
+
+            let options = TypedArray::<Option<List<u8>>>::try_from(array.clone())?;
+            check_values(&options, lists)?;
+            prop_assert!(TypedArray::<Option<List<u16>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<LargeList<u8>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<List<Option<u8>>>::try_from(array.clone()).is_ok() == lists.iter().all(Option::is_some));
+
+            let result = TypedArray::<List<u8>>::try_from(array);
+            if lists.iter().all(Option::is_some) {
+                let lists = lists.iter().flatten().collect::<Vec<_>>();
+                let array = result?;
+                check_values(&array, &lists)?;
+                prop_assert!(array.as_slice().iter().eq(lists.iter().map(|l| l.as_slice())));
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+
+        #[test]
+        fn try_from_nullable_items(items: Vec<Option<u8>>) {
+            let values = items.iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+            let is_valid = items.iter().map(Option::is_some).collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<List<Option<u8>>>::new();
+            builder.push(OptionSlice { values: &values, is_valid: &is_valid })?;
+            let array = builder.finish().into_array_ref();
+            let result = TypedArray::<List<u8>>::try_from(array);
+            prop_assert_eq!(result.is_ok(), items.iter().all(Option::is_some));
+        }
+    }
+
+    #[test]
+    fn try_from_masked_items() {
+        // Items of null lists and of lists outside of the slice may be null
+        let items = Int32Array::from(vec![Some(1), None, Some(2), Some(3), None, Some(4), None]);
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        let offsets = OffsetBuffer::new(vec![0, 2, 3, 5, 6, 7].into());
+        let nulls = NullBuffer::from(vec![false, true, false, true, true]);
+        let array = ListArray::new(field, offsets, Arc::new(items), Some(nulls));
+        assert!(TypedArray::<Option<List<u32>>>::try_from(&array as &dyn Array).is_err());
+        assert!(TypedArray::<Option<List<i32>>>::try_from(&array as &dyn Array).is_err());
+        assert!(TypedArray::<Option<List<Option<i32>>>>::try_from(&array as &dyn Array).is_ok());
+
+        let visible = array.slice(0, 4);
+        let typed = TypedArray::<Option<List<i32>>>::try_from(&visible as &dyn Array).unwrap();
+        assert!(typed
+            .iter()
+            .eq([None, Some(&[2][..]), None, Some(&[4][..])]));
+        let visible = array.slice(1, 1);
+        let typed = TypedArray::<List<i32>>::try_from(&visible as &dyn Array).unwrap();
+        assert!(typed.iter().eq([&[2][..]]));
+    }
+}
@@ -0,0 +1,224 @@
+//! Strong typing layer on top of [`MapArray`]
+//!
+//! Map arrays are read out like lists of key-value tuples, whose items are the
+//! entries struct array of the map.
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::{ArrayBackend, ArraySlice, OptionArraySlice},
+    types::{
+        list::GenericListArraySlice,
+        map::{Map, MapArraySlice},
+        slice_offsets,
+    },
+    ArrayElement,
+};
+use arrow_array::{Array, MapArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Entries of the `index`-th map of a map array, without bounds checking
+///
+/// # Safety
+///
+/// `index` must be smaller than the array's length.
+#[inline]
+unsafe fn entries_unchecked<K: ArrayElement, V: ArrayElement>(
+    array: &MapArray,
+    index: usize,
+) -> ArraySlice<'_, (K, V)>
+where
+    ArrayBackend<K>: TypedBackend<K>,
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    let offsets = array.value_offsets();
+    // SAFETY: A map array of length N has N+1 offsets, and the caller
+    //         guarantees that index is smaller than N
+    let (start, end) = unsafe {
+        (
+            *offsets.get_unchecked(index) as usize,
+            *offsets.get_unchecked(index + 1) as usize,
+        )
+    };
+    TypedBackend::<(K, V)>::as_subslice(array.entries(), start..end)
+}
+
+impl<K: ArrayElement, V: ArrayElement> TypedBackend<Map<K, V>> for MapArray
+where
+    ArrayBackend<K>: TypedBackend<K>,
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Value<'a> = ArraySlice<'a, (K, V)>;
+    type Slice<'a> = MapArraySlice<'a, K, V>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> ArraySlice<'_, (K, V)> {
+        unsafe { entries_unchecked::<K, V>(self, index) }
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> MapArraySlice<'_, K, V> {
+        GenericListArraySlice::new(slice_offsets(self.value_offsets(), range), self.entries())
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<Map<K, V>>(self)?;
+        TypedBackend::<Option<Map<K, V>>>::validate(self)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> TypedBackend<Option<Map<K, V>>> for MapArray
+where
+    ArrayBackend<K>: TypedBackend<K>,
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Value<'a> = Option<ArraySlice<'a, (K, V)>>;
+    type Slice<'a> = OptionArraySlice<'a, Map<K, V>>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<ArraySlice<'_, (K, V)>> {
+        self.is_valid(index)
+            .then(|| unsafe { entries_unchecked::<K, V>(self, index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, Map<K, V>> {
+        OptionArraySlice {
+            values: TypedBackend::<Map<K, V>>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        TypedBackend::<(K, V)>::validate(self.entries())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::{list::List, map::Map},
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::{
+        builder::{Int32Builder, MapBuilder as ArrowMapBuilder, StringBuilder},
+        ArrayRef,
+    };
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    /// Build an array of optional maps from their entries
+    fn build_options(maps: &[Option<Vec<(u8, i16)>>]) -> TypedArray<Option<Map<u8, i16>>> {
+        let mut builder = TypedBuilder::<Option<Map<u8, i16>>>::new();
+        for map in maps {
+            match map {
+                Some(entries) => builder.push_entries(entries.iter().copied()).unwrap(),
+                None => builder.push(None).unwrap(),
+            }
+        }
+        builder.finish()
+    }
+
+    /// Split maps into their keys and values
+    fn split(maps: &[Option<Vec<(u8, i16)>>]) -> Vec<Option<(Vec<u8>, Vec<i16>)>> {
+        maps.iter()
+            .map(|map| map.as_ref().map(|entries| entries.iter().copied().unzip()))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(maps: Vec<Vec<(u8, i16)>>) {
+            let mut builder = TypedBuilder::<Map<u8, i16>>::new();
+            for map in &maps {
+                builder.push_entries(map.iter().copied())?;
+            }
+            let array = builder.finish();
+            let expected = maps
+                .iter()
+                .map(|map| map.iter().copied().unzip::<_, _, Vec<u8>, Vec<i16>>())
+                .collect::<Vec<_>>();
+            check_values(&array, &expected)?;
+            prop_assert_eq!(array.null_count(), 0);
+
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.len(), maps.len());
+            let entries = maps.concat();
+            prop_assert!(slice.values().0.iter().eq(entries.iter().map(|(key, _)| key)));
+            prop_assert!(slice.values().1.iter().eq(entries.iter().map(|(_, value)| value)));
+            prop_assert!(slice
+                .iter()
+                .eq(expected.iter().map(|(keys, values)| (&keys[..], &values[..]))));
+        }
+
+        #[test]
+        fn read_options(maps: Vec<Option<Vec<(u8, i16)>>>) {
+            let array = build_options(&maps);
+            check_values(&array, &split(&maps))?;
+            prop_assert_eq!(array.null_count(), maps.iter().filter(|m| m.is_none()).count());
+            let is_valid = maps.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&array.as_slice(), &is_valid)?;
+        }
+
+        #[test]
+        fn read_arrow(
+            maps in prop::collection::vec(
+                prop::option::of(prop::collection::vec(any::<(String, Option<i32>)>(), 0..8)),
+                0..8,
+            ),
+        ) {
+            let mut builder = ArrowMapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+            for map in &maps {
+                if let Some(entries) = map {
+                    for (key, value) in entries {
+                        builder.keys().append_value(key);
+                        builder.values().append_option(*value);
+                    }
+                }
+                builder.append(map.is_some())?;
+            }
+            let array: ArrayRef = Arc::new(builder.finish());
+            prop_assert!(TypedArray::<Option<Map<String, i32>>>::try_from(array.clone()).is_ok()
+                == maps.iter().flatten().flatten().all(|(_, value)| value.is_some()));
+            prop_assert!(TypedArray::<Option<Map<String, Option<u32>>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<List<(String, Option<i32>)>>>::try_from(array.clone()).is_err());
+
+            let array = TypedArray::<Option<Map<String, Option<i32>>>>::try_from(array)?;
+            prop_assert_eq!(array.len(), maps.len());
+            for (map, expected) in array.iter().zip(&maps) {
+                prop_assert_eq!(map.is_some(), expected.is_some());
+                let (Some((keys, values)), Some(expected)) = (map, expected) else {
+                    continue;
+                };
+                prop_assert!(keys.iter().eq(expected.iter().map(|(key, _)| key.as_str())));
+                for (index, (_, value)) in expected.iter().enumerate() {
+                    if let Some(value) = value {
+                        prop_assert_eq!(values.values[index], *value);
+                    } else {
+                        prop_assert_eq!(values.is_valid.map(|is_valid| is_valid.at(index)), Some(false));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn try_from((maps, offset, len) in vec_and_subslice::<Option<Vec<(u8, i16)>>>()) {
+            let array = build_options(&maps).into_array_ref().slice(offset, len);
+            let maps = &maps[offset..offset + len];
+
+            let options = TypedArray::<Option<Map<u8, i16>>>::try_from(array.clone())?;
+            check_values(&options, &split(maps))?;
+            prop_assert!(TypedArray::<Option<Map<u8, u16>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<Map<i8, i16>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<List<(u8, i16)>>>::try_from(array.clone()).is_err());
+
+            let result = TypedArray::<Map<u8, i16>>::try_from(array);
+            if maps.iter().all(Option::is_some) {
+                let maps = split(maps).into_iter().flatten().collect::<Vec<_>>();
+                check_values(&result?, &maps)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Strong typing layer on top of Arrow arrays
+
+mod bool;
+mod byte_view;
+mod bytes;
+mod decimal;
+mod dictionary;
+mod fixed_size_binary;
+mod fixed_size_list;
+mod list;
+mod map;
+mod null;
+mod primitive;
+mod run_end;
+mod structs;
+mod timestamp;
+mod union;
+
+use super::ArrayBackend;
+use crate::{validity::ValiditySlice, ArrayElement};
+use arrow_array::{make_array, Array};
+use arrow_buffer::NullBuffer;
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType};
+use std::{fmt::Debug, ops::Range};
+
+/// Arrow array whose elements can be read as strongly typed values of type `T`
+pub trait TypedBackend<T: ArrayElement + ?Sized>: Array + Clone + Debug {
+    /// Value type used for individual element readout
+    ///
+    /// This is the same as [`ArrayElement::Value`] whenever arrow's memory
+    /// layout allows it, but some types need a different layout. For example,
+    /// lists are written as [`ArrayElement::Slice`]s of their items, but read
+    /// out using the bulk readout layout of their items (see below).
+    type Value<'a>: Debug
+    where
+        Self: 'a;
+
+    /// Slice type used for bulk readout
+    ///
+    /// This is the same as [`ArrayElement::Slice`] whenever arrow's memory
+    /// layout allows it, but some types need a different layout. For example,
+    /// arrow stores booleans and validity information as bitmaps, which cannot
+    /// be read out as `&[bool]` without copying.
+    type Slice<'a>: Debug
+    where
+        Self: 'a;
+
+    /// Read the `index`-th element of the array without bounds checking
+    ///
+    /// Implementors should almost always make this operation `#[inline]` to
+    /// allow for cross-crate inlining.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be smaller than the array's length.
+    unsafe fn value_unchecked(&self, index: usize) -> Self::Value<'_>;
+
+    /// Read out the elements within `range` in bulk
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    fn as_subslice(&self, range: Range<usize>) -> Self::Slice<'_>;
+
+    /// Read out the whole array in bulk
+    fn as_slice(&self) -> Self::Slice<'_> {
+        self.as_subslice(0..self.len())
+    }
+
+    /// Check that this array is a valid array of `T`
+    ///
+    /// This is used when converting untyped arrays from other arrow-rs code
+    /// into typed arrays. At this point, the array is already known to be of
+    /// the right concrete type, but it may still contain data that cannot be
+    /// represented by `T`, such as nulls when `T` is not nullable.
+    fn validate(&self) -> Result<(), ArrowError>;
+}
+
+/// Check that an array does not contain any null
+///
+/// This is the validation logic for element types that are not nullable.
+pub(crate) fn validate_non_null<T: ArrayElement + ?Sized>(
+    array: &dyn Array,
+) -> Result<(), ArrowError> {
+    match array.null_count() {
+        0 => Ok(()),
+        null_count => Err(ArrowError::InvalidArgumentError(format!(
+            "an array of {} cannot contain nulls, but this array contains {null_count} null(s)",
+            std::any::type_name::<T>()
+        ))),
+    }
+}
+
+/// Validity of the elements within `range` of an array, or `None` if the
+/// array has no validity bitmap
+///
+/// This is the validity part of the bulk readout logic for `Option<T>`.
+///
+/// # Panics
+///
+/// Panics if the array has a validity bitmap and `range` is out of bounds.
+pub(crate) fn validity_subslice(
+    array: &dyn Array,
+    range: Range<usize>,
+) -> Option<ValiditySlice<'_>> {
+    array
+        .nulls()
+        .map(|nulls| ValiditySlice::from_buffer(nulls.inner()).slice(range))
+}
+
+/// Check that the child array of a nested array is a valid array of `T`,
+/// given the validity of the parent entries that the child entries belong to
+///
+/// Arrow lets child arrays of non-nullable elements, like the items of
+/// fixed-size lists, contain nulls at positions where the parent is null. Such
+/// nulls are accepted here even if `T` is not nullable, but any other null is
+/// rejected.
+pub(crate) fn validate_masked_child<T: ArrayElement + ?Sized>(
+    child: &dyn Array,
+    parent_nulls: Option<&NullBuffer>,
+) -> Result<(), ArrowError>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    let Some(typed) = child.as_any().downcast_ref::<ArrayBackend<T>>() else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "child array of data type {} cannot be read as {}",
+            child.data_type(),
+            std::any::type_name::<T>()
+        )));
+    };
+    let Some(child_nulls) = child.nulls().filter(|_| !T::NULLABLE) else {
+        return typed.validate();
+    };
+    if !parent_nulls.is_some_and(|parent_nulls| parent_nulls.contains(child_nulls)) {
+        return validate_non_null::<T>(child);
+    }
+    let stripped = make_array(strip_masked_nulls(child.to_data())?);
+    stripped
+        .as_any()
+        .downcast_ref::<ArrayBackend<T>>()
+        .expect("stripping nulls does not change the array type")
+        .validate()
+}
+
+/// Remove the nulls of an array whose nulls are all masked by its parent,
+/// along with the nulls that they mask in turn
+///
+/// Arrow requires the nulls of the non-nullable items of a fixed-size list, and
+/// of the non-nullable fields of a struct, to be masked by the nulls of the
+/// parent, so these nulls must be removed too.
+///
+/// Removing nulls can turn array data invalid, for example by exposing the
+/// out-of-bounds keys of a dictionary's null entries, so the resulting array
+/// data is validated and an error is returned if it is invalid.
+fn strip_masked_nulls(data: ArrayData) -> Result<ArrayData, ArrowError> {
+    let masked_children = match data.data_type() {
+        DataType::FixedSizeList(field, _) => vec![!field.is_nullable()],
+        DataType::Struct(fields) => fields.iter().map(|field| !field.is_nullable()).collect(),
+        _ => Vec::new(),
+    };
+    let child_data = data
+        .child_data()
+        .iter()
+        .enumerate()
+        .map(|(index, child)| {
+            if masked_children.get(index).copied().unwrap_or(false) {
+                strip_masked_nulls(child.clone())
+            } else {
+                Ok(child.clone())
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    data.into_builder()
+        .nulls(None)
+        .child_data(child_data)
+        .build()
+}
@@ -0,0 +1,44 @@
+//! Strong typing layer on top of [`NullArray`]
+
+use super::TypedBackend;
+use crate::{types::primitive::Null, ElementSlice};
+use arrow_array::{Array, NullArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+impl TypedBackend<Null> for NullArray {
+    type Value<'a> = Null;
+    type Slice<'a> = usize;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, _index: usize) -> Null {
+        Null
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> usize {
+        self.len().slice_range(range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array::tests::check_values, tests::length_or_capacity, TypedBuilder};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn read(len in length_or_capacity()) {
+            let mut builder = TypedBuilder::<Null>::new();
+            builder.extend_with_nulls(len);
+            let array = builder.finish();
+            check_values(&array, &vec![Null; len])?;
+            prop_assert_eq!(array.as_slice(), len);
+            prop_assert_eq!(array.null_count(), len);
+        }
+    }
+}
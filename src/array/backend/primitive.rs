@@ -0,0 +1,299 @@
+//! Strong typing layer on top of [`PrimitiveArray`]
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::OptionArraySlice,
+    types::primitive::{NativeType, PrimitiveType},
+    ArrayElement,
+};
+use arrow_array::{Array, PrimitiveArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Check that a primitive array has the data type expected for `T`
+///
+/// Some primitive types, like decimals, share an arrow primitive type with
+/// other primitive types and are only distinguished by their data type.
+fn validate_data_type<T: PrimitiveType>(
+    array: &PrimitiveArray<T::Arrow>,
+) -> Result<(), ArrowError> {
+    let expected = T::arrow_data_type();
+    if array.data_type() == &expected {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "an array of {} must have data type {expected}, not {}",
+            std::any::type_name::<T>(),
+            array.data_type()
+        )))
+    }
+}
+
+impl<T: PrimitiveType> TypedBackend<T> for PrimitiveArray<T::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on PrimitiveType to let rustc figure out that
+    //        T::Value<'_> is just T for primitive types.
+    for<'a> T: Into<T::Value<'a>>,
+{
+    type Value<'a> = T::Value<'a>;
+    type Slice<'a> = T::Slice<'a>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> T::Value<'_> {
+        T::from_native(unsafe { self.value_unchecked(index) }).into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> T::Slice<'_> {
+        let native_slice: &[NativeType<T>] = &self.values()[range];
+        // SAFETY: This transmute is safe because...
+        //         - T::Slice is &[T] for all primitive types
+        //         - Primitive types are repr(transparent) wrappers over the
+        //           corresponding Arrow native types, so it is safe to
+        //           transmute &[NativeType<T>] into &[T].
+        unsafe { std::mem::transmute_copy::<&[NativeType<T>], T::Slice<'_>>(&native_slice) }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_data_type::<T>(self)?;
+        validate_non_null::<T>(self)?;
+        T::validate_values(self)
+    }
+}
+
+impl<T: PrimitiveType> TypedBackend<Option<T>> for PrimitiveArray<T::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on PrimitiveType to let rustc figure out that
+    //        T::Value<'_> is just T for primitive types.
+    for<'a> T: Into<T::Value<'a>>,
+    //
+    // FIXME: Remove these bounds once it becomes possible to blanket-impl
+    //        ArrayElement for Option<T: PrimitiveType>, making them obvious.
+    Option<T>: ArrayElement,
+    for<'a> Option<T::Value<'a>>: Into<<Option<T> as ArrayElement>::Value<'a>>,
+{
+    type Value<'a> = <Option<T> as ArrayElement>::Value<'a>;
+    type Slice<'a> = OptionArraySlice<'a, T>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> <Option<T> as ArrayElement>::Value<'_> {
+        let opt: Option<T::Value<'_>> = self
+            .is_valid(index)
+            .then(|| T::from_native(unsafe { self.value_unchecked(index) }).into());
+        opt.into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, T> {
+        OptionArraySlice {
+            values: TypedBackend::<T>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_data_type::<T>(self)?;
+        T::validate_values(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::{
+            decimal,
+            primitive::{
+                Date32, Date64, Decimal128, Decimal256, Duration, IntervalDayTime,
+                IntervalMonthDayNano, IntervalYearMonth, Microsecond, Millisecond, Nanosecond,
+                Second, Time, Timestamp, Utc,
+            },
+        },
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::{Array, ArrayRef, Decimal128Array, Decimal256Array};
+    use arrow_buffer::{i256, NullBuffer};
+    use arrow_schema::DataType;
+    use proptest::prelude::*;
+
+    macro_rules! test_primitives {
+        ($primitive: ident) => {
+            test_primitives!($primitive : $primitive);
+        };
+        ($mod_name:ident : $primitive:ty) => {
+            mod $mod_name {
+                use super::*;
+
+                proptest! {
+                    #[test]
+                    fn read_values(values: Vec<$primitive>) {
+                        let mut builder = TypedBuilder::<$primitive>::new();
+                        builder.extend_from_slice(&values);
+                        let array = builder.finish();
+                        check_values(&array, &values)?;
+                        prop_assert_eq!(array.null_count(), 0);
+                        prop_assert_eq!(
+                            format!("{:?}", array.as_slice()),
+                            format!("{:?}", &values[..])
+                        );
+                    }
+
+                    #[test]
+                    fn read_options(values: Vec<Option<$primitive>>) {
+                        let mut builder = TypedBuilder::<Option<$primitive>>::new();
+                        builder.extend(values.iter().copied());
+                        let array = builder.finish();
+                        check_values(&array, &values)?;
+                        prop_assert_eq!(
+                            array.null_count(),
+                            values.iter().filter(|v| v.is_none()).count()
+                        );
+                        let slice = array.as_slice();
+                        let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+                        check_option_slice(&slice, &is_valid)?;
+                        for (value, expected) in slice.values.iter().zip(&values) {
+                            if let Some(expected) = expected {
+                                prop_assert_eq!(format!("{value:?}"), format!("{expected:?}"));
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn try_from(
+                        (values, offset, len) in vec_and_subslice::<Option<$primitive>>()
+                    ) {
+                        let mut builder = TypedBuilder::<Option<$primitive>>::new();
+                        builder.extend(values.iter().copied());
+                        let array: ArrayRef = builder.finish().into_array_ref().slice(offset, len);
+                        let values = &values[offset..offset + len];
+
+                        let options = TypedArray::<Option<$primitive>>::try_from(array.clone())?;
+                        check_values(&options, values)?;
+                        let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+                        check_option_slice(&options.as_slice(), &is_valid)?;
+
+                        let result = TypedArray::<$primitive>::try_from(array.as_ref());
+                        if values.iter().all(Option::is_some) {
+                            let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                            let array = result?;
+                            check_values(&array, &values)?;
+                            prop_assert_eq!(
+                                format!("{:?}", array.as_slice()),
+                                format!("{:?}", &values[..])
+                            );
+                        } else {
+                            prop_assert!(result.is_err());
+                        }
+                    }
+                }
+            }
+        };
+        ($( $mod_name:ident $(: $primitive:ty)? ),*) => {$(
+            test_primitives!($mod_name $(: $primitive)? );
+        )*};
+    }
+    test_primitives!(
+        date32: Date32,
+        date64: Date64,
+        decimal128: Decimal128<20, 4>,
+        decimal256: Decimal256<50, -2>,
+        duration_micros: Duration<Microsecond>,
+        duration_millis: Duration<Millisecond>,
+        duration_nanos: Duration<Nanosecond>,
+        duration_secs: Duration<Second>,
+        // TODO: Put f16 here once it implements Arbitrary
+        f32, f64, i8, i16, i32, i64,
+        interval_day_time: IntervalDayTime,
+        interval_month_day_nano: IntervalMonthDayNano,
+        interval_year_month: IntervalYearMonth,
+        time_micros: Time<Microsecond>,
+        time_millis: Time<Millisecond>,
+        time_nanos: Time<Nanosecond>,
+        time_secs: Time<Second>,
+        timestamp_micros_utc: Timestamp<Microsecond, Utc>,
+        timestamp_millis_utc: Timestamp<Millisecond, Utc>,
+        timestamp_nanos_utc: Timestamp<Nanosecond, Utc>,
+        timestamp_secs_utc: Timestamp<Second, Utc>,
+        u8, u16, u32, u64
+    );
+
+    #[test]
+    fn try_from_wrong_type() {
+        let mut builder = TypedBuilder::<u32>::new();
+        builder.extend_from_slice(&[1, 2, 3]);
+        let array = builder.finish().into_array_ref();
+        assert!(TypedArray::<u32>::try_from(array.as_ref() as &dyn Array).is_ok());
+        assert!(TypedArray::<Option<u32>>::try_from(array.clone()).is_ok());
+        assert!(TypedArray::<i32>::try_from(array.clone()).is_err());
+        assert!(TypedArray::<Option<u64>>::try_from(array.clone()).is_err());
+        assert!(TypedArray::<Date32>::try_from(array).is_err());
+    }
+
+    #[test]
+    fn try_from_wrong_decimal() {
+        let mut builder = TypedBuilder::<Decimal128<10, 2>>::new();
+        builder.extend_from_slice(&[Decimal128::new(1999).unwrap(), Decimal128::new(-5).unwrap()]);
+        let array = builder.finish().into_array_ref();
+        assert_eq!(array.data_type(), &DataType::Decimal128(10, 2));
+        assert!(TypedArray::<Option<Decimal128<10, 2>>>::try_from(array.clone()).is_ok());
+        assert!(TypedArray::<Decimal128<10, 3>>::try_from(array.clone()).is_err());
+        assert!(TypedArray::<Option<Decimal128<12, 2>>>::try_from(array.clone()).is_err());
+        assert!(TypedArray::<Decimal256<10, 2>>::try_from(array.clone()).is_err());
+        let runtime = TypedArray::<decimal::Decimal128>::try_from(array).unwrap();
+        assert_eq!(runtime.as_slice(), [1999, -5]);
+    }
+
+    #[test]
+    fn try_from_out_of_precision() {
+        let array = Decimal128Array::from(vec![Some(1999), None, Some(-5)])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        assert!(TypedArray::<Option<Decimal128<4, 2>>>::try_from(&array as &dyn Array).is_ok());
+
+        let array = Decimal128Array::from(vec![Some(1999), Some(123_456_789)])
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+        assert!(TypedArray::<Decimal128<4, 2>>::try_from(&array as &dyn Array).is_err());
+        assert!(TypedArray::<Option<Decimal128<4, 2>>>::try_from(&array as &dyn Array).is_err());
+        assert!(Decimal128::<4, 2>::try_from(123_456_789).is_err());
+
+        // Values that are masked by nulls are not checked
+        let array = Decimal256Array::new(
+            vec![i256::from_i128(-5), i256::from_i128(123_456_789)].into(),
+            Some(NullBuffer::from(vec![true, false])),
+        )
+        .with_precision_and_scale(4, 2)
+        .unwrap();
+        assert!(TypedArray::<Option<Decimal256<4, 2>>>::try_from(&array as &dyn Array).is_ok());
+        assert!(Decimal256::<4, 2>::try_from(i256::from_i128(123_456_789)).is_err());
+    }
+
+    // FIXME: Since f16 does not implement Arbitrary yet, it cannot leverage the
+    //        above test macro and needs a custom test harness
+    mod f16 {
+        use super::*;
+        use half::f16;
+
+        fn any_f16_opt() -> impl Strategy<Value = Option<f16>> {
+            prop_oneof![
+                Just(None),
+                any::<u16>().prop_map(|bits| Some(f16::from_bits(bits)))
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn read_options(values in prop::collection::vec(any_f16_opt(), 0..100)) {
+                let mut builder = TypedBuilder::<Option<f16>>::new();
+                builder.extend(values.iter().copied());
+                let array = builder.finish();
+                check_values(&array, &values)?;
+                let values = values.into_iter().flatten().collect::<Vec<_>>();
+                let mut builder = TypedBuilder::<f16>::new();
+                builder.extend_from_slice(&values);
+                check_values(&builder.finish(), &values)?;
+            }
+        }
+    }
+}
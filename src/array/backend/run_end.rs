@@ -0,0 +1,253 @@
+//! Strong typing layer on top of [`RunArray`]
+//!
+//! Run-end encoded arrays are read out as decoded values, and in bulk as a
+//! sequence of runs.
+
+use super::{validate_masked_child, TypedBackend};
+use crate::{
+    array::{ArrayBackend, ArrayValue},
+    types::run_end::{RunEnd, RunEndArraySlice, RunEndIndex, RunEndValue},
+};
+use arrow_array::{Array, RunArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// Run values of a run array, if they have the expected type
+fn try_run_values<R: RunEndIndex, V: RunEndValue + ?Sized>(
+    array: &RunArray<R::Arrow>,
+) -> Result<&ArrayBackend<V>, ArrowError>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    let values = array.values();
+    values
+        .as_any()
+        .downcast_ref::<ArrayBackend<V>>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "run values of data type {} cannot be read as {}",
+                values.data_type(),
+                std::any::type_name::<V>()
+            ))
+        })
+}
+
+/// Run values of a run array that has been validated
+fn run_values<R: RunEndIndex, V: RunEndValue + ?Sized>(
+    array: &RunArray<R::Arrow>,
+) -> &ArrayBackend<V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    try_run_values::<R, V>(array).expect("run values were checked by validate()")
+}
+
+/// Check that the run values of a run array are valid values of type `V`
+///
+/// Null run values are accepted even if `V` is not nullable, since the run
+/// array itself is what carries nullability.
+fn validate_run_values<R: RunEndIndex, V: RunEndValue + ?Sized>(
+    array: &RunArray<R::Arrow>,
+) -> Result<(), ArrowError>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    let values = array.values();
+    validate_masked_child::<V>(values.as_ref(), values.nulls())
+}
+
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> TypedBackend<RunEnd<R, V>> for RunArray<R::Arrow>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Value<'a> = ArrayValue<'a, V>;
+    type Slice<'a> = RunEndArraySlice<'a, RunEnd<R, V>>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> ArrayValue<'_, V> {
+        let run = self.run_ends().get_physical_index(index);
+        // SAFETY: Run arrays have a value for each run, and the physical
+        //         index of an in-bounds element is that of an existing run.
+        unsafe { run_values::<R, V>(self).value_unchecked(run) }
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> RunEndArraySlice<'_, RunEnd<R, V>> {
+        RunEndArraySlice::new(self, run_values::<R, V>(self), range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_run_values::<R, V>(self)?;
+        match self.logical_nulls().map_or(0, |nulls| nulls.null_count()) {
+            0 => Ok(()),
+            null_count => Err(ArrowError::InvalidArgumentError(format!(
+                "an array of {} cannot contain nulls, but this array contains {null_count} null(s)",
+                std::any::type_name::<RunEnd<R, V>>()
+            ))),
+        }
+    }
+}
+
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> TypedBackend<Option<RunEnd<R, V>>>
+    for RunArray<R::Arrow>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Value<'a> = Option<ArrayValue<'a, V>>;
+    type Slice<'a> = RunEndArraySlice<'a, Option<RunEnd<R, V>>>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<ArrayValue<'_, V>> {
+        let run = self.run_ends().get_physical_index(index);
+        let values = run_values::<R, V>(self);
+        // SAFETY: Run arrays have a value for each run, and the physical
+        //         index of an in-bounds element is that of an existing run.
+        values
+            .is_valid(run)
+            .then(|| unsafe { values.value_unchecked(run) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> RunEndArraySlice<'_, Option<RunEnd<R, V>>> {
+        RunEndArraySlice::new(self, run_values::<R, V>(self), range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        try_run_values::<R, V>(self)?;
+        validate_run_values::<R, V>(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypedBackend;
+    use crate::{
+        array::tests::check_values, tests::vec_and_subslice, types::run_end::RunEnd, TypedArray,
+        TypedBuilder,
+    };
+    use arrow_array::{
+        builder::StringRunBuilder, types::Int16Type, ArrayRef, Int16Array, RunArray, StringArray,
+    };
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    /// Generate strings that are often repeated
+    fn repeated_strings() -> impl Strategy<Value = Vec<Option<String>>> {
+        any::<Vec<Option<bool>>>().prop_map(|values| {
+            values
+                .into_iter()
+                .map(|v| v.map(|v| format!("#{}", u8::from(v))))
+                .collect()
+        })
+    }
+
+    /// Build an array of optional run-end encoded strings
+    fn build_options(strings: &[Option<String>]) -> TypedArray<Option<RunEnd<i16, String>>> {
+        let mut builder = TypedBuilder::<Option<RunEnd<i16, String>>>::new();
+        for string in strings {
+            builder.push(string.as_deref()).unwrap();
+        }
+        builder.finish()
+    }
+
+    /// Expand a sequence of runs into the values that it encodes
+    fn expand<T: Clone>(runs: impl IntoIterator<Item = (T, usize)>) -> Vec<T> {
+        runs.into_iter()
+            .flat_map(|(value, len)| std::iter::repeat_n(value, len))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<bool>) {
+            let values = values.into_iter().map(u8::from).collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<RunEnd<i32, u8>>::new();
+            builder.extend_from_slice(&values)?;
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.len(), values.len());
+            prop_assert_eq!(slice.is_empty(), values.is_empty());
+            prop_assert_eq!(slice.get(values.len()), None);
+            for (index, &value) in values.iter().enumerate() {
+                prop_assert_eq!(slice.get(index), Some(value));
+            }
+            let runs = slice.runs().collect::<Vec<_>>();
+            prop_assert_eq!(runs.len(), slice.num_runs());
+            prop_assert!(runs.windows(2).all(|w| w[0].0 != w[1].0 && w[0].1 > 0));
+            prop_assert_eq!(expand(runs.iter().copied()), values.clone());
+            prop_assert!(slice.runs().rev().eq(runs.into_iter().rev()));
+        }
+
+        #[test]
+        fn read_options(strings in repeated_strings()) {
+            let array = build_options(&strings);
+            check_values(&array, &strings)?;
+            prop_assert_eq!(array.null_count(), strings.iter().filter(|s| s.is_none()).count());
+            let runs = array.as_slice().runs().collect::<Vec<_>>();
+            let expected = strings.iter().map(Option::as_deref).collect::<Vec<_>>();
+            prop_assert_eq!(expand(runs), expected);
+        }
+
+        #[test]
+        fn read_arrow(strings in repeated_strings()) {
+            let mut builder = StringRunBuilder::<Int16Type>::new();
+            builder.extend(strings.iter().map(Option::as_deref));
+            let array: ArrayRef = Arc::new(builder.finish());
+            prop_assert!(TypedArray::<Option<RunEnd<i32, String>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<RunEnd<i16, Vec<u8>>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<String>>::try_from(array.clone()).is_err());
+            prop_assert_eq!(
+                TypedArray::<RunEnd<i16, str>>::try_from(array.clone()).is_ok(),
+                strings.iter().all(Option::is_some)
+            );
+            let array = TypedArray::<Option<RunEnd<i16, str>>>::try_from(array)?;
+            check_values(&array, &strings)?;
+        }
+
+        #[test]
+        fn try_from((strings, offset, len) in vec_and_subslice::<Option<bool>>()) {
+            let strings = strings
+                .into_iter()
+                .map(|v| v.map(|v| format!("#{}", u8::from(v))))
+                .collect::<Vec<_>>();
+            let array = build_options(&strings).into_array_ref().slice(offset, len);
+            let strings = &strings[offset..offset + len];
+
+            let options = TypedArray::<Option<RunEnd<i16, String>>>::try_from(array.clone())?;
+            check_values(&options, strings)?;
+            let runs = options.as_slice().runs().collect::<Vec<_>>();
+            prop_assert!(runs.iter().all(|&(_, len)| len > 0));
+            let expected = strings.iter().map(Option::as_deref).collect::<Vec<_>>();
+            prop_assert_eq!(expand(runs), expected);
+
+            let result = TypedArray::<RunEnd<i16, String>>::try_from(array);
+            if strings.iter().all(Option::is_some) {
+                let strings = strings.iter().flatten().collect::<Vec<_>>();
+                check_values(&result?, &strings)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+
+        #[test]
+        fn subslice((values, start, len) in vec_and_subslice::<bool>()) {
+            let mut builder = TypedBuilder::<RunEnd<i16, bool>>::new();
+            builder.extend_from_slice(&values)?;
+            let array = builder.finish();
+            let slice = TypedBackend::<RunEnd<i16, bool>>::as_subslice(&array.0, start..start + len);
+            prop_assert_eq!(slice.len(), len);
+            prop_assert_eq!(expand(slice.runs()), &values[start..start + len]);
+        }
+    }
+
+    #[test]
+    fn unused_null_values() {
+        let run_ends = Int16Array::from(vec![2, 4]);
+        let values = StringArray::from(vec![Some("a"), None]);
+        let array: ArrayRef = Arc::new(RunArray::try_new(&run_ends, &values).unwrap());
+        assert!(TypedArray::<RunEnd<i16, String>>::try_from(array.clone()).is_err());
+        let array = TypedArray::<RunEnd<i16, String>>::try_from(array.slice(0, 2)).unwrap();
+        assert!(array.iter().eq(["a", "a"]));
+    }
+}
@@ -0,0 +1,387 @@
+//! Strong typing layer on top of [`StructArray`]
+//!
+//! [`StructElement`]s are read out as the tuple of their fields, which is then
+//! converted back into the struct when reading individual values.
+
+use super::{validate_masked_child, validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::{ArrayBackend, ArraySlice, ArrayValue, OptionArraySlice},
+    types::{structs::StructElement, tuple::for_each_tuple},
+    ArrayElement,
+};
+use arrow_array::{Array, StructArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+/// `index`-th field of a struct array, as the concrete array type used to
+/// store Ts
+///
+/// # Panics
+///
+/// Panics if the field is not stored in the array type that is used for Ts.
+/// This cannot happen after the array has been validated.
+fn field<T: ArrayElement>(array: &StructArray, index: usize) -> &ArrayBackend<T> {
+    array
+        .column(index)
+        .as_any()
+        .downcast_ref::<ArrayBackend<T>>()
+        .expect("struct fields should have been validated")
+}
+
+/// Check that a struct array has the expected number of fields
+fn validate_num_fields(array: &StructArray, expected: usize) -> Result<(), ArrowError> {
+    match array.num_columns() {
+        num_columns if num_columns == expected => Ok(()),
+        num_columns => Err(ArrowError::InvalidArgumentError(format!(
+            "expected a struct array with {expected} field(s), got {num_columns} field(s)"
+        ))),
+    }
+}
+
+/// Check that a struct array has the field names of a [`StructElement`]
+fn validate_field_names<T: StructElement>(array: &StructArray) -> Result<(), ArrowError> {
+    let names = array.column_names();
+    if names != T::FIELD_NAMES {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "expected a struct array with fields {:?}, got fields {names:?}",
+            T::FIELD_NAMES
+        )));
+    }
+    Ok(())
+}
+
+// Enable strongly typed readout of tuples
+macro_rules! impl_tuple_array {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t: ArrayElement),+> TypedBackend<($($t,)+)> for StructArray
+        where
+            $(ArrayBackend<$t>: TypedBackend<$t>,)+
+        {
+            type Value<'a> = ($(ArrayValue<'a, $t>,)+);
+            type Slice<'a> = ($(ArraySlice<'a, $t>,)+);
+
+            #[inline]
+            unsafe fn value_unchecked(&self, index: usize) -> ($(ArrayValue<'_, $t>,)+) {
+                // SAFETY: Struct fields have the same length as the struct, and
+                //         the caller guarantees that index is in bounds
+                ($(unsafe { field::<$t>(self, $idx).value_unchecked(index) },)+)
+            }
+
+            fn as_subslice(&self, range: Range<usize>) -> ($(ArraySlice<'_, $t>,)+) {
+                ($(field::<$t>(self, $idx).as_subslice(range.clone()),)+)
+            }
+
+            fn validate(&self) -> Result<(), ArrowError> {
+                validate_non_null::<($($t,)+)>(self)?;
+                TypedBackend::<Option<($($t,)+)>>::validate(self)
+            }
+        }
+
+        impl<$($t: ArrayElement),+> TypedBackend<Option<($($t,)+)>> for StructArray
+        where
+            $(ArrayBackend<$t>: TypedBackend<$t>,)+
+        {
+            type Value<'a> = Option<($(ArrayValue<'a, $t>,)+)>;
+            type Slice<'a> = OptionArraySlice<'a, ($($t,)+)>;
+
+            #[inline]
+            unsafe fn value_unchecked(&self, index: usize) -> Option<($(ArrayValue<'_, $t>,)+)> {
+                self.is_valid(index).then(|| unsafe {
+                    TypedBackend::<($($t,)+)>::value_unchecked(self, index)
+                })
+            }
+
+            fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, ($($t,)+)> {
+                OptionArraySlice {
+                    values: TypedBackend::<($($t,)+)>::as_subslice(self, range.clone()),
+                    is_valid: validity_subslice(self, range),
+                }
+            }
+
+            fn validate(&self) -> Result<(), ArrowError> {
+                validate_num_fields(self, [$($idx),+].len())?;
+                $(validate_masked_child::<$t>(self.column($idx).as_ref(), self.nulls())?;)+
+                Ok(())
+            }
+        }
+    };
+}
+//
+for_each_tuple!(impl_tuple_array);
+
+impl<T: StructElement> TypedBackend<T> for StructArray
+where
+    StructArray: TypedBackend<T::Fields>,
+{
+    type Value<'a> = T;
+    type Slice<'a> = ArraySlice<'a, T::Fields>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> T {
+        T::from_fields(unsafe { TypedBackend::<T::Fields>::value_unchecked(self, index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> ArraySlice<'_, T::Fields> {
+        TypedBackend::<T::Fields>::as_subslice(self, range)
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        TypedBackend::<T::Fields>::validate(self)?;
+        validate_field_names::<T>(self)
+    }
+}
+
+impl<T: StructElement> TypedBackend<Option<T>> for StructArray
+where
+    Option<T::Fields>: ArrayElement,
+    StructArray: TypedBackend<T::Fields> + TypedBackend<Option<T::Fields>>,
+{
+    type Value<'a> = Option<T>;
+    type Slice<'a> = OptionArraySlice<'a, T>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<T> {
+        self.is_valid(index)
+            .then(|| unsafe { TypedBackend::<T>::value_unchecked(self, index) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, T> {
+        OptionArraySlice {
+            values: TypedBackend::<T>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        TypedBackend::<Option<T::Fields>>::validate(self)?;
+        validate_field_names::<T>(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "derive")]
+    use crate::ArrayElement;
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        tests::vec_and_subslice,
+        types::dictionary::Dictionary,
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::{Array, DictionaryArray, Int32Array, StructArray, UInt8Array};
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::{DataType, Field};
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    /// Tuple type used in most tests
+    type Element = (i16, bool);
+
+    /// Struct type with the same fields as `Element`
+    #[cfg(feature = "derive")]
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    struct Record {
+        int: i16,
+        #[array_element(rename = "flag")]
+        bool: bool,
+    }
+
+    /// Struct type whose fields are not read out as themselves
+    #[cfg(feature = "derive")]
+    #[derive(ArrayElement, Clone, Debug, PartialEq)]
+    struct Profile {
+        name: String,
+        nickname: Option<String>,
+        avatar: Vec<u8>,
+        tags: (u8, Option<String>),
+        record: Option<Record>,
+    }
+
+    /// Build an array of optional tuples
+    fn build_options(values: &[Option<Element>]) -> TypedArray<Option<Element>> {
+        let mut builder = TypedBuilder::<Option<Element>>::new();
+        for &value in values {
+            builder.push(value).unwrap();
+        }
+        builder.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<(u8, String)>) {
+            let mut builder = TypedBuilder::<(u8, String)>::new();
+            for (int, string) in &values {
+                builder.push((*int, string))?;
+            }
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+
+            let (ints, strings) = array.as_slice();
+            prop_assert!(ints.iter().eq(values.iter().map(|(int, _)| int)));
+            prop_assert!(strings.iter().eq(values.iter().map(|(_, string)| string)));
+        }
+
+        #[test]
+        fn read_options(values: Vec<Option<Element>>) {
+            let array = build_options(&values);
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+            let slice = array.as_slice();
+            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            let (ints, bools) = slice.values;
+            prop_assert_eq!(ints.len(), values.len());
+            prop_assert_eq!(bools.len(), values.len());
+            for (index, expected) in values.iter().enumerate() {
+                if let Some((int, bool)) = expected {
+                    prop_assert_eq!(ints[index], *int);
+                    prop_assert_eq!(bools.get(index), Some(*bool));
+                }
+            }
+        }
+
+        #[test]
+        fn try_from((values, offset, len) in vec_and_subslice::<Option<Element>>()) {
+            let array = build_options(&values).into_array_ref().slice(offset, len);
+            let values = &values[offset..offset + len];
+
+            let options = TypedArray::<Option<Element>>::try_from(array.clone())?;
+            check_values(&options, values)?;
+            prop_assert!(TypedArray::<Option<(i32, bool)>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<(i16,)>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<(i16, bool, bool)>>::try_from(array.clone()).is_err());
+
+            let result = TypedArray::<Element>::try_from(array);
+            if values.iter().all(Option::is_some) {
+                let values = values.iter().copied().flatten().collect::<Vec<_>>();
+                check_values(&result?, &values)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+
+        #[cfg(feature = "derive")]
+        #[test]
+        fn read_structs((values, offset, len) in vec_and_subslice::<Option<Element>>()) {
+            let records = values
+                .iter()
+                .map(|value| value.map(|(int, bool)| Record { int, bool }))
+                .collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<Option<Record>>::new();
+            for &record in &records {
+                builder.push(record)?;
+            }
+            let array = builder.finish();
+            check_values(&array, &records)?;
+            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&array.as_slice(), &is_valid)?;
+
+            let array = array.into_array_ref().slice(offset, len);
+            let records = &records[offset..offset + len];
+            check_values(&TypedArray::<Option<Record>>::try_from(array.clone())?, records)?;
+            prop_assert!(TypedArray::<Option<(i16, bool)>>::try_from(array.clone()).is_ok());
+            let result = TypedArray::<Record>::try_from(array);
+            if records.iter().all(Option::is_some) {
+                let records = records.iter().copied().flatten().collect::<Vec<_>>();
+                check_values(&result?, &records)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+
+            // Field names must match
+            let tuples = build_options(&values).into_array_ref();
+            prop_assert!(TypedArray::<Option<Record>>::try_from(tuples).is_err());
+        }
+
+        #[cfg(feature = "derive")]
+        #[test]
+        fn read_owned_fields(
+            values: Vec<(String, Option<String>, Vec<u8>, (u8, Option<String>), Option<Element>)>,
+        ) {
+            let profiles = values
+                .into_iter()
+                .map(|(name, nickname, avatar, tags, record)| Profile {
+                    name,
+                    nickname,
+                    avatar,
+                    tags,
+                    record: record.map(|(int, bool)| Record { int, bool }),
+                })
+                .collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<Profile>::new();
+            for profile in &profiles {
+                builder.push(profile.clone())?;
+            }
+            let array = builder.finish();
+            check_values(&array, &profiles)?;
+
+            let array = TypedArray::<Profile>::try_from(array.into_array_ref())?;
+            check_values(&array, &profiles)?;
+        }
+
+        #[test]
+        fn try_from_nullable_fields(fields: Vec<Option<i32>>, structs_valid: Vec<bool>) {
+            let len = fields.len().min(structs_valid.len());
+            let structs_valid = &structs_valid[..len];
+            let fields = Int32Array::from(fields[..len].to_vec());
+            let array = StructArray::new(
+                vec![Field::new("0", DataType::Int32, true)].into(),
+                vec![Arc::new(fields.clone())],
+                Some(NullBuffer::from(structs_valid)),
+            );
+
+            let masked = fields.iter().zip(structs_valid).all(|(field, &valid)| field.is_some() || !valid);
+            prop_assert!(TypedArray::<Option<(Option<i32>,)>>::try_from(&array as &dyn Array).is_ok());
+            prop_assert_eq!(
+                TypedArray::<Option<(i32,)>>::try_from(&array as &dyn Array).is_ok(),
+                masked
+            );
+        }
+
+        #[test]
+        fn try_from_nested_nulls(values: Vec<Option<(u8, Option<Element>)>>) {
+            let mut builder = TypedBuilder::<Option<(u8, Option<Element>)>>::new();
+            for &value in &values {
+                builder.push(value)?;
+            }
+            let array = builder.finish().into_array_ref();
+            prop_assert!(TypedArray::<Option<(u8, Option<Element>)>>::try_from(array.clone()).is_ok());
+
+            let all_inner_valid = values.iter().flatten().all(|(_, inner)| inner.is_some());
+            let array = TypedArray::<Option<(u8, Element)>>::try_from(array);
+            prop_assert_eq!(array.is_ok(), all_inner_valid);
+            if let Ok(array) = array {
+                let expected = values
+                    .iter()
+                    .map(|value| value.map(|(int, inner)| (int, inner.unwrap())))
+                    .collect::<Vec<_>>();
+                check_values(&array, &expected)?;
+            }
+        }
+    }
+    #[test]
+    fn try_from_masked_dictionary_keys() {
+        // The key of a null dictionary entry is not bounds-checked by arrow,
+        // so it must not be exposed when the dictionary nulls are stripped
+        let make_array = |null_key: i32| {
+            let keys = Int32Array::new(
+                vec![0, null_key].into(),
+                Some(NullBuffer::from(vec![true, false])),
+            );
+            let dictionary = DictionaryArray::new(keys, Arc::new(UInt8Array::from(vec![42])));
+            StructArray::new(
+                vec![Field::new("0", dictionary.data_type().clone(), false)].into(),
+                vec![Arc::new(dictionary)],
+                Some(NullBuffer::from(vec![true, false])),
+            )
+        };
+        type Tuple = Option<(Dictionary<i32, u8>,)>;
+
+        let array = make_array(0);
+        let array = TypedArray::<Tuple>::try_from(&array as &dyn Array).unwrap();
+        assert!(array.iter().eq([Some((42,)), None]));
+        let array = make_array(5);
+        assert!(TypedArray::<Tuple>::try_from(&array as &dyn Array).is_err());
+    }
+}
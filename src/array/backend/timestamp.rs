@@ -0,0 +1,148 @@
+//! Strong typing layer on top of timestamp [`PrimitiveArray`]s
+//!
+//! Timestamps with a runtime timezone accept arrays regardless of their
+//! timezone, which can be queried through the array's data type. Timestamps
+//! with a compile-time timezone are primitive types, and are handled by the
+//! primitive array backend instead.
+
+use super::{validate_non_null, validity_subslice, TypedBackend};
+use crate::{
+    array::OptionArraySlice,
+    types::primitive::{TimeUnit, Timestamp},
+};
+use arrow_array::{Array, PrimitiveArray};
+use arrow_schema::ArrowError;
+use std::ops::Range;
+
+impl<Unit: TimeUnit> TypedBackend<Timestamp<Unit>> for PrimitiveArray<Unit::ArrowTimestamp> {
+    type Value<'a> = Timestamp<Unit>;
+    type Slice<'a> = &'a [Timestamp<Unit>];
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Timestamp<Unit> {
+        unsafe { self.value_unchecked(index) }.into()
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> &[Timestamp<Unit>] {
+        let native_slice: &[i64] = &self.values()[range];
+        // SAFETY: Timestamp is a repr(transparent) wrapper over i64
+        unsafe {
+            std::slice::from_raw_parts(
+                native_slice.as_ptr().cast::<Timestamp<Unit>>(),
+                native_slice.len(),
+            )
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        validate_non_null::<Timestamp<Unit>>(self)
+    }
+}
+
+impl<Unit: TimeUnit> TypedBackend<Option<Timestamp<Unit>>>
+    for PrimitiveArray<Unit::ArrowTimestamp>
+{
+    type Value<'a> = Option<Timestamp<Unit>>;
+    type Slice<'a> = OptionArraySlice<'a, Timestamp<Unit>>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> Option<Timestamp<Unit>> {
+        self.is_valid(index)
+            .then(|| unsafe { self.value_unchecked(index) }.into())
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> OptionArraySlice<'_, Timestamp<Unit>> {
+        OptionArraySlice {
+            values: TypedBackend::<Timestamp<Unit>>::as_subslice(self, range.clone()),
+            is_valid: validity_subslice(self, range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::tests::{check_option_slice, check_values},
+        builder::BuilderConfig,
+        tests::vec_and_subslice,
+        types::primitive::{
+            Microsecond, Millisecond, Nanosecond, Second, Timestamp, TimestampConfig, Utc,
+        },
+        TypedArray, TypedBuilder,
+    };
+    use arrow_array::{Array, ArrayRef, TimestampMillisecondArray};
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    crate::time_zone! {
+        /// Timezone of the arrays built by the tests
+        struct Paris = "Europe/Paris";
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<Timestamp<Nanosecond>>) {
+            let mut builder = TypedBuilder::<Timestamp<Nanosecond>>::new();
+            builder.extend_from_slice(&values);
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+            prop_assert_eq!(array.as_slice(), &values[..]);
+        }
+
+        #[test]
+        fn read_options(values: Vec<Option<Timestamp<Microsecond>>>) {
+            let config = TimestampConfig::with_timezone("UTC");
+            let mut builder = TypedBuilder::<Option<Timestamp<Microsecond>>>::with_config(
+                BuilderConfig::with_backend(None, config),
+            );
+            builder.extend(values.iter().copied());
+            let array = builder.finish();
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), values.iter().filter(|v| v.is_none()).count());
+            let slice = array.as_slice();
+            let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_option_slice(&slice, &is_valid)?;
+            for (value, expected) in slice.values.iter().zip(&values) {
+                if let Some(expected) = expected {
+                    prop_assert_eq!(value, expected);
+                }
+            }
+        }
+
+        #[test]
+        fn try_from((values, offset, len) in vec_and_subslice::<Option<i64>>()) {
+            let array = TimestampMillisecondArray::from(values.clone())
+                .with_timezone("Europe/Paris");
+            let array: ArrayRef = Arc::new(array);
+            let array = array.slice(offset, len);
+            let values = values[offset..offset + len]
+                .iter()
+                .map(|v| v.map(Timestamp::<Millisecond>::from))
+                .collect::<Vec<_>>();
+
+            prop_assert!(TypedArray::<Option<Timestamp<Second>>>::try_from(array.clone()).is_err());
+            prop_assert!(TypedArray::<Option<i64>>::try_from(array.clone()).is_err());
+            prop_assert!(
+                TypedArray::<Option<Timestamp<Millisecond, Utc>>>::try_from(array.clone()).is_err()
+            );
+            prop_assert!(
+                TypedArray::<Option<Timestamp<Millisecond, Paris>>>::try_from(array.clone()).is_ok()
+            );
+            let options = TypedArray::<Option<Timestamp<Millisecond>>>::try_from(array.clone())?;
+            check_values(&options, &values)?;
+
+            let result = TypedArray::<Timestamp<Millisecond>>::try_from(array);
+            if values.iter().all(Option::is_some) {
+                let values = values.iter().flatten().copied().collect::<Vec<_>>();
+                check_values(&result?, &values)?;
+            } else {
+                prop_assert!(result.is_err());
+            }
+        }
+    }
+}
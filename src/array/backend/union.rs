@@ -0,0 +1,345 @@
+//! Strong typing layer on top of [`UnionArray`]
+//!
+//! [`UnionElement`]s are read out by reading the value of their variant from
+//! the matching child array, then converting it back into the enum. Both dense
+//! and sparse unions can be read out, whatever layout the enum is built with.
+
+use super::{validate_masked_child, TypedBackend};
+use crate::{
+    array::ArrayBackend,
+    types::{
+        tuple::for_each_tuple,
+        union::{UnionArraySlice, UnionElement, UnionVariants},
+    },
+    ArrayElement,
+};
+use arrow_array::{Array, UnionArray};
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+use std::ops::Range;
+
+/// Position of the variant with type id `type_id` within `type_ids`
+///
+/// # Panics
+///
+/// Panics if `type_id` is not in `type_ids`. This cannot happen after the
+/// array has been validated.
+fn variant_index(type_ids: &[i8], type_id: i8) -> usize {
+    type_ids
+        .iter()
+        .position(|&id| id == type_id)
+        .expect("union type ids should have been validated")
+}
+
+/// [`UnionVariants`] which can be read out of the children of a [`UnionArray`]
+pub trait VariantArrays: UnionVariants {
+    /// Read the `offset`-th element of the child array of the `variant`-th
+    /// variant, whose type id is `type_ids[variant]`
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be smaller than the length of the child array.
+    unsafe fn value_unchecked<'a>(
+        array: &'a UnionArray,
+        type_ids: &[i8],
+        variant: usize,
+        offset: usize,
+    ) -> Self::ArrayValues<'a>;
+
+    /// Read out the elements within `range` of all child arrays, or the whole
+    /// child arrays if `range` is `None`
+    fn as_subslice<'a>(
+        array: &'a UnionArray,
+        type_ids: &[i8],
+        range: Option<Range<usize>>,
+    ) -> Self::ArraySlices<'a>;
+
+    /// Check that the child arrays contain valid values for their variant
+    /// wherever they are referenced by the union
+    fn validate(array: &UnionArray, type_ids: &[i8]) -> Result<(), ArrowError>;
+}
+
+/// Child array of a union with type id `type_id`, as the concrete array type
+/// used to store Ts
+///
+/// # Panics
+///
+/// Panics if the child is not stored in the array type that is used for Ts.
+/// This cannot happen after the array has been validated.
+fn child<T: ArrayElement>(array: &UnionArray, type_id: i8) -> &ArrayBackend<T> {
+    array
+        .child(type_id)
+        .as_any()
+        .downcast_ref::<ArrayBackend<T>>()
+        .expect("union children should have been validated")
+}
+
+/// Check that the child array of a union with type id `type_id` is a valid
+/// array of T, wherever it is referenced by the union
+fn validate_child<T: ArrayElement>(array: &UnionArray, type_id: i8) -> Result<(), ArrowError>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    let child = array.child(type_id);
+    let referenced = if array.offsets().is_some() {
+        None
+    } else {
+        let selected = array.type_ids().iter().map(|&id| id == type_id);
+        Some(NullBuffer::new(BooleanBuffer::from_iter(selected)))
+    };
+    validate_masked_child::<T>(child.as_ref(), referenced.as_ref())
+}
+
+// Enable strongly typed readout of union children
+macro_rules! impl_variant_arrays {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t: ArrayElement),+> VariantArrays for ($($t,)+)
+        where
+            $(ArrayBackend<$t>: TypedBackend<$t>,)+
+        {
+            #[inline]
+            unsafe fn value_unchecked<'a>(
+                array: &'a UnionArray,
+                type_ids: &[i8],
+                variant: usize,
+                offset: usize,
+            ) -> <Self as UnionVariants>::ArrayValues<'a> {
+                ($(
+                    // SAFETY: The caller guarantees that offset is in bounds
+                    (variant == $idx).then(|| unsafe {
+                        child::<$t>(array, type_ids[$idx]).value_unchecked(offset)
+                    }),
+                )+)
+            }
+
+            fn as_subslice<'a>(
+                array: &'a UnionArray,
+                type_ids: &[i8],
+                range: Option<Range<usize>>,
+            ) -> <Self as UnionVariants>::ArraySlices<'a> {
+                ($({
+                    let child = child::<$t>(array, type_ids[$idx]);
+                    match &range {
+                        Some(range) => child.as_subslice(range.clone()),
+                        None => child.as_slice(),
+                    }
+                },)+)
+            }
+
+            fn validate(array: &UnionArray, type_ids: &[i8]) -> Result<(), ArrowError> {
+                $(validate_child::<$t>(array, type_ids[$idx])?;)+
+                Ok(())
+            }
+        }
+    };
+}
+//
+for_each_tuple!(impl_variant_arrays);
+
+impl<T: UnionElement> TypedBackend<T> for UnionArray
+where
+    T::Variants: VariantArrays,
+{
+    type Value<'a> = T;
+    type Slice<'a> = UnionArraySlice<'a, T>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> T {
+        // SAFETY: The caller guarantees that index is in bounds
+        let type_id = unsafe { *self.type_ids().get_unchecked(index) };
+        let offset = match self.offsets() {
+            // SAFETY: The caller guarantees that index is in bounds
+            Some(offsets) => unsafe { *offsets.get_unchecked(index) as usize },
+            None => index,
+        };
+        let variant = variant_index(T::TYPE_IDS, type_id);
+        // SAFETY: Dense offsets have been validated to be in bounds, and the
+        //         children of sparse unions have the length of the union
+        T::from_variant(unsafe { T::Variants::value_unchecked(self, T::TYPE_IDS, variant, offset) })
+    }
+
+    fn as_subslice(&self, range: Range<usize>) -> UnionArraySlice<'_, T> {
+        let offsets = self.offsets().map(|offsets| &offsets[range.clone()]);
+        let child_range = offsets.is_none().then(|| range.clone());
+        UnionArraySlice {
+            type_ids: &self.type_ids()[range],
+            offsets,
+            variants: T::Variants::as_subslice(self, T::TYPE_IDS, child_range),
+        }
+    }
+
+    fn validate(&self) -> Result<(), ArrowError> {
+        let DataType::Union(fields, _mode) = self.data_type() else {
+            unreachable!("union arrays should have a union data type")
+        };
+        let (type_ids, names): (Vec<i8>, Vec<&str>) = fields
+            .iter()
+            .map(|(type_id, field)| (type_id, field.name().as_str()))
+            .unzip();
+        if type_ids != T::TYPE_IDS || names != T::VARIANT_NAMES {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected a union array with type ids {:?} and variants {:?}, \
+                 got type ids {type_ids:?} and variants {names:?}",
+                T::TYPE_IDS,
+                T::VARIANT_NAMES,
+            )));
+        }
+        if let Some(type_id) = self
+            .type_ids()
+            .iter()
+            .find(|type_id| !T::TYPE_IDS.contains(type_id))
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "union array contains unknown type id {type_id}"
+            )));
+        }
+        if let Some(offsets) = self.offsets() {
+            for (&type_id, &offset) in self.type_ids().iter().zip(offsets.iter()) {
+                let child_len = self.child(type_id).len();
+                if usize::try_from(offset).map_or(true, |offset| offset >= child_len) {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "union offset {offset} is out of bounds of a child array of length {child_len}"
+                    )));
+                }
+            }
+        }
+        T::Variants::validate(self, T::TYPE_IDS)
+    }
+}
+
+// All tests use derived enums, which need the derive feature
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{
+        array::tests::check_values, builder::BackendConfig, tests::vec_and_subslice, ArrayElement,
+        TypedArray, TypedBuilder,
+    };
+    use arrow_schema::ArrowError;
+    use proptest::prelude::*;
+
+    /// Enum type used in most tests, stored as a dense union
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    enum Dense {
+        Empty,
+        Int(u16),
+        Pair(bool, i32),
+    }
+    //
+    impl Arbitrary for Dense {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+        fn arbitrary_with((): ()) -> Self::Strategy {
+            prop_oneof![
+                Just(Self::Empty),
+                any::<u16>().prop_map(Self::Int),
+                any::<(bool, i32)>().prop_map(|(b, i)| Self::Pair(b, i)),
+            ]
+            .boxed()
+        }
+    }
+
+    /// Same variants as `Dense`, but stored as a sparse union
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    #[array_element(sparse)]
+    enum Sparse {
+        Empty,
+        Int(u16),
+        Pair(bool, i32),
+    }
+    //
+    impl From<Dense> for Sparse {
+        fn from(value: Dense) -> Self {
+            match value {
+                Dense::Empty => Self::Empty,
+                Dense::Int(i) => Self::Int(i),
+                Dense::Pair(b, i) => Self::Pair(b, i),
+            }
+        }
+    }
+
+    /// Same variants as `Dense`, but with different type ids
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    enum Renumbered {
+        #[array_element(type_id = 3)]
+        Empty,
+        Int(u16),
+        Pair(bool, i32),
+    }
+
+    /// Build an array of enums
+    fn build<T>(values: &[T]) -> TypedArray<T>
+    where
+        T: for<'a> ArrayElement<
+            Slice<'a> = &'a [T],
+            ExtendFromSliceResult = Result<(), ArrowError>,
+        >,
+        BackendConfig<T>: Default,
+    {
+        let mut builder = TypedBuilder::<T>::new();
+        builder.extend_from_slice(values).unwrap();
+        builder.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn read_values(values: Vec<Dense>) {
+            let array = build(&values);
+            check_values(&array, &values)?;
+            prop_assert_eq!(array.null_count(), 0);
+            let slice = array.as_slice();
+            prop_assert_eq!(slice.type_ids.len(), values.len());
+            let offsets = slice.offsets.expect("dense unions have offsets");
+            prop_assert_eq!(offsets.len(), values.len());
+            let (empty, ints, (bools, pairs)) = slice.variants;
+            for ((value, &type_id), &offset) in values.iter().zip(slice.type_ids).zip(offsets) {
+                let offset = offset as usize;
+                match *value {
+                    Dense::Empty => {
+                        prop_assert_eq!(type_id, 0);
+                        prop_assert!(offset < empty);
+                    }
+                    Dense::Int(int) => {
+                        prop_assert_eq!(type_id, 1);
+                        prop_assert_eq!(ints[offset], int);
+                    }
+                    Dense::Pair(bool, int) => {
+                        prop_assert_eq!(type_id, 2);
+                        prop_assert_eq!(bools.get(offset), Some(bool));
+                        prop_assert_eq!(pairs[offset], int);
+                    }
+                }
+            }
+
+            let values = values.into_iter().map(Sparse::from).collect::<Vec<_>>();
+            let array = build(&values);
+            check_values(&array, &values)?;
+            let slice = array.as_slice();
+            prop_assert!(slice.offsets.is_none());
+            let (empty, ints, (bools, pairs)) = slice.variants;
+            prop_assert_eq!(empty, values.len());
+            prop_assert_eq!(ints.len(), values.len());
+            prop_assert_eq!(bools.len(), values.len());
+            prop_assert_eq!(pairs.len(), values.len());
+        }
+
+        #[test]
+        fn try_from((values, offset, len) in vec_and_subslice::<Dense>()) {
+            let sparse_values = values.iter().copied().map(Sparse::from).collect::<Vec<_>>();
+            let dense = build(&values).into_array_ref().slice(offset, len);
+            let sparse = build(&sparse_values).into_array_ref().slice(offset, len);
+            let values = &values[offset..offset + len];
+            let sparse_values = &sparse_values[offset..offset + len];
+
+            // Both union layouts can be read, whatever the enum is built with
+            check_values(&TypedArray::<Dense>::try_from(dense.clone())?, values)?;
+            check_values(&TypedArray::<Dense>::try_from(sparse.clone())?, values)?;
+            check_values(&TypedArray::<Sparse>::try_from(dense.clone())?, sparse_values)?;
+            check_values(&TypedArray::<Sparse>::try_from(sparse.clone())?, sparse_values)?;
+
+            // Type ids and variant types must match
+            prop_assert!(TypedArray::<Renumbered>::try_from(dense.clone()).is_err());
+            prop_assert!(TypedArray::<Renumbered>::try_from(sparse).is_err());
+            prop_assert!(TypedArray::<(u16,)>::try_from(dense).is_err());
+        }
+    }
+}
@@ -0,0 +1,447 @@
+//! Strongly typed array
+
+pub(crate) mod backend;
+
+use self::backend::TypedBackend;
+#[cfg(doc)]
+use crate::TypedBuilder;
+use crate::{
+    builder::{backend::Backend, BuilderBackend},
+    validity::ValiditySlice,
+    ArrayElement,
+};
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::ArrowError;
+use std::{fmt::Debug, iter::FusedIterator, ops::Range, sync::Arc};
+
+/// Strongly typed array
+///
+/// This is a thin wrapper around the concrete arrow array type that stores
+/// elements of type `T`, which is typically obtained by calling
+/// [`TypedBuilder::finish()`].
+pub struct TypedArray<T: ArrayElement + ?Sized>(ArrayBackend<T>);
+//
+impl<T: ArrayElement + ?Sized> TypedArray<T> {
+    /// Wrap a concrete arrow array that is known to contain elements of type T
+    pub(crate) fn new(array: ArrayBackend<T>) -> Self {
+        Self(array)
+    }
+
+    /// Number of elements in the array
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<i8>::new();
+    /// builder.extend_from_slice(&[-1, 0, 1]);
+    /// assert_eq!(builder.finish().len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Truth that the array contains no element
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<i8>::new();
+    /// assert!(builder.finish_cloned().is_empty());
+    /// builder.push(42);
+    /// assert!(!builder.finish().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of null elements in the array
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<Option<u64>>::new();
+    /// builder.extend([Some(1), None, Some(3), None]);
+    /// assert_eq!(builder.finish().null_count(), 2);
+    /// ```
+    ///
+    /// Nulls are counted at the logical level, so every element of an array
+    /// of [`Null`](crate::types::primitive::Null)s is counted as a null even
+    /// though the underlying arrow array has no validity bitmap.
+    pub fn null_count(&self) -> usize {
+        self.0.logical_nulls().map_or(0, |nulls| nulls.null_count())
+    }
+
+    /// Convert into a type-erased arrow [`ArrayRef`]
+    ///
+    /// This conversion is lossless, the resulting `ArrayRef` can be used with
+    /// any arrow-rs API that accepts arrays.
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// use arrow_array::Array;
+    ///
+    /// let mut builder = TypedBuilder::<u16>::new();
+    /// builder.extend_from_slice(&[4, 8, 15, 16, 23, 42]);
+    /// let array = builder.finish().into_array_ref();
+    /// assert_eq!(array.len(), 6);
+    /// ```
+    pub fn into_array_ref(self) -> ArrayRef {
+        Arc::new(self.0)
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> TypedArray<T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    /// Value of the `index`-th element, if in bounds
+    ///
+    /// Whenever arrow's memory layout allows for it, elements are read out as
+    /// [`T::Value`](ArrayElement::Value), the same type that is accepted by
+    /// [`TypedBuilder::push()`]. The exception is list-like types, whose
+    /// elements are read out using the [bulk readout](Self::as_slice) layout
+    /// of their items.
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<Option<i32>>::new();
+    /// builder.extend([Some(12), None]);
+    /// let array = builder.finish();
+    /// assert_eq!(array.get(0), Some(Some(12)));
+    /// assert_eq!(array.get(1), Some(None));
+    /// assert_eq!(array.get(2), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<ArrayValue<'_, T>> {
+        (index < self.len()).then(|| unsafe { self.0.value_unchecked(index) })
+    }
+
+    /// Value of the `index`-th element, with panic-based bounds checking
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<bool>::new();
+    /// builder.extend_from_slice(&[true, false]);
+    /// let array = builder.finish();
+    /// assert!(array.value(0));
+    /// assert!(!array.value(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn value(&self, index: usize) -> ArrayValue<'_, T> {
+        self.get(index).expect("index is out of bounds")
+    }
+
+    /// Iterate over the elements of the array
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<u8>::new();
+    /// builder.extend_from_slice(&[1, 2, 3]);
+    /// let array = builder.finish();
+    /// assert!(array.iter().eq([1, 2, 3]));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            array: self,
+            indices: 0..self.len(),
+        }
+    }
+
+    /// Read out the whole array in bulk
+    ///
+    /// Whenever arrow's memory layout allows for it, this is a zero-copy
+    /// operation that returns the same slice type that is accepted by
+    /// [`TypedBuilder::extend_from_slice()`]. For example, arrays of primitive
+    /// types are read out as `&[T]`...
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<u32>::new();
+    /// builder.extend_from_slice(&[0xdeadbeef, 0xfeedface]);
+    /// let array = builder.finish();
+    /// assert_eq!(array.as_slice(), &[0xdeadbeef, 0xfeedface]);
+    /// ```
+    ///
+    /// ...while arrays of [`Null`](crate::types::primitive::Null) are read out
+    /// as a number of elements.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{TypedBuilder, types::primitive::Null};
+    /// let mut builder = TypedBuilder::<Null>::new();
+    /// builder.extend_with_nulls(42);
+    /// assert_eq!(builder.finish().as_slice(), 42);
+    /// ```
+    ///
+    /// Some types, however, use a bulk readout layout that differs from their
+    /// bulk insertion layout. This happens because arrow stores booleans and
+    /// validity information as bitmaps, which cannot be read out as `&[bool]`.
+    /// Arrays of `bool` are thus read out as [`ValiditySlice`]s, and arrays of
+    /// `Option<T>` are read out as [`OptionArraySlice`]s.
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<Option<f64>>::new();
+    /// builder.extend([Some(1.2), None, Some(3.4)]);
+    /// let array = builder.finish();
+    /// let slice = array.as_slice();
+    /// let validity = slice.is_valid.expect("some elements are null");
+    /// assert_eq!(validity, &[true, false, true][..]);
+    /// assert_eq!(slice.values[0], 1.2);
+    /// assert_eq!(slice.values[2], 3.4);
+    /// ```
+    pub fn as_slice(&self) -> ArraySlice<'_, T> {
+        self.0.as_slice()
+    }
+}
+//
+/// Arrays from other arrow-rs code can be converted into typed arrays, as long
+/// as their data type and contents match the element type `T`.
+///
+/// ```rust
+/// # use arrow_typing::TypedArray;
+/// use arrow_array::{Array, Int32Array};
+///
+/// let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// let typed = TypedArray::<Option<i32>>::try_from(&array as &dyn Array)?;
+/// assert_eq!(typed.get(1), Some(None));
+///
+/// // Non-nullable element types cannot be used to read arrays with nulls...
+/// assert!(TypedArray::<i32>::try_from(&array as &dyn Array).is_err());
+///
+/// // ...and arrays of different types are rejected as well
+/// assert!(TypedArray::<Option<i64>>::try_from(&array as &dyn Array).is_err());
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+impl<T: ArrayElement + ?Sized> TryFrom<&dyn Array> for TypedArray<T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    type Error = ArrowError;
+
+    fn try_from(array: &dyn Array) -> Result<Self, ArrowError> {
+        let typed = array
+            .as_any()
+            .downcast_ref::<ArrayBackend<T>>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "an array of data type {} cannot be read as an array of {}",
+                    array.data_type(),
+                    std::any::type_name::<T>()
+                ))
+            })?;
+        typed.validate()?;
+        Ok(Self(typed.clone()))
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> TryFrom<ArrayRef> for TypedArray<T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    type Error = ArrowError;
+
+    fn try_from(array: ArrayRef) -> Result<Self, ArrowError> {
+        Self::try_from(array.as_ref())
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Clone for TypedArray<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Debug for TypedArray<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TypedArray").field(&self.0).finish()
+    }
+}
+//
+impl<'array, T: ArrayElement + ?Sized> IntoIterator for &'array TypedArray<T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    type Item = ArrayValue<'array, T>;
+    type IntoIter = Iter<'array, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of a [`TypedArray`]
+#[derive(Debug)]
+pub struct Iter<'array, T: ArrayElement + ?Sized> {
+    /// Array that is being iterated over
+    array: &'array TypedArray<T>,
+
+    /// Indices of the elements that have not been yielded yet
+    indices: Range<usize>,
+}
+//
+impl<T: ArrayElement + ?Sized> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array,
+            indices: self.indices.clone(),
+        }
+    }
+}
+//
+impl<'array, T: ArrayElement + ?Sized> DoubleEndedIterator for Iter<'array, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back()?;
+        Some(unsafe { self.array.0.value_unchecked(index) })
+    }
+}
+//
+impl<'array, T: ArrayElement + ?Sized> ExactSizeIterator for Iter<'array, T> where
+    ArrayBackend<T>: TypedBackend<T>
+{
+}
+//
+impl<'array, T: ArrayElement + ?Sized> FusedIterator for Iter<'array, T> where
+    ArrayBackend<T>: TypedBackend<T>
+{
+}
+//
+impl<'array, T: ArrayElement + ?Sized> Iterator for Iter<'array, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    type Item = ArrayValue<'array, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some(unsafe { self.array.0.value_unchecked(index) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+/// Columnar view of an array of `Option<T>`
+///
+/// This is the bulk readout counterpart of [`OptionSlice`](crate::OptionSlice).
+/// It differs from it in that validity information is exposed in the
+/// bit-packed format used by arrow, and that `values` uses the bulk readout
+/// layout of `T`, as returned by [`TypedArray::as_slice()`].
+pub struct OptionArraySlice<'a, T: ArrayElement>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    /// Values that may or may not be valid
+    ///
+    /// The value of invalid elements is unspecified.
+    pub values: ArraySlice<'a, T>,
+
+    /// Validity bitmap, or `None` if all elements are valid
+    pub is_valid: Option<ValiditySlice<'a>>,
+}
+//
+impl<'a, T: ArrayElement> Clone for OptionArraySlice<'a, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+    ArraySlice<'a, T>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            is_valid: self.is_valid,
+        }
+    }
+}
+//
+impl<'a, T: ArrayElement> Copy for OptionArraySlice<'a, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+    ArraySlice<'a, T>: Copy,
+{
+}
+//
+impl<'a, T: ArrayElement> Debug for OptionArraySlice<'a, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OptionArraySlice")
+            .field("values", &self.values)
+            .field("is_valid", &self.is_valid)
+            .finish()
+    }
+}
+
+/// Shortcut to the concrete arrow array type used to store Ts
+pub(crate) type ArrayBackend<T> = <BuilderBackend<T> as Backend>::Array;
+
+/// Shortcut to the individual element readout type of an array of Ts
+pub(crate) type ArrayValue<'a, T> = <ArrayBackend<T> as TypedBackend<T>>::Value<'a>;
+
+/// Shortcut to the bulk readout type of an array of Ts
+pub(crate) type ArraySlice<'a, T> = <ArrayBackend<T> as TypedBackend<T>>::Slice<'a>;
+
+/// Shared test utilities
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    /// Check that a `TypedArray` contains the expected values
+    ///
+    /// Values are compared through their `Debug` representation, which is
+    /// available for every element type and also handles floating-point NaNs.
+    pub fn check_values<T: ArrayElement + ?Sized>(
+        array: &TypedArray<T>,
+        expected: &[impl Debug],
+    ) -> TestCaseResult
+    where
+        ArrayBackend<T>: TypedBackend<T>,
+    {
+        let debug = |value: &dyn Debug| format!("{value:?}");
+        let expected = expected.iter().map(|v| debug(v)).collect::<Vec<_>>();
+        prop_assert_eq!(array.len(), expected.len());
+        prop_assert_eq!(array.is_empty(), expected.is_empty());
+        for (index, expected) in expected.iter().enumerate() {
+            prop_assert_eq!(&debug(&array.get(index)), &format!("Some({expected})"));
+            prop_assert_eq!(&debug(&array.value(index)), expected);
+        }
+        prop_assert!(array.get(array.len()).is_none());
+        prop_assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(
+            || array.value(array.len())
+        ))
+        .is_err());
+        prop_assert_eq!(
+            array.iter().map(|v| debug(&v)).collect::<Vec<_>>(),
+            expected.clone()
+        );
+        prop_assert!(array
+            .iter()
+            .rev()
+            .map(|v| debug(&v))
+            .eq(expected.iter().rev().cloned()));
+        Ok(())
+    }
+
+    /// Check the validity information of an [`OptionArraySlice`]
+    pub fn check_option_slice<T: ArrayElement>(
+        slice: &OptionArraySlice<T>,
+        expected: &[bool],
+    ) -> TestCaseResult
+    where
+        ArrayBackend<T>: TypedBackend<T>,
+    {
+        if let Some(is_valid) = slice.is_valid {
+            prop_assert_eq!(is_valid, expected);
+        } else {
+            prop_assert!(expected.iter().all(|valid| *valid));
+        }
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@
 
 use super::{Backend, TypedBackend, ValiditySlice};
 use crate::{builder::BuilderConfig, OptionSlice};
-use arrow_array::builder::BooleanBuilder;
-use arrow_schema::ArrowError;
+use arrow_array::{builder::BooleanBuilder, BooleanArray};
+use arrow_schema::{ArrowError, DataType};
 
 impl Backend for BooleanBuilder {
+    type Array = BooleanArray;
+
     fn capacity(&self) -> usize {
         self.capacity()
     }
@@ -13,6 +15,19 @@ impl Backend for BooleanBuilder {
     fn extend_with_nulls(&mut self, n: usize) {
         self.append_nulls(n)
     }
+
+    fn truncate(&mut self, len: usize) {
+        let array = self.finish().slice(0, len);
+        self.extend(array.iter());
+    }
+
+    fn finish(&mut self) -> BooleanArray {
+        self.finish()
+    }
+
+    fn finish_cloned(&self) -> BooleanArray {
+        self.finish_cloned()
+    }
 }
 
 impl ValiditySlice for BooleanBuilder {
@@ -24,6 +39,10 @@ impl ValiditySlice for BooleanBuilder {
 impl TypedBackend<bool> for BooleanBuilder {
     type Config = ();
 
+    fn data_type(_config: &()) -> DataType {
+        DataType::Boolean
+    }
+
     fn new(config: BuilderConfig<bool>) -> Self {
         if let Some(capacity) = config.capacity {
             Self::with_capacity(capacity)
@@ -45,6 +64,10 @@ impl TypedBackend<bool> for BooleanBuilder {
 impl TypedBackend<Option<bool>> for BooleanBuilder {
     type Config = ();
 
+    fn data_type(_config: &()) -> DataType {
+        DataType::Boolean
+    }
+
     fn new(config: BuilderConfig<Option<bool>>) -> Self {
         if let Some(capacity) = config.capacity {
             Self::with_capacity(capacity)
@@ -0,0 +1,610 @@
+//! Strong typing layer on top of [`GenericByteViewArray`]
+//!
+//! arrow-rs' `GenericByteViewBuilder` neither tracks capacity nor exposes its
+//! validity buffer, and it cannot deduplicate values. Therefore, we use our
+//! own builder, which mimics the allocation strategy of the arrow-rs one.
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::BuilderConfig,
+    types::bytes::{
+        ByteSlice, ByteViewConfig, ByteViewTypeExt, ViewNativeType, ViewType, MAX_INLINE_LEN,
+    },
+    ArrayElement, OptionSlice,
+};
+use arrow_array::{builder::ArrayBuilder, types::ByteViewType, ArrayRef, GenericByteViewArray};
+use arrow_buffer::{Buffer, NullBufferBuilder, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType};
+use std::{
+    any::Any,
+    collections::{hash_map::RandomState, HashMap},
+    fmt::{self, Debug},
+    hash::BuildHasher,
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// Builder of [`GenericByteViewArray`]s
+pub struct ByteViewBuilder<T: ByteViewType> {
+    /// One view per element
+    views: Vec<u128>,
+
+    /// Validity bitmap
+    nulls: NullBufferBuilder,
+
+    /// Data buffers that are full
+    completed: Vec<Buffer>,
+
+    /// Data buffer that is being filled
+    in_progress: Vec<u8>,
+
+    /// Builder configuration
+    config: ByteViewConfig,
+
+    /// Views of previously inserted non-inlined values, indexed by value hash
+    ///
+    /// This is only used when deduplication is enabled. Only the last value
+    /// with a given hash is tracked, so hash collisions merely result in
+    /// missed deduplication opportunities.
+    dedup: HashMap<u64, u128>,
+
+    /// Hasher used for deduplication
+    hasher: RandomState,
+
+    /// Element type
+    element: PhantomData<T>,
+}
+//
+impl<T: ByteViewType> ByteViewBuilder<T> {
+    /// Default number of elements that builders can hold without reallocating
+    ///
+    /// This is the same as what `GenericByteViewBuilder::new()` uses.
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, config: ByteViewConfig) -> Self {
+        let capacity = capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        Self {
+            views: Vec::with_capacity(capacity),
+            nulls: NullBufferBuilder::new(capacity),
+            completed: Vec::new(),
+            in_progress: Vec::new(),
+            config,
+            dedup: HashMap::new(),
+            hasher: RandomState::new(),
+            element: PhantomData,
+        }
+    }
+
+    /// Append a non-null value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is larger than 4 GiB, or if more than `u32::MAX`
+    /// data buffers are needed.
+    fn append_value(&mut self, value: &[u8]) {
+        let length: u32 = value
+            .len()
+            .try_into()
+            .expect("view arrays cannot hold values larger than 4 GiB");
+
+        // Short values are inlined into the view
+        if value.len() <= MAX_INLINE_LEN {
+            let mut view = [0; 16];
+            view[..4].copy_from_slice(&length.to_le_bytes());
+            view[4..4 + value.len()].copy_from_slice(value);
+            self.views.push(u128::from_le_bytes(view));
+            self.nulls.append_non_null();
+            return;
+        }
+
+        // Reuse the storage of previously inserted values if possible
+        let hash = self.config.deduplicate.then(|| self.hasher.hash_one(value));
+        if let Some(hash) = hash {
+            if let Some(&view) = self.dedup.get(&hash) {
+                if self.view_data(view) == value {
+                    self.views.push(view);
+                    self.nulls.append_non_null();
+                    return;
+                }
+            }
+        }
+
+        // Otherwise, store the value in the in-progress data buffer, using the
+        // same allocation strategy as arrow-rs' GenericByteViewBuilder
+        let required_capacity = self.in_progress.len() + value.len();
+        if self.in_progress.capacity() < required_capacity {
+            let in_progress = Vec::with_capacity(value.len().max(self.config.block_size as usize));
+            let flushed = std::mem::replace(&mut self.in_progress, in_progress);
+            if !flushed.is_empty() {
+                assert!(
+                    self.completed.len() < u32::MAX as usize,
+                    "view arrays cannot have more than u32::MAX data buffers"
+                );
+                self.completed.push(flushed.into());
+            }
+        }
+        let offset = self.in_progress.len() as u32;
+        self.in_progress.extend_from_slice(value);
+        let prefix = u32::from_le_bytes(value[..4].try_into().unwrap());
+        let buffer_index = self.completed.len() as u32;
+        let view = u128::from(length)
+            | (u128::from(prefix) << 32)
+            | (u128::from(buffer_index) << 64)
+            | (u128::from(offset) << 96);
+        self.views.push(view);
+        self.nulls.append_non_null();
+        if let Some(hash) = hash {
+            self.dedup.insert(hash, view);
+        }
+    }
+
+    /// Append a null value
+    fn append_null(&mut self) {
+        self.views.push(0);
+        self.nulls.append_null();
+    }
+
+    /// Data that a non-inlined view points to
+    fn view_data(&self, view: u128) -> &[u8] {
+        let length = view as u32 as usize;
+        let buffer_index = (view >> 64) as u32 as usize;
+        let offset = (view >> 96) as u32 as usize;
+        let buffer = if buffer_index == self.completed.len() {
+            &self.in_progress[..]
+        } else {
+            &self.completed[buffer_index][..]
+        };
+        &buffer[offset..offset + length]
+    }
+}
+//
+impl<T: ByteViewTypeExt> ByteViewBuilder<T> {
+    /// Check that a slice of variable-length data, with optional validity,
+    /// can be appended
+    fn check_byte_slice(
+        values: &ByteSlice<'_, T::ByteArray>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        if let Some(is_valid) = is_valid {
+            if values.len() != is_valid.len() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Value and validity lengths must be equal".to_string(),
+                ));
+            }
+        }
+        values.validate()
+    }
+
+    /// Append a slice of variable-length data, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_byte_slice(
+        &mut self,
+        values: ByteSlice<'_, T::ByteArray>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        Self::check_byte_slice(&values, is_valid)?;
+        self.views.reserve(values.len());
+        match is_valid {
+            Some(is_valid) => {
+                for (value, &valid) in values.iter().zip(is_valid) {
+                    if valid {
+                        self.append_value(AsRef::<[u8]>::as_ref(value));
+                    } else {
+                        self.append_null();
+                    }
+                }
+            }
+            None => {
+                for value in values {
+                    self.append_value(AsRef::<[u8]>::as_ref(value));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+//
+impl<T: ByteViewType> ArrayBuilder for ByteViewBuilder<T> {
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+//
+impl<T: ByteViewType> Debug for ByteViewBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteViewBuilder")
+            .field("views", &self.views)
+            .field("nulls", &self.nulls)
+            .field("completed", &self.completed)
+            .field("in_progress", &self.in_progress)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ByteViewType> Backend for ByteViewBuilder<T> {
+    type Array = GenericByteViewArray<T>;
+
+    fn capacity(&self) -> usize {
+        self.views.capacity()
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        self.views.resize(self.views.len() + n, 0);
+        self.nulls.append_n_nulls(n);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        // The data buffers are kept, since views may be deduplicated
+        self.views.truncate(len);
+        super::truncate_nulls(&mut self.nulls, len);
+    }
+
+    fn finish(&mut self) -> GenericByteViewArray<T> {
+        let mut buffers = std::mem::take(&mut self.completed);
+        if !self.in_progress.is_empty() {
+            buffers.push(std::mem::take(&mut self.in_progress).into());
+        }
+        self.dedup.clear();
+        let views = ScalarBuffer::from(std::mem::take(&mut self.views));
+        let nulls = self.nulls.finish();
+        // SAFETY: Views and buffers are valid by construction
+        unsafe { GenericByteViewArray::new_unchecked(views, buffers, nulls) }
+    }
+
+    fn finish_cloned(&self) -> GenericByteViewArray<T> {
+        let mut buffers = self.completed.clone();
+        if !self.in_progress.is_empty() {
+            buffers.push(Buffer::from_slice_ref(&self.in_progress));
+        }
+        let views = ScalarBuffer::from(self.views.clone());
+        let nulls = self.nulls.finish_cloned();
+        // SAFETY: Views and buffers are valid by construction
+        unsafe { GenericByteViewArray::new_unchecked(views, buffers, nulls) }
+    }
+}
+
+impl<T: ByteViewType> ValiditySlice for ByteViewBuilder<T> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.nulls.as_slice()
+    }
+}
+
+impl<T: ViewType + ?Sized> TypedBackend<T> for ByteViewBuilder<T::Arrow>
+where
+    // FIXME: Remove these bounds once the Rust trait system supports adding
+    //        the appropriate bounds on ViewType to let rustc figure out that
+    //        T::Value<'a> is &'a ViewNativeType<T> and T::Slice<'a> is
+    //        a ByteSlice for view types.
+    for<'a> T::Value<'a>: Into<&'a ViewNativeType<T>>,
+    for<'a> T::Slice<'a>: Into<ByteSlice<'a, <T::Arrow as ByteViewTypeExt>::ByteArray>>,
+{
+    type Config = ByteViewConfig;
+
+    fn data_type(_config: &ByteViewConfig) -> DataType {
+        T::Arrow::DATA_TYPE
+    }
+
+    fn new(config: BuilderConfig<T>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: T::Value<'_>) {
+        self.append_value(AsRef::<[u8]>::as_ref(v.into()))
+    }
+
+    fn extend_from_slice(&mut self, s: T::Slice<'_>) -> Result<(), ArrowError> {
+        self.extend_from_byte_slice(s.into(), None)
+    }
+
+    fn check_extend_from_slice(&self, s: &T::Slice<'_>) -> Result<(), ArrowError> {
+        Self::check_byte_slice(&s.clone().into(), None)
+    }
+}
+
+impl<T: ViewType> TypedBackend<Option<T>> for ByteViewBuilder<T::Arrow>
+where
+    // FIXME: Remove these bounds once the Rust trait system supports adding
+    //        the appropriate bounds on ViewType (see above)
+    for<'a> T::Value<'a>: Into<&'a ViewNativeType<T>>,
+    for<'a> T::Slice<'a>: Into<ByteSlice<'a, <T::Arrow as ByteViewTypeExt>::ByteArray>>,
+    //
+    // FIXME: Remove these bounds once it becomes possible to blanket-impl
+    //        ArrayElement for Option<T: ViewType>, making them obvious.
+    Option<T>: ArrayElement<
+        BuilderBackend = ByteViewBuilder<T::Arrow>,
+        PushResult = (),
+        ExtendFromSliceResult = Result<(), ArrowError>,
+    >,
+    for<'a> <Option<T> as ArrayElement>::Value<'a>: Into<Option<T::Value<'a>>>,
+    for<'a> <Option<T> as ArrayElement>::Slice<'a>: Into<OptionSlice<'a, T>>,
+{
+    type Config = ByteViewConfig;
+
+    fn data_type(_config: &ByteViewConfig) -> DataType {
+        T::Arrow::DATA_TYPE
+    }
+
+    fn new(config: BuilderConfig<Option<T>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: <Option<T> as ArrayElement>::Value<'_>) {
+        let opt: Option<T::Value<'_>> = v.into();
+        match opt {
+            Some(value) => self.append_value(AsRef::<[u8]>::as_ref(value.into())),
+            None => self.append_null(),
+        }
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: <Option<T> as ArrayElement>::Slice<'_>,
+    ) -> Result<(), ArrowError> {
+        let slice: OptionSlice<T> = slice.into();
+        self.extend_from_byte_slice(slice.values.into(), Some(slice.is_valid))
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &<Option<T> as ArrayElement>::Slice<'_>,
+    ) -> Result<(), ArrowError> {
+        let slice: OptionSlice<T> = slice.clone().into();
+        Self::check_byte_slice(&slice.values.into(), Some(slice.is_valid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builder::tests::{
+            check_extend_outcome, check_extend_with_nulls, check_init_default_optional,
+            check_init_with_capacity_optional, check_init_with_capacity_outcome, check_push,
+            check_validity,
+        },
+        tests::length_or_capacity,
+        types::bytes::{
+            tests::{
+                concat, concatenated, offsets_are_valid, values_and_offsets, TestByteArrayType,
+            },
+            BinaryView, Utf8View,
+        },
+        TypedBuilder,
+    };
+    use arrow_array::types::{BinaryViewType, StringViewType};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+    use std::borrow::Borrow;
+
+    /// Generate a view builder configuration
+    fn byte_view_config() -> impl Strategy<Value = ByteViewConfig> {
+        (0..=64u32, any::<bool>()).prop_map(|(block_size, deduplicate)| ByteViewConfig {
+            block_size,
+            deduplicate,
+        })
+    }
+
+    macro_rules! test_view_elements {
+        ($($mod_name:ident : $element:ty => $arrow:ty),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type ByteArray = <$arrow as ByteViewTypeExt>::ByteArray;
+                    type Owned = <ByteArray as TestByteArrayType>::Owned;
+                    type Native = <$arrow as ByteViewType>::Native;
+
+                    /// Create a builder of `T` with some configuration
+                    fn make_builder<T: ArrayElement + ?Sized>(
+                        config: ByteViewConfig,
+                        capacity: usize,
+                    ) -> TypedBuilder<T>
+                    where
+                        T::BuilderBackend: TypedBackend<T, Config = ByteViewConfig>,
+                    {
+                        TypedBuilder::with_config(BuilderConfig::with_backend(Some(capacity), config))
+                    }
+
+                    #[test]
+                    fn init_default() -> TestCaseResult {
+                        check_init_default_optional::<$element>()
+                    }
+
+                    proptest! {
+                        #[test]
+                        fn init_with_capacity(
+                            config in byte_view_config(),
+                            capacity in length_or_capacity(),
+                        ) {
+                            check_init_with_capacity_optional::<$element>(|| config, capacity)?;
+                        }
+
+                        #[test]
+                        fn push_value(
+                            config in byte_view_config(),
+                            init_capacity in length_or_capacity(),
+                            value: Owned,
+                        ) {
+                            check_push::<$element>(config, init_capacity, value.borrow())?;
+                        }
+
+                        #[test]
+                        fn push_option(
+                            config in byte_view_config(),
+                            init_capacity in length_or_capacity(),
+                            value: Option<Owned>,
+                        ) {
+                            let value: Option<&Native> = value.as_ref().map(Borrow::borrow);
+                            let mut builder = make_builder::<Option<$element>>(config, init_capacity);
+                            builder.push(value);
+                            check_extend_outcome(&builder, init_capacity, 1)?;
+                            check_validity(&builder, &[value.is_some()])?;
+                            let array = builder.finish();
+                            prop_assert_eq!(array.value(0), value);
+                        }
+
+                        #[test]
+                        fn extend_from_values(
+                            config in byte_view_config(),
+                            init_capacity in length_or_capacity(),
+                            (values, offsets) in values_and_offsets::<ByteArray>(),
+                        ) {
+                            let (_, bytes) = concat::<ByteArray>(&values);
+                            let values = concatenated::<ByteArray>(&bytes);
+                            let slice = ByteSlice::<ByteArray> { offsets: &offsets, values };
+                            let valid = offsets_are_valid::<ByteArray>(values, &offsets);
+
+                            let mut builder = make_builder::<$element>(config, init_capacity);
+                            let result = builder.extend_from_slice(slice);
+                            prop_assert_eq!(result.is_ok(), valid);
+                            if valid {
+                                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                                prop_assert!(builder.finish_cloned().iter().eq(slice.iter()));
+                                prop_assert!(builder.finish().iter().eq(slice.iter()));
+                            } else {
+                                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                            }
+
+                            let mut builder = make_builder::<Option<$element>>(config, init_capacity);
+                            let result = builder.extend_from_value_slice(slice);
+                            prop_assert_eq!(result.is_ok(), valid);
+                            if valid {
+                                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                                check_validity(&builder, &vec![true; slice.len()])?;
+                            } else {
+                                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                            }
+                        }
+
+                        #[test]
+                        fn extend_from_options(
+                            config in byte_view_config(),
+                            init_capacity in length_or_capacity(),
+                            options: Vec<Option<Owned>>,
+                            truncate_validity: bool,
+                        ) {
+                            let options = options
+                                .iter()
+                                .map(|opt| opt.as_ref().map(Borrow::borrow))
+                                .collect::<Vec<Option<&Native>>>();
+                            let values = options
+                                .iter()
+                                .map(|opt| opt.unwrap_or_default())
+                                .collect::<Vec<_>>();
+                            let (offsets, bytes) = concat::<ByteArray>(&values);
+                            let mut is_valid = options.iter().map(Option::is_some).collect::<Vec<_>>();
+                            if truncate_validity {
+                                is_valid.pop();
+                            }
+                            let slice = OptionSlice::<$element> {
+                                values: ByteSlice {
+                                    offsets: &offsets,
+                                    values: concatenated::<ByteArray>(&bytes),
+                                },
+                                is_valid: &is_valid,
+                            };
+
+                            let mut builder = make_builder::<Option<$element>>(config, init_capacity);
+                            let result = builder.extend_from_slice(slice);
+                            if options.len() != is_valid.len() {
+                                prop_assert!(result.is_err());
+                                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                                return Ok(());
+                            }
+                            prop_assert!(result.is_ok());
+                            check_extend_outcome(&builder, init_capacity, options.len())?;
+                            check_validity(&builder, &is_valid)?;
+                            prop_assert!(builder.finish().iter().eq(options));
+                        }
+
+                        #[test]
+                        fn extend_with_nulls(
+                            config in byte_view_config(),
+                            init_capacity in length_or_capacity(),
+                            num_nulls in length_or_capacity()
+                        ) {
+                            check_extend_with_nulls::<$element>(config, init_capacity, num_nulls)?;
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_view_elements!(
+        utf8_view: Utf8View => StringViewType,
+        binary_view: BinaryView => BinaryViewType
+    );
+
+    proptest! {
+        #[test]
+        fn deduplicate(
+            value in "[a-z]{13,32}",
+            num_repetitions in 1..=16usize,
+            deduplicate: bool,
+        ) {
+            let mut builder = TypedBuilder::<Utf8View>::with_config(BuilderConfig::with_backend(
+                None,
+                ByteViewConfig {
+                    deduplicate,
+                    ..Default::default()
+                },
+            ));
+            builder.extend(std::iter::repeat_n(value.as_str(), num_repetitions));
+            let array = builder.finish().into_array_ref();
+            let array = array
+                .as_any()
+                .downcast_ref::<GenericByteViewArray<StringViewType>>()
+                .unwrap();
+            let data_len = array.data_buffers().iter().map(Buffer::len).sum::<usize>();
+            let expected_repetitions = if deduplicate { 1 } else { num_repetitions };
+            prop_assert_eq!(data_len, expected_repetitions * value.len());
+            prop_assert!(array.iter().all(|elem| elem == Some(value.as_str())));
+        }
+
+        #[test]
+        fn block_size(block_size in 0..=256u32, values in prop::collection::vec("[a-z]{13,32}", 0..32)) {
+            let mut builder = TypedBuilder::<Utf8View>::with_config(BuilderConfig::with_backend(
+                None,
+                ByteViewConfig {
+                    block_size,
+                    deduplicate: false,
+                },
+            ));
+            builder.extend(values.iter().map(String::as_str));
+            let array = builder.finish().into_array_ref();
+            let array = array
+                .as_any()
+                .downcast_ref::<GenericByteViewArray<StringViewType>>()
+                .unwrap();
+            for buffer in array.data_buffers() {
+                prop_assert!(buffer.len() <= (block_size as usize).max(32));
+            }
+            prop_assert!(array.iter().eq(values.iter().map(|v| Some(v.as_str()))));
+        }
+    }
+}
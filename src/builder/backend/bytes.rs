@@ -0,0 +1,406 @@
+//! Strong typing layer on top of [`GenericByteBuilder`]
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::BuilderConfig,
+    types::bytes::{ByteArrayTypeExt, ByteSlice, ByteType, NativeType},
+    ArrayElement, OptionSlice,
+};
+use arrow_array::{
+    builder::{ArrayBuilder, GenericByteBuilder},
+    types::ByteArrayType,
+    ArrayRef, GenericByteArray,
+};
+use arrow_schema::{ArrowError, DataType};
+use std::{
+    any::Any,
+    fmt::{self, Debug},
+};
+
+/// Thin wrapper around [`GenericByteBuilder`] which keeps track of capacity
+///
+/// `GenericByteBuilder` does not expose its capacity, so we need to keep track
+/// of it ourselves. Capacity is measured in number of elements, without
+/// accounting for the capacity of the underlying byte buffer.
+pub struct ByteBuilder<T: ByteArrayType> {
+    /// Underlying arrow builder
+    builder: GenericByteBuilder<T>,
+
+    /// Number of elements that was requested at construction time
+    capacity: usize,
+}
+//
+impl<T: ByteArrayType> ByteBuilder<T> {
+    /// Default number of elements that builders can hold without reallocating
+    ///
+    /// This is the same as what `GenericByteBuilder::new()` uses.
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Number of bytes that are allocated for element data
+    ///
+    /// This is the same as what `GenericByteBuilder::new()` uses.
+    const DEFAULT_DATA_CAPACITY: usize = 1024;
+
+    /// Create a builder with space for `capacity` elements
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            builder: GenericByteBuilder::with_capacity(capacity, Self::DEFAULT_DATA_CAPACITY),
+            capacity,
+        }
+    }
+
+    /// Create a builder from a typed builder configuration
+    fn from_config<E: ArrayElement + ?Sized>(config: BuilderConfig<E>) -> Self {
+        Self::with_capacity(config.capacity.unwrap_or(Self::DEFAULT_CAPACITY))
+    }
+}
+//
+impl<T: ByteArrayTypeExt> ByteBuilder<T> {
+    /// Check that a slice of variable-length data, with optional validity,
+    /// can be appended
+    fn check_byte_slice(
+        values: &ByteSlice<'_, T>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        if let Some(is_valid) = is_valid {
+            if values.len() != is_valid.len() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Value and validity lengths must be equal".to_string(),
+                ));
+            }
+        }
+        values.validate()
+    }
+
+    /// Append a slice of variable-length data, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_byte_slice(
+        &mut self,
+        values: ByteSlice<'_, T>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        Self::check_byte_slice(&values, is_valid)?;
+        match is_valid {
+            Some(is_valid) => {
+                for (value, &valid) in values.iter().zip(is_valid) {
+                    if valid {
+                        self.builder.append_value(value);
+                    } else {
+                        self.builder.append_null();
+                    }
+                }
+            }
+            None => {
+                for value in values {
+                    self.builder.append_value(value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+//
+impl<T: ByteArrayType> ArrayBuilder for ByteBuilder<T> {
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        ArrayBuilder::finish(&mut self.builder)
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        ArrayBuilder::finish_cloned(&self.builder)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+//
+impl<T: ByteArrayType> Debug for ByteBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteBuilder")
+            .field("builder", &self.builder)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<T: ByteArrayType> Backend for ByteBuilder<T> {
+    type Array = GenericByteArray<T>;
+
+    fn capacity(&self) -> usize {
+        self.capacity.max(self.builder.len())
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        for _ in 0..n {
+            self.builder.append_null();
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        let array = self.builder.finish().slice(0, len);
+        self.builder.extend(array.iter());
+    }
+
+    fn finish(&mut self) -> GenericByteArray<T> {
+        // GenericByteBuilder::finish() releases the builder's allocations
+        self.capacity = 0;
+        self.builder.finish()
+    }
+
+    fn finish_cloned(&self) -> GenericByteArray<T> {
+        self.builder.finish_cloned()
+    }
+}
+
+impl<T: ByteArrayType> ValiditySlice for ByteBuilder<T> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.builder.validity_slice()
+    }
+}
+
+impl<T: ByteType + ?Sized> TypedBackend<T> for ByteBuilder<T::Arrow>
+where
+    // FIXME: Remove these bounds once the Rust trait system supports adding
+    //        the appropriate bounds on ByteType to let rustc figure out that
+    //        T::Value<'a> is &'a NativeType<T> and T::Slice<'a> is
+    //        ByteSlice<'a, T::Arrow> for byte types.
+    for<'a> T::Value<'a>: Into<&'a NativeType<T>>,
+    for<'a> T::Slice<'a>: Into<ByteSlice<'a, T::Arrow>>,
+{
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        T::Arrow::DATA_TYPE
+    }
+
+    fn new(config: BuilderConfig<T>) -> Self {
+        Self::from_config(config)
+    }
+
+    #[inline]
+    fn push(&mut self, v: T::Value<'_>) {
+        self.builder.append_value(v.into())
+    }
+
+    fn extend_from_slice(&mut self, s: T::Slice<'_>) -> Result<(), ArrowError> {
+        self.extend_from_byte_slice(s.into(), None)
+    }
+
+    fn check_extend_from_slice(&self, s: &T::Slice<'_>) -> Result<(), ArrowError> {
+        Self::check_byte_slice(&s.clone().into(), None)
+    }
+}
+
+impl<T: ByteType> TypedBackend<Option<T>> for ByteBuilder<T::Arrow>
+where
+    // FIXME: Remove these bounds once the Rust trait system supports adding
+    //        the appropriate bounds on ByteType (see above)
+    for<'a> T::Value<'a>: Into<&'a NativeType<T>>,
+    for<'a> T::Slice<'a>: Into<ByteSlice<'a, T::Arrow>>,
+    //
+    // FIXME: Remove these bounds once it becomes possible to blanket-impl
+    //        ArrayElement for Option<T: ByteType>, making them obvious.
+    Option<T>: ArrayElement<PushResult = (), ExtendFromSliceResult = Result<(), ArrowError>>,
+    for<'a> <Option<T> as ArrayElement>::Value<'a>: Into<Option<T::Value<'a>>>,
+    for<'a> <Option<T> as ArrayElement>::Slice<'a>: Into<OptionSlice<'a, T>>,
+{
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        T::Arrow::DATA_TYPE
+    }
+
+    fn new(config: BuilderConfig<Option<T>>) -> Self {
+        Self::from_config(config)
+    }
+
+    #[inline]
+    fn push(&mut self, v: <Option<T> as ArrayElement>::Value<'_>) {
+        let opt: Option<T::Value<'_>> = v.into();
+        self.builder.append_option(opt.map(Into::into))
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: <Option<T> as ArrayElement>::Slice<'_>,
+    ) -> Result<(), ArrowError> {
+        let slice: OptionSlice<T> = slice.into();
+        self.extend_from_byte_slice(slice.values.into(), Some(slice.is_valid))
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &<Option<T> as ArrayElement>::Slice<'_>,
+    ) -> Result<(), ArrowError> {
+        let slice: OptionSlice<T> = slice.clone().into();
+        Self::check_byte_slice(&slice.values.into(), Some(slice.is_valid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::tests::{
+            check_extend_outcome, check_extend_with_nulls, check_init_default,
+            check_init_default_optional, check_init_with_capacity_optional,
+            check_init_with_capacity_outcome, check_push, check_validity,
+        },
+        tests::length_or_capacity,
+        types::bytes::{
+            tests::{
+                concat, concatenated, offsets_are_valid, values_and_offsets, TestByteArrayType,
+            },
+            ByteSlice, LargeBinary, LargeUtf8,
+        },
+        OptionSlice, TypedBuilder,
+    };
+    use arrow_array::types::{BinaryType, ByteArrayType, LargeBinaryType, LargeUtf8Type, Utf8Type};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+    use std::borrow::Borrow;
+
+    macro_rules! test_byte_elements {
+        ($($mod_name:ident : $element:ty, $value_element:ty => $arrow:ty),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Owned = <$arrow as TestByteArrayType>::Owned;
+                    type Native = <$arrow as ByteArrayType>::Native;
+
+                    #[test]
+                    fn init_default() -> TestCaseResult {
+                        check_init_default::<$value_element>()?;
+                        check_init_default_optional::<$element>()
+                    }
+
+                    proptest! {
+                        #[test]
+                        fn init_with_capacity(capacity in length_or_capacity()) {
+                            check_init_with_capacity_optional::<$element>(|| (), capacity)?;
+                            check_init_with_capacity_outcome(
+                                &TypedBuilder::<$value_element>::with_capacity(capacity),
+                                capacity,
+                            )?;
+                        }
+
+                        #[test]
+                        fn push_value(init_capacity in length_or_capacity(), value: Owned) {
+                            check_push::<$value_element>((), init_capacity, value.borrow())?;
+                            check_push::<$element>((), init_capacity, value.borrow())?;
+                        }
+
+                        #[test]
+                        fn push_option(init_capacity in length_or_capacity(), value: Option<Owned>) {
+                            let value: Option<&Native> = value.as_ref().map(Borrow::borrow);
+                            let mut builder = TypedBuilder::<Option<$element>>::with_capacity(init_capacity);
+                            builder.push(value);
+                            check_extend_outcome(&builder, init_capacity, 1)?;
+                            check_validity(&builder, &[value.is_some()])?;
+                            let array = builder.finish();
+                            prop_assert_eq!(array.value(0), value);
+                        }
+
+                        #[test]
+                        fn extend_from_values(
+                            init_capacity in length_or_capacity(),
+                            (values, offsets) in values_and_offsets::<$arrow>(),
+                        ) {
+                            let (_, bytes) = concat::<$arrow>(&values);
+                            let values = concatenated::<$arrow>(&bytes);
+                            let slice = ByteSlice::<$arrow> { offsets: &offsets, values };
+                            let valid = offsets_are_valid::<$arrow>(values, &offsets);
+
+                            let mut builder = TypedBuilder::<$value_element>::with_capacity(init_capacity);
+                            let result = builder.extend_from_slice(slice);
+                            prop_assert_eq!(result.is_ok(), valid);
+                            if valid {
+                                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                                prop_assert!(builder.finish().iter().eq(slice.iter()));
+                            } else {
+                                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                            }
+
+                            let mut builder = TypedBuilder::<Option<$element>>::with_capacity(init_capacity);
+                            let result = builder.extend_from_value_slice(slice);
+                            prop_assert_eq!(result.is_ok(), valid);
+                            if valid {
+                                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                                check_validity(&builder, &vec![true; slice.len()])?;
+                            } else {
+                                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                            }
+                        }
+
+                        #[test]
+                        fn extend_from_options(
+                            init_capacity in length_or_capacity(),
+                            options: Vec<Option<Owned>>,
+                            truncate_validity: bool,
+                        ) {
+                            let options = options
+                                .iter()
+                                .map(|opt| opt.as_ref().map(Borrow::borrow))
+                                .collect::<Vec<Option<&Native>>>();
+                            let values = options
+                                .iter()
+                                .map(|opt| opt.unwrap_or_default())
+                                .collect::<Vec<_>>();
+                            let (offsets, bytes) = concat::<$arrow>(&values);
+                            let mut is_valid = options.iter().map(Option::is_some).collect::<Vec<_>>();
+                            if truncate_validity {
+                                is_valid.pop();
+                            }
+                            let slice = OptionSlice::<$element> {
+                                values: ByteSlice {
+                                    offsets: &offsets,
+                                    values: concatenated::<$arrow>(&bytes),
+                                },
+                                is_valid: &is_valid,
+                            };
+
+                            let mut builder = TypedBuilder::<Option<$element>>::with_capacity(init_capacity);
+                            let result = builder.extend_from_slice(slice);
+                            if options.len() != is_valid.len() {
+                                prop_assert!(result.is_err());
+                                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                                return Ok(());
+                            }
+                            prop_assert!(result.is_ok());
+                            check_extend_outcome(&builder, init_capacity, options.len())?;
+                            check_validity(&builder, &is_valid)?;
+                            prop_assert!(builder.finish().iter().eq(options));
+                        }
+
+                        #[test]
+                        fn extend_with_nulls(
+                            init_capacity in length_or_capacity(),
+                            num_nulls in length_or_capacity()
+                        ) {
+                            check_extend_with_nulls::<$element>((), init_capacity, num_nulls)?;
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_byte_elements!(
+        string: String, str => Utf8Type,
+        binary: Vec<u8>, [u8] => BinaryType,
+        large_utf8: LargeUtf8, LargeUtf8 => LargeUtf8Type,
+        large_binary: LargeBinary, LargeBinary => LargeBinaryType
+    );
+}
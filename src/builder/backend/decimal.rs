@@ -0,0 +1,484 @@
+//! Strong typing layer on top of [`PrimitiveBuilder`] for decimal types
+//!
+//! arrow-rs' `PrimitiveBuilder` does not check that decimal values fit in the
+//! precision of its data type, and does not expose this data type either. We
+//! therefore wrap it along with the decimal configuration, which is used to
+//! validate values as they are inserted.
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::BuilderConfig,
+    types::decimal::{Decimal128, Decimal256, DecimalConfig, DecimalElement},
+    OptionSlice,
+};
+use arrow_array::{
+    builder::{ArrayBuilder, PrimitiveBuilder},
+    types::{ArrowPrimitiveType, DecimalType},
+    ArrayRef, PrimitiveArray,
+};
+use arrow_buffer::i256;
+use arrow_schema::{ArrowError, DataType};
+use std::{any::Any, sync::Arc};
+
+/// Native type of decimal element type `T`
+type NativeDecimal<T> = <<T as DecimalElement>::Arrow as ArrowPrimitiveType>::Native;
+
+/// Builder of decimal [`PrimitiveArray`]s, which checks that values do not
+/// exceed the configured precision
+#[derive(Debug)]
+pub struct DecimalBuilder<T: DecimalElement> {
+    /// Underlying arrow builder
+    builder: PrimitiveBuilder<T::Arrow>,
+
+    /// Decimal precision and scale
+    config: DecimalConfig<T>,
+}
+//
+impl<T: DecimalElement> DecimalBuilder<T> {
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, config: DecimalConfig<T>) -> Self {
+        let builder = if let Some(capacity) = capacity {
+            PrimitiveBuilder::with_capacity(capacity)
+        } else {
+            PrimitiveBuilder::new()
+        };
+        Self {
+            builder: builder.with_data_type(config.data_type()),
+            config,
+        }
+    }
+
+    /// Check that a value fits in the configured precision
+    fn validate_value(&self, value: NativeDecimal<T>) -> Result<(), ArrowError> {
+        T::Arrow::validate_decimal_precision(value, self.config.precision())
+    }
+
+    /// Append a non-null value, if it fits in the configured precision
+    #[inline]
+    fn append_value(&mut self, value: NativeDecimal<T>) -> Result<(), ArrowError> {
+        self.validate_value(value)?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    /// Append a possibly null value, if it fits in the configured precision
+    #[inline]
+    fn append_option(&mut self, value: Option<NativeDecimal<T>>) -> Result<(), ArrowError> {
+        match value {
+            Some(value) => self.append_value(value),
+            None => {
+                self.builder.append_null();
+                Ok(())
+            }
+        }
+    }
+
+    /// Check that a slice of values, with optional validity, can be appended
+    fn check_values(
+        &self,
+        values: &[NativeDecimal<T>],
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        match is_valid {
+            Some(is_valid) => {
+                if is_valid.len() != values.len() {
+                    return Err(ArrowError::InvalidArgumentError(
+                        "Value and validity lengths must be equal".to_string(),
+                    ));
+                }
+                for (&value, _) in values.iter().zip(is_valid).filter(|(_, &valid)| valid) {
+                    self.validate_value(value)?;
+                }
+            }
+            None => {
+                for &value in values {
+                    self.validate_value(value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Append a slice of values, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid or if a valid
+    /// value does not fit in the configured precision.
+    fn extend_from_values(
+        &mut self,
+        values: &[NativeDecimal<T>],
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        self.check_values(values, is_valid)?;
+        match is_valid {
+            Some(is_valid) => self.builder.append_values(values, is_valid),
+            None => self.builder.append_slice(values),
+        }
+        Ok(())
+    }
+}
+//
+impl<T: DecimalElement> ArrayBuilder for DecimalBuilder<T> {
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<T: DecimalElement> Backend for DecimalBuilder<T> {
+    type Array = PrimitiveArray<T::Arrow>;
+
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        self.builder.append_nulls(n)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        Backend::truncate(&mut self.builder, len)
+    }
+
+    fn finish(&mut self) -> PrimitiveArray<T::Arrow> {
+        self.builder.finish()
+    }
+
+    fn finish_cloned(&self) -> PrimitiveArray<T::Arrow> {
+        self.builder.finish_cloned()
+    }
+}
+
+impl<T: DecimalElement> ValiditySlice for DecimalBuilder<T> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.builder.validity_slice()
+    }
+}
+
+macro_rules! impl_typed_backend {
+    ($($element:ty => $native:ty),*) => {
+        $(
+            impl TypedBackend<$element> for DecimalBuilder<$element> {
+                type Config = DecimalConfig<$element>;
+
+                fn data_type(config: &DecimalConfig<$element>) -> DataType {
+                    config.data_type()
+                }
+
+                fn new(config: BuilderConfig<$element>) -> Self {
+                    Self::from_config(config.capacity, config.backend)
+                }
+
+                #[inline]
+                fn push(&mut self, v: $native) -> Result<(), ArrowError> {
+                    self.append_value(v)
+                }
+
+                fn extend_from_slice(&mut self, s: &[$native]) -> Result<(), ArrowError> {
+                    self.extend_from_values(s, None)
+                }
+
+                fn check_push(&self, v: &$native) -> Result<(), ArrowError> {
+                    self.validate_value(*v)
+                }
+
+                fn check_extend_from_slice(&self, s: &&[$native]) -> Result<(), ArrowError> {
+                    self.check_values(s, None)
+                }
+            }
+
+            impl TypedBackend<Option<$element>> for DecimalBuilder<$element> {
+                type Config = DecimalConfig<$element>;
+
+                fn data_type(config: &DecimalConfig<$element>) -> DataType {
+                    config.data_type()
+                }
+
+                fn new(config: BuilderConfig<Option<$element>>) -> Self {
+                    Self::from_config(config.capacity, config.backend)
+                }
+
+                #[inline]
+                fn push(&mut self, v: Option<$native>) -> Result<(), ArrowError> {
+                    self.append_option(v)
+                }
+
+                fn extend_from_slice(
+                    &mut self,
+                    slice: OptionSlice<'_, $element>,
+                ) -> Result<(), ArrowError> {
+                    self.extend_from_values(slice.values, Some(slice.is_valid))
+                }
+
+                fn check_push(&self, v: &Option<$native>) -> Result<(), ArrowError> {
+                    v.map_or(Ok(()), |v| self.validate_value(v))
+                }
+
+                fn check_extend_from_slice(
+                    &self,
+                    slice: &OptionSlice<'_, $element>,
+                ) -> Result<(), ArrowError> {
+                    self.check_values(slice.values, Some(slice.is_valid))
+                }
+            }
+        )*
+    };
+}
+//
+impl_typed_backend!(Decimal128 => i128, Decimal256 => i256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builder::tests::{
+            check_extend_outcome, check_extend_with_nulls, check_init_default_optional,
+            check_init_with_capacity_optional, check_init_with_capacity_outcome, check_push,
+            check_validity,
+        },
+        tests::length_or_capacity,
+        ArrayElement, TypedBuilder,
+    };
+    use arrow_array::{cast::AsArray, Array};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    /// Generate a valid 128-bit decimal configuration
+    fn decimal128_config() -> impl Strategy<Value = DecimalConfig<Decimal128>> {
+        (1..=38u8).prop_flat_map(|precision| {
+            (-10..=precision as i8)
+                .prop_map(move |scale| DecimalConfig::new(precision, scale).unwrap())
+        })
+    }
+
+    /// Largest value that fits in a decimal of a certain precision
+    fn max_value(config: DecimalConfig<Decimal128>) -> i128 {
+        10i128.pow(u32::from(config.precision())) - 1
+    }
+
+    /// Generate a valid decimal configuration, along with values that may or
+    /// may not fit in its precision
+    fn config_and_values() -> impl Strategy<Value = (DecimalConfig<Decimal128>, Vec<i128>)> {
+        decimal128_config().prop_flat_map(|config| {
+            let max = max_value(config);
+            let value = prop_oneof![
+                4 => -max..=max,
+                1 => any::<i128>(),
+            ];
+            (Just(config), prop::collection::vec(value, 0..100))
+        })
+    }
+
+    /// Truth that a value fits in a decimal configuration
+    fn fits(config: DecimalConfig<Decimal128>, value: i128) -> bool {
+        value.unsigned_abs() <= max_value(config) as u128
+    }
+
+    /// Check that a finished decimal array has the expected data type and
+    /// values
+    fn check_array(
+        builder: &mut TypedBuilder<impl ArrayElement + ?Sized>,
+        config: DecimalConfig<Decimal128>,
+        expected: &[Option<i128>],
+    ) -> TestCaseResult {
+        let array = builder.finish().into_array_ref();
+        prop_assert_eq!(
+            array.data_type(),
+            &DataType::Decimal128(config.precision(), config.scale())
+        );
+        let array = array.as_primitive::<arrow_array::types::Decimal128Type>();
+        prop_assert!(array.iter().eq(expected.iter().copied()));
+        Ok(())
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default_optional::<Decimal128>()?;
+        check_init_default_optional::<Decimal256>()
+    }
+
+    #[test]
+    fn data_type() {
+        assert_eq!(
+            Decimal128::data_type(&DecimalConfig::default()),
+            DataType::Decimal128(38, 10)
+        );
+        assert_eq!(
+            Option::<Decimal256>::data_type(&DecimalConfig::default()),
+            DataType::Decimal256(76, 10)
+        );
+    }
+
+    #[test]
+    fn decimal256() {
+        let config = DecimalConfig::<Decimal256>::new(40, 5).unwrap();
+        let mut builder = TypedBuilder::<Option<Decimal256>>::with_config(
+            BuilderConfig::with_backend(None, config),
+        );
+        let max = i256::from_i128(10i128.pow(38)).wrapping_mul(i256::from_i128(100)) - i256::ONE;
+        builder.push(Some(max)).unwrap();
+        builder.push(None).unwrap();
+        assert!(builder.push(Some(max + i256::ONE)).is_err());
+        assert!(builder.push(Some(-max - i256::ONE)).is_err());
+        builder.push(Some(-max)).unwrap();
+        let array = builder.finish();
+        assert_eq!(
+            array.into_array_ref().data_type(),
+            &DataType::Decimal256(40, 5)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(
+            config in decimal128_config(),
+            capacity in length_or_capacity()
+        ) {
+            check_init_with_capacity_optional::<Decimal128>(|| config, capacity)?;
+        }
+
+        #[test]
+        fn push_value(
+            init_capacity in length_or_capacity(),
+            (config, values) in config_and_values(),
+        ) {
+            if let Some(&value) = values.iter().find(|&&value| fits(config, value)) {
+                check_push::<Decimal128>(config, init_capacity, value)?;
+            }
+            let mut builder = TypedBuilder::<Decimal128>::with_config(BuilderConfig::with_backend(
+                Some(init_capacity),
+                config,
+            ));
+            let mut expected = Vec::new();
+            for &value in &values {
+                let result = builder.push(value);
+                prop_assert_eq!(result.is_ok(), fits(config, value));
+                if result.is_ok() {
+                    expected.push(Some(value));
+                }
+            }
+            check_extend_outcome(&builder, init_capacity, expected.len())?;
+            check_array(&mut builder, config, &expected)?;
+        }
+
+        #[test]
+        fn push_option(
+            init_capacity in length_or_capacity(),
+            (config, values) in config_and_values(),
+        ) {
+            let mut builder = TypedBuilder::<Option<Decimal128>>::with_config(
+                BuilderConfig::with_backend(Some(init_capacity), config),
+            );
+            let mut expected = Vec::new();
+            for (index, &value) in values.iter().enumerate() {
+                let value = (index % 3 != 0).then_some(value);
+                let result = builder.push(value);
+                prop_assert_eq!(result.is_ok(), value.is_none_or(|value| fits(config, value)));
+                if result.is_ok() {
+                    expected.push(value);
+                }
+            }
+            check_extend_outcome(&builder, init_capacity, expected.len())?;
+            let is_valid = expected.iter().map(Option::is_some).collect::<Vec<_>>();
+            check_validity(&builder, &is_valid)?;
+            check_array(&mut builder, config, &expected)?;
+        }
+
+        #[test]
+        fn extend_from_values(
+            init_capacity in length_or_capacity(),
+            (config, values) in config_and_values(),
+        ) {
+            let valid = values.iter().all(|&value| fits(config, value));
+            let expected = values.iter().copied().map(Some).collect::<Vec<_>>();
+
+            let mut builder = TypedBuilder::<Decimal128>::with_config(BuilderConfig::with_backend(
+                Some(init_capacity),
+                config,
+            ));
+            prop_assert_eq!(builder.extend_from_slice(&values).is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, values.len())?;
+                check_array(&mut builder, config, &expected)?;
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+
+            let mut builder = TypedBuilder::<Option<Decimal128>>::with_config(
+                BuilderConfig::with_backend(Some(init_capacity), config),
+            );
+            prop_assert_eq!(builder.extend_from_value_slice(&values).is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, values.len())?;
+                check_array(&mut builder, config, &expected)?;
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_from_options(
+            init_capacity in length_or_capacity(),
+            (config, values) in config_and_values(),
+            truncate_validity: bool,
+        ) {
+            // Only mask out values that do not fit, so that the outcome only
+            // depends on the validity length
+            let mut is_valid = values.iter().map(|&value| fits(config, value)).collect::<Vec<_>>();
+            if truncate_validity {
+                is_valid.pop();
+            }
+            let valid = is_valid.len() == values.len();
+            let mut builder = TypedBuilder::<Option<Decimal128>>::with_config(
+                BuilderConfig::with_backend(Some(init_capacity), config),
+            );
+            let result = builder.extend_from_slice(OptionSlice { values: &values, is_valid: &is_valid });
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, values.len())?;
+                check_validity(&builder, &is_valid)?;
+                let expected = values
+                    .iter()
+                    .zip(&is_valid)
+                    .map(|(&value, &valid)| valid.then_some(value))
+                    .collect::<Vec<_>>();
+                check_array(&mut builder, config, &expected)?;
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+
+            let is_valid = vec![true; values.len()];
+            let mut builder = TypedBuilder::<Option<Decimal128>>::with_config(
+                BuilderConfig::with_backend(Some(init_capacity), config),
+            );
+            let result = builder.extend_from_slice(OptionSlice { values: &values, is_valid: &is_valid });
+            prop_assert_eq!(result.is_ok(), values.iter().all(|&value| fits(config, value)));
+        }
+
+        #[test]
+        fn extend_with_nulls(
+            config in decimal128_config(),
+            init_capacity in length_or_capacity(),
+            num_nulls in length_or_capacity()
+        ) {
+            check_extend_with_nulls::<Decimal128>(config, init_capacity, num_nulls)?;
+        }
+    }
+}
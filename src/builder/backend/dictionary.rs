@@ -0,0 +1,740 @@
+//! Strong typing layer on top of [`PrimitiveDictionaryBuilder`] and
+//! [`GenericByteDictionaryBuilder`]
+
+use super::{Backend, TypedBackend};
+use crate::{
+    builder::BuilderConfig,
+    types::{
+        bytes::{self, ByteSlice, ByteType},
+        dictionary::{Dictionary, DictionaryKey, DictionaryValue},
+        primitive::{self, PrimitiveType},
+    },
+    ArrayElement, OptionSlice,
+};
+use arrow_array::{
+    builder::{
+        ArrayBuilder, GenericByteDictionaryBuilder, PrimitiveBuilder, PrimitiveDictionaryBuilder,
+    },
+    cast::AsArray,
+    types::{ArrowDictionaryKeyType, ArrowPrimitiveType, ByteArrayType},
+    ArrayRef, DictionaryArray,
+};
+use arrow_buffer::{ArrowNativeType, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
+use std::{
+    any::Any,
+    collections::HashSet,
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+/// Arrow builder of dictionary arrays with keys of type `K` and values of
+/// element type `V`
+///
+/// This trait is implemented for arrow-rs' [`PrimitiveDictionaryBuilder`] and
+/// for [`ByteDictionaryBuilder`], which take care of value deduplication.
+pub trait ArrowDictionaryBuilder<K: ArrowDictionaryKeyType, V: ArrayElement + ?Sized>:
+    ArrayBuilder
+{
+    /// Create a builder with space for `capacity` keys
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Arrow data type of the dictionary values
+    fn value_type() -> DataType;
+
+    /// Append a value, adding it to the dictionary if it is not there yet
+    ///
+    /// If the value is not in the dictionary and the dictionary is full, an
+    /// error is returned and the builder is left untouched.
+    fn append_value(&mut self, value: V::Value<'_>) -> Result<(), ArrowError>;
+
+    /// Check that a value can be appended, without modifying the builder
+    fn check_value(&self, value: &V::Value<'_>) -> Result<(), ArrowError>;
+
+    /// Check that a slice of values, with optional validity, can be appended
+    /// without modifying the builder
+    fn check_values(
+        &self,
+        values: &V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError>;
+
+    /// Append a slice of values, with optional validity
+    ///
+    /// Invalid inputs and dictionary key overflows are detected before
+    /// anything is appended, so the builder is left untouched on error.
+    fn append_values(
+        &mut self,
+        values: V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError>;
+
+    /// Append a null key
+    fn append_null(&mut self);
+
+    /// Remove all keys past the first `len` ones
+    ///
+    /// The dictionary is rebuilt from the retained keys.
+    fn truncate(&mut self, len: usize);
+
+    /// Build the dictionary array and reset the builder
+    fn finish_dictionary(&mut self) -> DictionaryArray<K>;
+
+    /// Build the dictionary array without resetting the builder
+    fn finish_dictionary_cloned(&self) -> DictionaryArray<K>;
+}
+//
+impl<K: ArrowDictionaryKeyType, V: PrimitiveType> ArrowDictionaryBuilder<K, V>
+    for PrimitiveDictionaryBuilder<K, V::Arrow>
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on PrimitiveType to let rustc figure out that
+    //        T::Value<'_> is just T for primitive types.
+    for<'a> V::Value<'a>: Into<primitive::NativeType<V>> + Copy,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self::new_from_empty_builders(
+            PrimitiveBuilder::with_capacity(capacity),
+            PrimitiveBuilder::new().with_data_type(V::arrow_data_type()),
+        )
+    }
+
+    fn value_type() -> DataType {
+        V::arrow_data_type()
+    }
+
+    #[inline]
+    fn append_value(&mut self, value: V::Value<'_>) -> Result<(), ArrowError> {
+        ArrowDictionaryBuilder::<K, V>::check_value(self, &value)?;
+        self.append(value.into()).map(|_| ())
+    }
+
+    fn check_value(&self, value: &V::Value<'_>) -> Result<(), ArrowError> {
+        let value: primitive::NativeType<V> = (*value).into();
+        check_key_space::<K, _>(self.values_slice(), std::slice::from_ref(&value))
+    }
+
+    fn check_values(
+        &self,
+        values: &V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        let values = primitive_values::<V>(values);
+        check_validity_len(values.len(), is_valid)?;
+        check_key_space::<K, _>(self.values_slice(), valid_values(values, is_valid))
+    }
+
+    fn append_values(
+        &mut self,
+        values: V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        ArrowDictionaryBuilder::<K, V>::check_values(self, &values, is_valid)?;
+        let values = primitive_values::<V>(&values);
+        for (index, &value) in values.iter().enumerate() {
+            if is_valid.is_none_or(|is_valid| is_valid[index]) {
+                self.append(value)?;
+            } else {
+                PrimitiveDictionaryBuilder::append_null(self);
+            }
+        }
+        Ok(())
+    }
+
+    fn append_null(&mut self) {
+        self.append_null()
+    }
+
+    fn truncate(&mut self, len: usize) {
+        let array = self.finish().slice(0, len);
+        let dictionary = array.values().as_primitive::<V::Arrow>();
+        for key in array.keys() {
+            self.append_option(key.map(|key| dictionary.value(key.as_usize())));
+        }
+    }
+
+    fn finish_dictionary(&mut self) -> DictionaryArray<K> {
+        self.finish()
+    }
+
+    fn finish_dictionary_cloned(&self) -> DictionaryArray<K> {
+        self.finish_cloned()
+    }
+}
+//
+impl<K: ArrowDictionaryKeyType, V: ByteType + ?Sized> ArrowDictionaryBuilder<K, V>
+    for ByteDictionaryBuilder<K, V::Arrow>
+where
+    // FIXME: Remove these bounds once the Rust trait system supports adding
+    //        the appropriate bounds on ByteType to let rustc figure out that
+    //        T::Value<'a> is &'a NativeType<T> and T::Slice<'a> is
+    //        ByteSlice<'a, T::Arrow> for byte types.
+    for<'a> V::Value<'a>: Into<&'a bytes::NativeType<V>> + Copy,
+    for<'a> V::Slice<'a>: Into<ByteSlice<'a, V::Arrow>>,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            builder: GenericByteDictionaryBuilder::with_capacity(capacity, 0, 0),
+            dictionary_len: 0,
+        }
+    }
+
+    fn value_type() -> DataType {
+        V::Arrow::DATA_TYPE
+    }
+
+    #[inline]
+    fn append_value(&mut self, value: V::Value<'_>) -> Result<(), ArrowError> {
+        ArrowDictionaryBuilder::<K, V>::check_value(self, &value)?;
+        self.append(value.into());
+        Ok(())
+    }
+
+    fn check_value(&self, value: &V::Value<'_>) -> Result<(), ArrowError> {
+        self.check_key_space(std::iter::once((*value).into()))
+    }
+
+    fn check_values(
+        &self,
+        values: &V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        let values: ByteSlice<'_, V::Arrow> = values.clone().into();
+        check_validity_len(values.len(), is_valid)?;
+        values.validate()?;
+        self.check_key_space(valid_values(values.iter(), is_valid))
+    }
+
+    fn append_values(
+        &mut self,
+        values: V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        ArrowDictionaryBuilder::<K, V>::check_values(self, &values, is_valid)?;
+        let values: ByteSlice<'_, V::Arrow> = values.into();
+        for (index, value) in values.iter().enumerate() {
+            if is_valid.is_none_or(|is_valid| is_valid[index]) {
+                self.append(value);
+            } else {
+                self.builder.append_null();
+            }
+        }
+        Ok(())
+    }
+
+    fn append_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn truncate(&mut self, len: usize) {
+        let array = ArrowDictionaryBuilder::<K, V>::finish_dictionary(self).slice(0, len);
+        let dictionary = array.values().as_bytes::<V::Arrow>();
+        for key in array.keys() {
+            match key {
+                Some(key) => self.append(dictionary.value(key.as_usize())),
+                None => self.builder.append_null(),
+            }
+        }
+    }
+
+    fn finish_dictionary(&mut self) -> DictionaryArray<K> {
+        self.dictionary_len = 0;
+        self.builder.finish()
+    }
+
+    fn finish_dictionary_cloned(&self) -> DictionaryArray<K> {
+        self.builder.finish_cloned()
+    }
+}
+
+/// Reinterpret a slice of primitive dictionary values as a slice of their
+/// native type
+fn primitive_values<'a, V: PrimitiveType>(values: &V::Slice<'a>) -> &'a [primitive::NativeType<V>] {
+    // SAFETY: This transmute is safe because...
+    //         - V::Slice is &[V] for all primitive types
+    //         - Primitive types are repr(transparent) wrappers over the
+    //           corresponding Arrow native types, so it is safe to
+    //           transmute &[V] into &[NativeType<V>].
+    unsafe { std::mem::transmute_copy::<V::Slice<'a>, &'a [primitive::NativeType<V>]>(values) }
+}
+
+/// Check that a validity slice, if any, has as many entries as there are
+/// values
+fn check_validity_len(num_values: usize, is_valid: Option<&[bool]>) -> Result<(), ArrowError> {
+    match is_valid {
+        Some(is_valid) if is_valid.len() != num_values => Err(ArrowError::InvalidArgumentError(
+            "Value and validity lengths must be equal".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Values that are marked valid by an optional validity slice, which must
+/// have been checked by [`check_validity_len()`]
+fn valid_values<'a, I: IntoIterator + 'a>(
+    values: I,
+    is_valid: Option<&'a [bool]>,
+) -> impl Iterator<Item = I::Item> + 'a {
+    values
+        .into_iter()
+        .enumerate()
+        .filter(move |(index, _)| is_valid.is_none_or(|is_valid| is_valid[*index]))
+        .map(|(_, value)| value)
+}
+
+/// Check that a dictionary with keys of type `K` can hold `len` values
+fn check_dictionary_len<K: ArrowDictionaryKeyType>(len: usize) -> Result<(), ArrowError> {
+    match len.checked_sub(1) {
+        Some(max_key) if K::Native::from_usize(max_key).is_none() => {
+            Err(ArrowError::DictionaryKeyOverflowError)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that a dictionary with keys of type `K`, which currently holds the
+/// values of `dictionary`, has room for the distinct `new_values`
+///
+/// Values are compared by their byte representation, like arrow-rs'
+/// dictionary builders do. Exact deduplication is only performed when the
+/// dictionary may actually overflow.
+fn check_key_space<'a, K: ArrowDictionaryKeyType, T: ToByteSlice + ?Sized + 'a>(
+    dictionary: &'a [impl ToByteSlice],
+    new_values: impl IntoIterator<Item = &'a T>,
+) -> Result<(), ArrowError> {
+    let new_values = new_values.into_iter().collect::<Vec<_>>();
+    if check_dictionary_len::<K>(dictionary.len() + new_values.len()).is_ok() {
+        return Ok(());
+    }
+    let mut values = dictionary
+        .iter()
+        .map(ToByteSlice::to_byte_slice)
+        .collect::<HashSet<_>>();
+    values.extend(new_values.into_iter().map(ToByteSlice::to_byte_slice));
+    check_dictionary_len::<K>(values.len())
+}
+
+/// Wrapper around arrow-rs' [`GenericByteDictionaryBuilder`] that keeps track
+/// of the size of the dictionary
+///
+/// arrow-rs' byte dictionary builders do not expose their dictionary, which
+/// is needed to detect key overflows before anything is appended.
+pub struct ByteDictionaryBuilder<K: ArrowDictionaryKeyType, T: ByteArrayType> {
+    /// Underlying arrow builder
+    builder: GenericByteDictionaryBuilder<K, T>,
+
+    /// Number of distinct values in the dictionary
+    dictionary_len: usize,
+}
+//
+impl<K: ArrowDictionaryKeyType, T: ByteArrayType> ByteDictionaryBuilder<K, T> {
+    /// Append a value whose key is known not to overflow
+    fn append(&mut self, value: &T::Native) {
+        let key = self
+            .builder
+            .append(value)
+            .expect("key overflows should have been checked beforehand");
+        self.dictionary_len = self.dictionary_len.max(key.as_usize() + 1);
+    }
+
+    /// Check that the dictionary has room for the distinct `new_values`
+    fn check_key_space<'a>(
+        &self,
+        new_values: impl IntoIterator<Item = &'a T::Native>,
+    ) -> Result<(), ArrowError> {
+        let new_values = new_values.into_iter().collect::<Vec<_>>();
+        if check_dictionary_len::<K>(self.dictionary_len + new_values.len()).is_ok() {
+            return Ok(());
+        }
+        // The dictionary is not exposed by the arrow builder, so it needs to
+        // be materialized, which is acceptable as this only happens when the
+        // dictionary is about to be full
+        let dictionary = self.builder.finish_cloned();
+        let dictionary = dictionary.values().as_bytes::<T>();
+        let mut values = dictionary
+            .iter()
+            .flatten()
+            .map(AsRef::<[u8]>::as_ref)
+            .collect::<HashSet<_>>();
+        values.extend(new_values.into_iter().map(AsRef::<[u8]>::as_ref));
+        check_dictionary_len::<K>(values.len())
+    }
+}
+//
+impl<K: ArrowDictionaryKeyType, T: ByteArrayType> ArrayBuilder for ByteDictionaryBuilder<K, T> {
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        self.dictionary_len = 0;
+        Arc::new(self.builder.finish())
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.builder.finish_cloned())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// Builder of [`DictionaryArray`]s with keys of type `K` and values of type
+/// `V`, which deduplicates values as they are inserted
+///
+/// The underlying arrow-rs builders do not expose their capacity, so we need to
+/// keep track of it ourselves. Capacity is measured in number of keys, without
+/// accounting for the capacity of the dictionary.
+pub struct DictionaryBuilder<K: DictionaryKey, V: DictionaryValue + ?Sized> {
+    /// Underlying arrow builder
+    builder: V::Builder<K::Arrow>,
+
+    /// Number of elements that was requested at construction time
+    capacity: usize,
+}
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> DictionaryBuilder<K, V> {
+    /// Default number of elements that builders can hold without reallocating
+    ///
+    /// This is the same as what arrow-rs' dictionary builders use.
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create a builder from a typed builder configuration
+    fn from_config<E: ArrayElement + ?Sized>(config: BuilderConfig<E>) -> Self {
+        let capacity = config.capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        Self {
+            builder: ArrowDictionaryBuilder::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Arrow data type of the arrays produced by this builder
+    fn dictionary_data_type() -> DataType {
+        DataType::Dictionary(
+            Box::new(K::Arrow::DATA_TYPE),
+            Box::new(V::Builder::<K::Arrow>::value_type()),
+        )
+    }
+}
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> ArrayBuilder for DictionaryBuilder<K, V> {
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> Debug for DictionaryBuilder<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // arrow-rs' dictionary builders only implement Debug when their type
+        // parameters do, which is not the case of most arrow data types
+        f.debug_struct("DictionaryBuilder")
+            .field("len", &self.builder.len())
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> Backend for DictionaryBuilder<K, V> {
+    type Array = DictionaryArray<K::Arrow>;
+
+    fn capacity(&self) -> usize {
+        self.capacity.max(self.builder.len())
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        for _ in 0..n {
+            self.builder.append_null();
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.builder.truncate(len)
+    }
+
+    fn finish(&mut self) -> DictionaryArray<K::Arrow> {
+        // Finishing arrow-rs' dictionary builders releases their allocations
+        self.capacity = 0;
+        self.builder.finish_dictionary()
+    }
+
+    fn finish_cloned(&self) -> DictionaryArray<K::Arrow> {
+        self.builder.finish_dictionary_cloned()
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> TypedBackend<Dictionary<K, V>>
+    for DictionaryBuilder<K, V>
+{
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        Self::dictionary_data_type()
+    }
+
+    fn new(config: BuilderConfig<Dictionary<K, V>>) -> Self {
+        Self::from_config(config)
+    }
+
+    #[inline]
+    fn push(&mut self, v: V::Value<'_>) -> Result<(), ArrowError> {
+        self.builder.append_value(v)
+    }
+
+    fn extend_from_slice(&mut self, s: V::Slice<'_>) -> Result<(), ArrowError> {
+        self.builder.append_values(s, None)
+    }
+
+    fn check_push(&self, v: &V::Value<'_>) -> Result<(), ArrowError> {
+        self.builder.check_value(v)
+    }
+
+    fn check_extend_from_slice(&self, s: &V::Slice<'_>) -> Result<(), ArrowError> {
+        self.builder.check_values(s, None)
+    }
+}
+
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> TypedBackend<Option<Dictionary<K, V>>>
+    for DictionaryBuilder<K, V>
+{
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        Self::dictionary_data_type()
+    }
+
+    fn new(config: BuilderConfig<Option<Dictionary<K, V>>>) -> Self {
+        Self::from_config(config)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<V::Value<'_>>) -> Result<(), ArrowError> {
+        match v {
+            Some(v) => self.builder.append_value(v),
+            None => {
+                self.builder.append_null();
+                Ok(())
+            }
+        }
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: OptionSlice<'_, Dictionary<K, V>>,
+    ) -> Result<(), ArrowError> {
+        self.builder
+            .append_values(slice.values, Some(slice.is_valid))
+    }
+
+    fn check_push(&self, v: &Option<V::Value<'_>>) -> Result<(), ArrowError> {
+        v.as_ref().map_or(Ok(()), |v| self.builder.check_value(v))
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &OptionSlice<'_, Dictionary<K, V>>,
+    ) -> Result<(), ArrowError> {
+        self.builder
+            .check_values(&slice.values, Some(slice.is_valid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::tests::{
+            check_encoding, check_init_default_optional, check_init_with_capacity_optional,
+            check_string_encoding, repeated_values,
+        },
+        tests::length_or_capacity,
+        types::{bytes::StringSlice, dictionary::Dictionary},
+        ArrayElement, OptionSlice, TypedBuilder,
+    };
+    use arrow_array::{
+        cast::AsArray,
+        types::{ArrowDictionaryKeyType, Int16Type, Int32Type, UInt8Type},
+        Array,
+    };
+    use arrow_buffer::ArrowNativeType;
+    use arrow_schema::{ArrowError, DataType};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+    use std::{collections::HashSet, fmt::Debug, hash::Hash};
+
+    /// Check that a finished dictionary array holds the expected values, each
+    /// of which is only stored once in the dictionary
+    fn check_dictionary<K: ArrowDictionaryKeyType, T: Copy + Eq + Hash + Debug>(
+        array: &dyn Array,
+        expected: &[Option<T>],
+        decode: impl Fn(&dyn Array, usize) -> T,
+    ) -> TestCaseResult {
+        let array = array.as_dictionary::<K>();
+        prop_assert_eq!(array.keys().len(), expected.len());
+        let values = array.values();
+        let distinct = expected.iter().flatten().collect::<HashSet<_>>();
+        prop_assert_eq!(values.len(), distinct.len());
+        prop_assert_eq!(values.null_count(), 0);
+        for (index, expected) in expected.iter().enumerate() {
+            prop_assert_eq!(array.keys().is_valid(index), expected.is_some());
+            if let Some(expected) = expected {
+                prop_assert_eq!(
+                    decode(values.as_ref(), array.keys().value(index).as_usize()),
+                    *expected
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode a value from a dictionary of `i16`
+    fn decode_i16(values: &dyn Array, key: usize) -> i16 {
+        values.as_primitive::<Int16Type>().value(key)
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default_optional::<Dictionary<u8, i16>>()?;
+        check_init_default_optional::<Dictionary<i64, String>>()
+    }
+
+    #[test]
+    fn data_type() {
+        assert_eq!(
+            Dictionary::<u32, f64>::data_type(&()),
+            DataType::Dictionary(Box::new(DataType::UInt32), Box::new(DataType::Float64))
+        );
+        assert_eq!(
+            Option::<Dictionary<i8, Vec<u8>>>::data_type(&()),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Binary))
+        );
+    }
+
+    #[test]
+    fn key_overflow() {
+        let mut builder = TypedBuilder::<Dictionary<i8, u16>>::new();
+        assert!(matches!(
+            builder.extend_from_slice(&(0..200).collect::<Vec<_>>()),
+            Err(ArrowError::DictionaryKeyOverflowError)
+        ));
+        assert!(builder.is_empty());
+        builder
+            .extend_from_slice(&(0..100).collect::<Vec<_>>())
+            .unwrap();
+        for value in 100..128 {
+            builder.push(value).unwrap();
+        }
+        builder.push(127).unwrap();
+        assert!(matches!(
+            builder.push(128),
+            Err(ArrowError::DictionaryKeyOverflowError)
+        ));
+        assert!(builder.extend_from_slice(&[0, 1, 200]).is_err());
+        builder.extend_from_slice(&[0, 1, 127]).unwrap();
+        let array = builder.finish();
+        let expected = (0..128).chain([127, 0, 1, 127]);
+        assert!(array.iter().eq(expected));
+        assert_eq!(array.as_slice().values().len(), 128);
+    }
+
+    #[test]
+    fn key_overflow_strings() {
+        let strings = (0..260).map(|i| i.to_string()).collect::<Vec<_>>();
+        let mut offsets = vec![0];
+        for string in &strings[240..] {
+            offsets.push(offsets.last().unwrap() + string.len() as i32);
+        }
+        let values = strings[240..].concat();
+        let slice = StringSlice {
+            offsets: &offsets,
+            values: &values,
+        };
+
+        let mut builder = TypedBuilder::<Option<Dictionary<u8, String>>>::new();
+        for string in &strings[..250] {
+            builder.push(Some(string.as_str())).unwrap();
+        }
+        builder.push(None).unwrap();
+        assert!(matches!(
+            builder.extend_from_value_slice(slice),
+            Err(ArrowError::DictionaryKeyOverflowError)
+        ));
+        assert_eq!(builder.len(), 251);
+        let mut is_valid = [true; 20];
+        is_valid[16..].fill(false);
+        builder
+            .extend_from_slice(OptionSlice {
+                values: slice,
+                is_valid: &is_valid,
+            })
+            .unwrap();
+        assert!(matches!(
+            builder.push(Some("256")),
+            Err(ArrowError::DictionaryKeyOverflowError)
+        ));
+        builder.push(Some("0")).unwrap();
+        assert_eq!(builder.len(), 272);
+        let array = builder.finish().into_array_ref();
+        assert_eq!(array.as_dictionary::<UInt8Type>().values().len(), 256);
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<Dictionary<u8, i16>>(|| (), capacity)?;
+            check_init_with_capacity_optional::<Dictionary<u16, str>>(|| (), capacity)?;
+        }
+
+        #[test]
+        fn encoding(init_capacity in length_or_capacity(), values in repeated_values()) {
+            check_encoding::<Dictionary<u8, i16>>(init_capacity, &values, |array, expected| {
+                check_dictionary::<UInt8Type, _>(array, expected, decode_i16)
+            })?;
+            check_string_encoding::<Dictionary<i32, String>>(&values, |array, expected| {
+                check_dictionary::<Int32Type, _>(array, expected, |strings, key| {
+                    strings.as_string::<i32>().value(key).parse().unwrap()
+                })
+            })?;
+        }
+
+        #[test]
+        fn extend_from_strings(offsets: Vec<i32>, values: String) {
+            let slice = StringSlice { offsets: &offsets, values: &values };
+            let mut builder = TypedBuilder::<Dictionary<u16, String>>::new();
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), slice.validate().is_ok());
+            if result.is_ok() {
+                let array = builder.finish();
+                prop_assert!(array.iter().eq(slice.iter()));
+            } else {
+                prop_assert!(builder.is_empty());
+            }
+        }
+    }
+}
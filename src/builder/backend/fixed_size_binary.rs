@@ -0,0 +1,553 @@
+//! Strong typing layer on top of [`FixedSizeBinaryArray`]
+//!
+//! arrow-rs' `FixedSizeBinaryBuilder` can only append values one by one, and
+//! it neither tracks capacity nor exposes its validity buffer. Therefore, we
+//! use our own builder, which stores values in a single contiguous buffer.
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::BuilderConfig,
+    types::fixed_size_binary::{
+        ByteWidth, FixedSizeBinary, FixedSizeBinaryConfig, FixedSizeBinarySlice,
+    },
+    ElementSlice, OptionSlice,
+};
+use arrow_array::{builder::ArrayBuilder, ArrayRef, FixedSizeBinaryArray};
+use arrow_buffer::{Buffer, NullBufferBuilder};
+use arrow_schema::{ArrowError, DataType};
+use std::{any::Any, sync::Arc};
+
+/// Builder of [`FixedSizeBinaryArray`]s
+#[derive(Debug)]
+pub struct FixedBinaryBuilder {
+    /// Concatenated values
+    values: Vec<u8>,
+
+    /// Validity bitmap
+    nulls: NullBufferBuilder,
+
+    /// Number of bytes per value
+    byte_width: i32,
+}
+//
+impl FixedBinaryBuilder {
+    /// Default number of elements that builders can hold without reallocating
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, byte_width: i32) -> Self {
+        let capacity = capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        Self {
+            values: Vec::with_capacity(capacity * byte_width as usize),
+            nulls: NullBufferBuilder::new(capacity),
+            byte_width,
+        }
+    }
+
+    /// Check that a non-null value has the right length
+    fn check_value(&self, value: &[u8]) -> Result<(), ArrowError> {
+        if value.len() != self.byte_width as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected a value of {} bytes, got {} bytes",
+                self.byte_width,
+                value.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Append a non-null value, which must have the right length
+    fn append_value(&mut self, value: &[u8]) -> Result<(), ArrowError> {
+        self.check_value(value)?;
+        self.values.extend_from_slice(value);
+        self.nulls.append_non_null();
+        Ok(())
+    }
+
+    /// Append `len` concatenated values, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_bytes(
+        &mut self,
+        values: &[u8],
+        len: usize,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        debug_assert_eq!(values.len(), len * self.byte_width as usize);
+        match is_valid {
+            Some(is_valid) if is_valid.len() != len => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Value and validity lengths must be equal".to_string(),
+                ));
+            }
+            Some(is_valid) => self.nulls.append_slice(is_valid),
+            None => self.nulls.append_n_non_nulls(len),
+        }
+        self.values.extend_from_slice(values);
+        Ok(())
+    }
+
+    /// Check that a slice of runtime-sized values has the right byte width
+    fn check_byte_width(&self, values: &FixedSizeBinarySlice<'_>) -> Result<(), ArrowError> {
+        if values.byte_width() != self.byte_width as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected values of {} bytes, got values of {} bytes",
+                self.byte_width,
+                values.byte_width()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Append a slice of runtime-sized values, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_binary_slice(
+        &mut self,
+        values: FixedSizeBinarySlice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        self.check_byte_width(&values)?;
+        self.extend_from_bytes(values.values(), values.len(), is_valid)
+    }
+}
+//
+impl ArrayBuilder for FixedBinaryBuilder {
+    fn len(&self) -> usize {
+        self.nulls.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl Backend for FixedBinaryBuilder {
+    type Array = FixedSizeBinaryArray;
+
+    fn capacity(&self) -> usize {
+        self.values.capacity() / self.byte_width as usize
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        self.values
+            .resize(self.values.len() + n * self.byte_width as usize, 0);
+        self.nulls.append_n_nulls(n);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.values.truncate(len * self.byte_width as usize);
+        super::truncate_nulls(&mut self.nulls, len);
+    }
+
+    fn finish(&mut self) -> FixedSizeBinaryArray {
+        let values = Buffer::from_vec(std::mem::take(&mut self.values));
+        FixedSizeBinaryArray::new(self.byte_width, values, self.nulls.finish())
+    }
+
+    fn finish_cloned(&self) -> FixedSizeBinaryArray {
+        let values = Buffer::from_slice_ref(&self.values);
+        FixedSizeBinaryArray::new(self.byte_width, values, self.nulls.finish_cloned())
+    }
+}
+
+impl ValiditySlice for FixedBinaryBuilder {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.nulls.as_slice()
+    }
+}
+
+impl<const N: usize> TypedBackend<[u8; N]> for FixedBinaryBuilder {
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        DataType::FixedSizeBinary(ByteWidth::<N>::I32)
+    }
+
+    fn new(config: BuilderConfig<[u8; N]>) -> Self {
+        Self::from_config(config.capacity, ByteWidth::<N>::I32)
+    }
+
+    #[inline]
+    fn push(&mut self, v: [u8; N]) {
+        self.values.extend_from_slice(&v);
+        self.nulls.append_non_null();
+    }
+
+    fn extend_from_slice(&mut self, s: &[[u8; N]]) {
+        self.extend_from_bytes(s.as_flattened(), s.len(), None)
+            .expect("cannot fail in the absence of a validity slice")
+    }
+}
+
+impl<const N: usize> TypedBackend<Option<[u8; N]>> for FixedBinaryBuilder {
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        DataType::FixedSizeBinary(ByteWidth::<N>::I32)
+    }
+
+    fn new(config: BuilderConfig<Option<[u8; N]>>) -> Self {
+        Self::from_config(config.capacity, ByteWidth::<N>::I32)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<[u8; N]>) {
+        match v {
+            Some(v) => TypedBackend::<[u8; N]>::push(self, v),
+            None => self.extend_with_nulls(1),
+        }
+    }
+
+    fn extend_from_slice(&mut self, slice: OptionSlice<'_, [u8; N]>) -> Result<(), ArrowError> {
+        self.extend_from_bytes(
+            slice.values.as_flattened(),
+            slice.values.len(),
+            Some(slice.is_valid),
+        )
+    }
+}
+
+impl TypedBackend<FixedSizeBinary> for FixedBinaryBuilder {
+    type Config = FixedSizeBinaryConfig;
+
+    fn data_type(config: &FixedSizeBinaryConfig) -> DataType {
+        DataType::FixedSizeBinary(config.byte_width_i32())
+    }
+
+    fn new(config: BuilderConfig<FixedSizeBinary>) -> Self {
+        Self::from_config(config.capacity, config.backend.byte_width_i32())
+    }
+
+    #[inline]
+    fn push(&mut self, v: &[u8]) -> Result<(), ArrowError> {
+        self.append_value(v)
+    }
+
+    fn extend_from_slice(&mut self, s: FixedSizeBinarySlice<'_>) -> Result<(), ArrowError> {
+        self.extend_from_binary_slice(s, None)
+    }
+
+    fn check_push(&self, v: &&[u8]) -> Result<(), ArrowError> {
+        self.check_value(v)
+    }
+
+    fn check_extend_from_slice(&self, s: &FixedSizeBinarySlice<'_>) -> Result<(), ArrowError> {
+        self.check_byte_width(s)
+    }
+}
+
+impl TypedBackend<Option<FixedSizeBinary>> for FixedBinaryBuilder {
+    type Config = FixedSizeBinaryConfig;
+
+    fn data_type(config: &FixedSizeBinaryConfig) -> DataType {
+        DataType::FixedSizeBinary(config.byte_width_i32())
+    }
+
+    fn new(config: BuilderConfig<Option<FixedSizeBinary>>) -> Self {
+        Self::from_config(config.capacity, config.backend.byte_width_i32())
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<&[u8]>) -> Result<(), ArrowError> {
+        match v {
+            Some(v) => self.append_value(v),
+            None => {
+                self.extend_with_nulls(1);
+                Ok(())
+            }
+        }
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: OptionSlice<'_, FixedSizeBinary>,
+    ) -> Result<(), ArrowError> {
+        self.extend_from_binary_slice(slice.values, Some(slice.is_valid))
+    }
+
+    fn check_push(&self, v: &Option<&[u8]>) -> Result<(), ArrowError> {
+        v.map_or(Ok(()), |v| self.check_value(v))
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &OptionSlice<'_, FixedSizeBinary>,
+    ) -> Result<(), ArrowError> {
+        slice.slice_len()?;
+        self.check_byte_width(&slice.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::{
+            tests::{
+                check_extend_from_options, check_extend_from_values, check_extend_outcome,
+                check_extend_with_nulls, check_init_default_optional,
+                check_init_with_capacity_optional, check_init_with_capacity_outcome, check_push,
+                check_push_option, check_validity, option_vec,
+            },
+            BuilderConfig,
+        },
+        tests::length_or_capacity,
+        types::fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig, FixedSizeBinarySlice},
+        ArrayElement, OptionSlice, TypedBuilder,
+    };
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    macro_rules! test_byte_arrays {
+        ($($mod_name:ident : $width:literal),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Element = [u8; $width];
+
+                    #[test]
+                    fn init_default() -> TestCaseResult {
+                        check_init_default_optional::<Element>()
+                    }
+
+                    proptest! {
+                        #[test]
+                        fn init_with_capacity(capacity in length_or_capacity()) {
+                            check_init_with_capacity_optional::<Element>(|| (), capacity)?;
+                        }
+
+                        #[test]
+                        fn push_value(init_capacity in length_or_capacity(), value: Element) {
+                            check_push::<Element>((), init_capacity, value)?;
+                        }
+
+                        #[test]
+                        fn push_option(init_capacity in length_or_capacity(), value: Option<Element>) {
+                            check_push_option::<Element>((), init_capacity, value)?;
+                        }
+
+                        #[test]
+                        fn extend_from_values(init_capacity in length_or_capacity(), values: Vec<Element>) {
+                            check_extend_from_values::<Element>(|| (), init_capacity, &values)?;
+                            let mut builder = TypedBuilder::<Element>::with_capacity(init_capacity);
+                            builder.extend_from_slice(&values);
+                            let array = builder.finish();
+                            prop_assert_eq!(array.as_slice(), &values[..]);
+                        }
+
+                        #[test]
+                        fn extend_from_options(
+                            init_capacity in length_or_capacity(),
+                            (values, is_valid) in option_vec::<Element>(),
+                        ) {
+                            check_extend_from_options::<Element>((), init_capacity, OptionSlice {
+                                values: &values,
+                                is_valid: &is_valid,
+                            })?;
+                        }
+
+                        #[test]
+                        fn extend_with_nulls(
+                            init_capacity in length_or_capacity(),
+                            num_nulls in length_or_capacity()
+                        ) {
+                            check_extend_with_nulls::<Element>((), init_capacity, num_nulls)?;
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_byte_arrays!(width1: 1, width16: 16, width32: 32);
+
+    /// Maximal byte width used in runtime-sized tests
+    const MAX_BYTE_WIDTH: usize = 8;
+
+    /// Generate a runtime byte width configuration
+    fn config() -> impl Strategy<Value = FixedSizeBinaryConfig> {
+        (1..=MAX_BYTE_WIDTH).prop_map(|width| FixedSizeBinaryConfig::new(width).unwrap())
+    }
+
+    /// Generate a byte string whose length may or may not be `byte_width`
+    fn bytes(byte_width: usize) -> impl Strategy<Value = Vec<u8>> {
+        prop_oneof![
+            4 => prop::collection::vec(any::<u8>(), byte_width),
+            1 => prop::collection::vec(any::<u8>(), 0..=MAX_BYTE_WIDTH),
+        ]
+    }
+
+    /// Generate a configuration and concatenated values of a possibly
+    /// different byte width
+    fn config_and_slice() -> impl Strategy<Value = (FixedSizeBinaryConfig, usize, Vec<u8>)> {
+        config().prop_flat_map(|config| {
+            let byte_width = prop_oneof![
+                4 => Just(config.byte_width()),
+                1 => 1..=MAX_BYTE_WIDTH,
+            ];
+            (Just(config), byte_width, length_or_capacity()).prop_flat_map(
+                |(config, byte_width, len)| {
+                    (
+                        Just(config),
+                        Just(byte_width),
+                        prop::collection::vec(any::<u8>(), len * byte_width),
+                    )
+                },
+            )
+        })
+    }
+
+    /// Create a builder of runtime-sized values
+    fn make_builder<T: ArrayElement<BuilderBackend = super::FixedBinaryBuilder> + ?Sized>(
+        config: FixedSizeBinaryConfig,
+        capacity: usize,
+    ) -> TypedBuilder<T>
+    where
+        super::FixedBinaryBuilder: super::TypedBackend<T, Config = FixedSizeBinaryConfig>,
+    {
+        TypedBuilder::with_config(BuilderConfig::with_backend(Some(capacity), config))
+    }
+
+    #[test]
+    fn config_new() {
+        assert!(FixedSizeBinaryConfig::new(0).is_err());
+        assert_eq!(FixedSizeBinaryConfig::new(1).unwrap().byte_width(), 1);
+        let max = i32::MAX as usize;
+        assert_eq!(FixedSizeBinaryConfig::new(max).unwrap().byte_width(), max);
+        assert!(FixedSizeBinaryConfig::new(max + 1).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(config in config(), capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<FixedSizeBinary>(|| config, capacity)?;
+        }
+
+        #[test]
+        fn push_value(
+            (config, value) in config().prop_flat_map(|config| (Just(config), bytes(config.byte_width()))),
+            init_capacity in length_or_capacity(),
+        ) {
+            let mut builder = make_builder::<FixedSizeBinary>(config, init_capacity);
+            let result = builder.push(&value);
+            if value.len() == config.byte_width() {
+                prop_assert!(result.is_ok());
+                check_extend_outcome(&builder, init_capacity, 1)?;
+                let array = builder.finish();
+                prop_assert_eq!(array.value(0), &value[..]);
+            } else {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn push_option(
+            (config, value) in config().prop_flat_map(|config| {
+                (Just(config), prop::option::of(bytes(config.byte_width())))
+            }),
+            init_capacity in length_or_capacity(),
+        ) {
+            let mut builder = make_builder::<Option<FixedSizeBinary>>(config, init_capacity);
+            let result = builder.push(value.as_deref());
+            if value.as_ref().is_none_or(|value| value.len() == config.byte_width()) {
+                prop_assert!(result.is_ok());
+                check_extend_outcome(&builder, init_capacity, 1)?;
+                check_validity(&builder, &[value.is_some()])?;
+                let array = builder.finish();
+                prop_assert_eq!(array.value(0), value.as_deref());
+            } else {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_from_values(
+            (config, byte_width, bytes) in config_and_slice(),
+            init_capacity in length_or_capacity(),
+        ) {
+            let slice = FixedSizeBinarySlice::new(byte_width, &bytes).unwrap();
+            let valid = byte_width == config.byte_width();
+
+            let mut builder = make_builder::<FixedSizeBinary>(config, init_capacity);
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                let array = builder.finish();
+                prop_assert_eq!(array.as_slice(), slice);
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+
+            let mut builder = make_builder::<Option<FixedSizeBinary>>(config, init_capacity);
+            let result = builder.extend_from_value_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                check_validity(&builder, &vec![true; slice.len()])?;
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_from_options(
+            (config, byte_width, bytes) in config_and_slice(),
+            init_capacity in length_or_capacity(),
+            is_valid: Vec<bool>,
+            truncate_validity: bool,
+        ) {
+            let values = FixedSizeBinarySlice::new(byte_width, &bytes).unwrap();
+            let mut is_valid = (0..values.len())
+                .map(|idx| is_valid.get(idx).copied().unwrap_or(true))
+                .collect::<Vec<_>>();
+            if truncate_validity {
+                is_valid.pop();
+            }
+            let valid = byte_width == config.byte_width() && is_valid.len() == values.len();
+
+            let mut builder = make_builder::<Option<FixedSizeBinary>>(config, init_capacity);
+            let result = builder.extend_from_slice(OptionSlice {
+                values,
+                is_valid: &is_valid,
+            });
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, values.len())?;
+                check_validity(&builder, &is_valid)?;
+                let array = builder.finish();
+                let expected = values.iter().zip(&is_valid).map(|(v, valid)| valid.then_some(v));
+                prop_assert!(array.iter().eq(expected));
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_with_nulls(
+            config in config(),
+            init_capacity in length_or_capacity(),
+            num_nulls in length_or_capacity()
+        ) {
+            check_extend_with_nulls::<FixedSizeBinary>(config, init_capacity, num_nulls)?;
+        }
+    }
+}
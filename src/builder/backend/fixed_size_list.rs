@@ -0,0 +1,669 @@
+//! Strong typing layer on top of [`FixedSizeListArray`]
+//!
+//! arrow-rs' `FixedSizeListBuilder` can only append lists one by one, by
+//! pushing items into its child builder, and it does not track capacity.
+//! Therefore, we use our own builder, which keeps track of validity on top of
+//! a strongly typed child builder and appends list items in bulk.
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::{BackendConfig, BuilderConfig},
+    types::{
+        fixed_size_list::{
+            FixedSizeList, FixedSizeListConfig, FixedSizeListItem, FixedSizeListSlice, ListSize,
+        },
+        primitive::NativeType,
+    },
+    ArrayElement, ElementSlice, InsertionResult, OptionSlice,
+};
+use arrow_array::{
+    builder::{ArrayBuilder, PrimitiveBuilder},
+    ArrayRef, FixedSizeListArray,
+};
+use arrow_buffer::{NullBuffer, NullBufferBuilder};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef};
+use std::{any::Any, sync::Arc};
+
+/// Builder of [`FixedSizeListArray`]s of `T`
+#[derive(Debug)]
+pub struct FixedListBuilder<T: ArrayElement + ?Sized> {
+    /// Builder of concatenated list items
+    items: T::BuilderBackend,
+
+    /// Validity bitmap
+    nulls: NullBufferBuilder,
+
+    /// Field of the list items
+    field: FieldRef,
+
+    /// Number of items per list
+    list_size: i32,
+}
+//
+impl<T: ArrayElement + ?Sized> FixedListBuilder<T> {
+    /// Default number of lists that builders can hold without reallocating
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Field of the list items, given the backend configuration of `T`
+    fn item_field(config: &BackendConfig<T>) -> Field {
+        T::field_with_config("item", config)
+    }
+
+    /// Arrow data type of the arrays produced by this builder
+    fn list_data_type(config: &FixedSizeListConfig<T>) -> DataType {
+        DataType::FixedSizeList(
+            Arc::new(Self::item_field(config.items())),
+            config.list_size_i32(),
+        )
+    }
+
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, config: FixedSizeListConfig<T>) -> Self {
+        let capacity = capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        let list_size = config.list_size_i32();
+        let items_config = config.items().clone();
+        Self {
+            field: Arc::new(Self::item_field(&items_config)),
+            items: <T::BuilderBackend as TypedBackend<T>>::new(BuilderConfig::with_backend(
+                Some(capacity * list_size as usize),
+                items_config,
+            )),
+            nulls: NullBufferBuilder::new(capacity),
+            list_size,
+        }
+    }
+
+    /// Check that a list has the right length and that its items can be
+    /// inserted
+    fn check_list(&self, items: &T::Slice<'_>) -> Result<(), ArrowError> {
+        let num_items = items.slice_len()?;
+        if num_items != self.list_size as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected a list of {} items, got {num_items} items",
+                self.list_size
+            )));
+        }
+        self.items.check_extend_from_slice(items)
+    }
+
+    /// Append a list with the specified validity
+    ///
+    /// The builder is left untouched if the list does not have the right
+    /// length or if its items cannot be inserted.
+    fn append_list(&mut self, items: T::Slice<'_>, valid: bool) -> Result<(), ArrowError> {
+        self.check_list(&items)?;
+        self.items.extend_from_slice(items).into_result()?;
+        self.nulls.append(valid);
+        Ok(())
+    }
+
+    /// Check that a slice of runtime-sized lists has the right list size and
+    /// that its items can be inserted
+    fn check_list_slice(&self, lists: &FixedSizeListSlice<'_, T>) -> Result<(), ArrowError> {
+        if lists.list_size() != self.list_size as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected lists of {} items, got lists of {} items",
+                self.list_size,
+                lists.list_size()
+            )));
+        }
+        self.items.check_extend_from_slice(&lists.values())
+    }
+
+    /// Append a slice of runtime-sized lists, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_list_slice(
+        &mut self,
+        lists: FixedSizeListSlice<'_, T>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        self.check_list_slice(&lists)?;
+        if let Some(is_valid) = is_valid {
+            if is_valid.len() != lists.len() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Value and validity lengths must be equal".to_string(),
+                ));
+            }
+        }
+        self.items.extend_from_slice(lists.values()).into_result()?;
+        match is_valid {
+            Some(is_valid) => self.nulls.append_slice(is_valid),
+            None => self.nulls.append_n_non_nulls(lists.len()),
+        }
+        Ok(())
+    }
+
+    /// Build an array from the current items and validity
+    fn make_array(&self, items: ArrayRef, nulls: Option<NullBuffer>) -> FixedSizeListArray {
+        FixedSizeListArray::new(self.field.clone(), self.list_size, items, nulls)
+    }
+}
+//
+impl<T: FixedSizeListItem> FixedListBuilder<T> {
+    /// Create a builder of `[T; N]` lists
+    ///
+    /// This bypasses the generic `TypedBackend<T>` interface of the child
+    /// builder, because the trait solver cannot prove that it is implemented
+    /// for all primitive types (see the FIXMEs in the primitive backend).
+    fn from_list_size<const N: usize>(capacity: Option<usize>) -> Self {
+        let capacity = capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        Self {
+            items: PrimitiveBuilder::with_capacity(capacity * N)
+                .with_data_type(T::arrow_data_type()),
+            nulls: NullBufferBuilder::new(capacity),
+            field: Arc::new(Self::array_item_field()),
+            list_size: ListSize::<N>::I32,
+        }
+    }
+
+    /// Field of the items of `[T; N]` lists
+    fn array_item_field() -> Field {
+        Field::new("item", T::arrow_data_type(), false)
+    }
+
+    /// Arrow data type of arrays of `[T; N]`
+    fn array_data_type<const N: usize>() -> DataType {
+        DataType::FixedSizeList(Arc::new(Self::array_item_field()), ListSize::<N>::I32)
+    }
+
+    /// Append `lists.len()` lists, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_arrays<const N: usize>(
+        &mut self,
+        lists: &[[T; N]],
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        debug_assert_eq!(self.list_size, ListSize::<N>::I32);
+        match is_valid {
+            Some(is_valid) if is_valid.len() != lists.len() => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Value and validity lengths must be equal".to_string(),
+                ));
+            }
+            Some(is_valid) => self.nulls.append_slice(is_valid),
+            None => self.nulls.append_n_non_nulls(lists.len()),
+        }
+        let items: &[T] = lists.as_flattened();
+        // SAFETY: Primitive types are repr(transparent) wrappers over the
+        //         corresponding Arrow native types, so it is safe to transmute
+        //         &[T] into &[NativeType<T>].
+        let items = unsafe { std::mem::transmute::<&[T], &[NativeType<T>]>(items) };
+        self.items.append_slice(items);
+        Ok(())
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> ArrayBuilder for FixedListBuilder<T> {
+    fn len(&self) -> usize {
+        self.nulls.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<T: ArrayElement + ?Sized> Backend for FixedListBuilder<T> {
+    type Array = FixedSizeListArray;
+
+    fn capacity(&self) -> usize {
+        self.items.capacity() / self.list_size as usize
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        // Null lists must still have list_size items, which are masked by the
+        // list validity bitmap
+        self.items.extend_with_nulls(n * self.list_size as usize);
+        self.nulls.append_n_nulls(n);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.items.truncate(len * self.list_size as usize);
+        super::truncate_nulls(&mut self.nulls, len);
+    }
+
+    fn finish(&mut self) -> FixedSizeListArray {
+        let items = Arc::new(Backend::finish(&mut self.items));
+        let nulls = self.nulls.finish();
+        self.make_array(items, nulls)
+    }
+
+    fn finish_cloned(&self) -> FixedSizeListArray {
+        let items = Arc::new(Backend::finish_cloned(&self.items));
+        self.make_array(items, self.nulls.finish_cloned())
+    }
+}
+
+impl<T: ArrayElement + ?Sized> ValiditySlice for FixedListBuilder<T> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.nulls.as_slice()
+    }
+}
+
+impl<T: FixedSizeListItem, const N: usize> TypedBackend<[T; N]> for FixedListBuilder<T> {
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        Self::array_data_type::<N>()
+    }
+
+    fn new(config: BuilderConfig<[T; N]>) -> Self {
+        Self::from_list_size::<N>(config.capacity)
+    }
+
+    #[inline]
+    fn push(&mut self, v: [T; N]) {
+        TypedBackend::<[T; N]>::extend_from_slice(self, std::slice::from_ref(&v))
+    }
+
+    fn extend_from_slice(&mut self, s: &[[T; N]]) {
+        self.extend_from_arrays(s, None)
+            .expect("cannot fail in the absence of a validity slice")
+    }
+}
+
+impl<T: FixedSizeListItem, const N: usize> TypedBackend<Option<[T; N]>> for FixedListBuilder<T> {
+    type Config = ();
+
+    fn data_type(_config: &()) -> DataType {
+        Self::array_data_type::<N>()
+    }
+
+    fn new(config: BuilderConfig<Option<[T; N]>>) -> Self {
+        Self::from_list_size::<N>(config.capacity)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<[T; N]>) {
+        match v {
+            Some(v) => TypedBackend::<[T; N]>::push(self, v),
+            None => self.extend_with_nulls(1),
+        }
+    }
+
+    fn extend_from_slice(&mut self, slice: OptionSlice<'_, [T; N]>) -> Result<(), ArrowError> {
+        self.extend_from_arrays(slice.values, Some(slice.is_valid))
+    }
+}
+
+impl<T: ArrayElement + ?Sized> TypedBackend<FixedSizeList<T>> for FixedListBuilder<T> {
+    type Config = FixedSizeListConfig<T>;
+
+    fn data_type(config: &FixedSizeListConfig<T>) -> DataType {
+        Self::list_data_type(config)
+    }
+
+    fn new(config: BuilderConfig<FixedSizeList<T>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: T::Slice<'_>) -> Result<(), ArrowError> {
+        self.append_list(v, true)
+    }
+
+    fn extend_from_slice(&mut self, s: FixedSizeListSlice<'_, T>) -> Result<(), ArrowError> {
+        self.extend_from_list_slice(s, None)
+    }
+
+    fn check_push(&self, v: &T::Slice<'_>) -> Result<(), ArrowError> {
+        self.check_list(v)
+    }
+
+    fn check_extend_from_slice(&self, s: &FixedSizeListSlice<'_, T>) -> Result<(), ArrowError> {
+        self.check_list_slice(s)
+    }
+}
+
+impl<T: ArrayElement + ?Sized> TypedBackend<Option<FixedSizeList<T>>> for FixedListBuilder<T> {
+    type Config = FixedSizeListConfig<T>;
+
+    fn data_type(config: &FixedSizeListConfig<T>) -> DataType {
+        Self::list_data_type(config)
+    }
+
+    fn new(config: BuilderConfig<Option<FixedSizeList<T>>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<T::Slice<'_>>) -> Result<(), ArrowError> {
+        match v {
+            Some(v) => self.append_list(v, true),
+            None => {
+                self.extend_with_nulls(1);
+                Ok(())
+            }
+        }
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: OptionSlice<'_, FixedSizeList<T>>,
+    ) -> Result<(), ArrowError> {
+        self.extend_from_list_slice(slice.values, Some(slice.is_valid))
+    }
+
+    fn check_push(&self, v: &Option<T::Slice<'_>>) -> Result<(), ArrowError> {
+        v.as_ref().map_or(Ok(()), |v| self.check_list(v))
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &OptionSlice<'_, FixedSizeList<T>>,
+    ) -> Result<(), ArrowError> {
+        slice.slice_len()?;
+        self.check_list_slice(&slice.values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::{
+            tests::{
+                check_extend_from_options, check_extend_from_values, check_extend_outcome,
+                check_extend_with_nulls, check_init_default_optional,
+                check_init_with_capacity_optional, check_init_with_capacity_outcome, check_push,
+                check_push_option, check_validity, option_vec,
+            },
+            BuilderConfig,
+        },
+        tests::length_or_capacity,
+        types::fixed_size_list::{FixedSizeList, FixedSizeListConfig, FixedSizeListSlice},
+        ArrayElement, OptionSlice, TypedBuilder,
+    };
+    use arrow_schema::{DataType, Field};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    macro_rules! test_fixed_size_lists {
+        ($($mod_name:ident : [$item:ty; $size:literal]),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Element = [$item; $size];
+
+                    #[test]
+                    fn init_default() -> TestCaseResult {
+                        check_init_default_optional::<Element>()
+                    }
+
+                    #[test]
+                    fn data_type() {
+                        let item = Field::new("item", <$item>::data_type(&()), false);
+                        assert_eq!(
+                            Element::data_type(&()),
+                            DataType::FixedSizeList(item.into(), $size)
+                        );
+                    }
+
+                    proptest! {
+                        #[test]
+                        fn init_with_capacity(capacity in length_or_capacity()) {
+                            check_init_with_capacity_optional::<Element>(|| (), capacity)?;
+                        }
+
+                        #[test]
+                        fn push_value(init_capacity in length_or_capacity(), value: Element) {
+                            check_push::<Element>((), init_capacity, value)?;
+                        }
+
+                        #[test]
+                        fn push_option(init_capacity in length_or_capacity(), value: Option<Element>) {
+                            check_push_option::<Element>((), init_capacity, value)?;
+                        }
+
+                        #[test]
+                        fn extend_from_values(init_capacity in length_or_capacity(), values: Vec<Element>) {
+                            check_extend_from_values::<Element>(|| (), init_capacity, &values)?;
+                            let mut builder = TypedBuilder::<Element>::with_capacity(init_capacity);
+                            builder.extend_from_slice(&values);
+                            let array = builder.finish();
+                            prop_assert_eq!(array.as_slice(), &values[..]);
+                        }
+
+                        #[test]
+                        fn extend_from_options(
+                            init_capacity in length_or_capacity(),
+                            (values, is_valid) in option_vec::<Element>(),
+                        ) {
+                            check_extend_from_options::<Element>((), init_capacity, OptionSlice {
+                                values: &values,
+                                is_valid: &is_valid,
+                            })?;
+                        }
+
+                        #[test]
+                        fn extend_with_nulls(
+                            init_capacity in length_or_capacity(),
+                            num_nulls in length_or_capacity()
+                        ) {
+                            check_extend_with_nulls::<Element>((), init_capacity, num_nulls)?;
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_fixed_size_lists!(i8x1: [i8; 1], u16x3: [u16; 3], i64x32: [i64; 32]);
+
+    /// Maximal list size used in runtime-sized tests
+    const MAX_LIST_SIZE: usize = 8;
+
+    /// Generate a runtime list size configuration
+    fn config() -> impl Strategy<Value = FixedSizeListConfig<u8>> {
+        (1..=MAX_LIST_SIZE).prop_map(|size| FixedSizeListConfig::new(size).unwrap())
+    }
+
+    /// Generate a list whose length may or may not be `list_size`
+    fn list(list_size: usize) -> impl Strategy<Value = Vec<u8>> {
+        prop_oneof![
+            4 => prop::collection::vec(any::<u8>(), list_size),
+            1 => prop::collection::vec(any::<u8>(), 0..=MAX_LIST_SIZE),
+        ]
+    }
+
+    /// Generate a configuration and concatenated lists of a possibly
+    /// different list size
+    fn config_and_slice() -> impl Strategy<Value = (FixedSizeListConfig<u8>, usize, Vec<u8>)> {
+        config().prop_flat_map(|config| {
+            let list_size = prop_oneof![
+                4 => Just(config.list_size()),
+                1 => 1..=MAX_LIST_SIZE,
+            ];
+            (Just(config), list_size, length_or_capacity()).prop_flat_map(
+                |(config, list_size, len)| {
+                    (
+                        Just(config),
+                        Just(list_size),
+                        prop::collection::vec(any::<u8>(), len * list_size),
+                    )
+                },
+            )
+        })
+    }
+
+    /// Create a builder of runtime-sized lists
+    fn make_builder<T: ArrayElement<BuilderBackend = super::FixedListBuilder<u8>> + ?Sized>(
+        config: FixedSizeListConfig<u8>,
+        capacity: usize,
+    ) -> TypedBuilder<T>
+    where
+        super::FixedListBuilder<u8>: super::TypedBackend<T, Config = FixedSizeListConfig<u8>>,
+    {
+        TypedBuilder::with_config(BuilderConfig::with_backend(Some(capacity), config))
+    }
+
+    #[test]
+    fn config_new() {
+        assert!(FixedSizeListConfig::<u8>::new(0).is_err());
+        assert_eq!(FixedSizeListConfig::<u8>::new(1).unwrap().list_size(), 1);
+        let max = i32::MAX as usize;
+        assert_eq!(
+            FixedSizeListConfig::<u8>::new(max).unwrap().list_size(),
+            max
+        );
+        assert!(FixedSizeListConfig::<u8>::new(max + 1).is_err());
+    }
+
+    #[test]
+    fn slice_new() {
+        assert!(FixedSizeListSlice::<u8>::new(0, &[]).is_err());
+        assert!(FixedSizeListSlice::<u8>::new(2, &[1, 2, 3]).is_err());
+        let slice = FixedSizeListSlice::<u8>::new(2, &[1, 2, 3, 4]).unwrap();
+        assert_eq!(slice.len(), 2);
+        assert!(slice.iter().eq([&[1, 2][..], &[3, 4][..]]));
+        let options = FixedSizeListSlice::<Option<u8>>::new(
+            1,
+            OptionSlice {
+                values: &[1, 2],
+                is_valid: &[true],
+            },
+        );
+        assert!(options.is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(config in config(), capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<FixedSizeList<u8>>(|| config.clone(), capacity)?;
+        }
+
+        #[test]
+        fn push_value(
+            (config, value) in config().prop_flat_map(|config| {
+                let list = list(config.list_size());
+                (Just(config), list)
+            }),
+            init_capacity in length_or_capacity(),
+        ) {
+            let list_size = config.list_size();
+            let mut builder = make_builder::<FixedSizeList<u8>>(config, init_capacity);
+            let result = builder.push(&value);
+            if value.len() == list_size {
+                prop_assert!(result.is_ok());
+                check_extend_outcome(&builder, init_capacity, 1)?;
+                let array = builder.finish();
+                prop_assert_eq!(array.value(0), &value[..]);
+            } else {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn push_option(
+            (config, value) in config().prop_flat_map(|config| {
+                let list = prop::option::of(list(config.list_size()));
+                (Just(config), list)
+            }),
+            init_capacity in length_or_capacity(),
+        ) {
+            let list_size = config.list_size();
+            let mut builder = make_builder::<Option<FixedSizeList<u8>>>(config, init_capacity);
+            let result = builder.push(value.as_deref());
+            if value.as_ref().is_none_or(|value| value.len() == list_size) {
+                prop_assert!(result.is_ok());
+                check_extend_outcome(&builder, init_capacity, 1)?;
+                check_validity(&builder, &[value.is_some()])?;
+                let array = builder.finish();
+                prop_assert_eq!(array.value(0), value.as_deref());
+            } else {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_from_values(
+            (config, list_size, items) in config_and_slice(),
+            init_capacity in length_or_capacity(),
+        ) {
+            let slice = FixedSizeListSlice::<u8>::new(list_size, &items).unwrap();
+            let valid = list_size == config.list_size();
+
+            let mut builder = make_builder::<FixedSizeList<u8>>(config.clone(), init_capacity);
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                let array = builder.finish();
+                prop_assert!(array.iter().eq(slice.iter()));
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+
+            let mut builder = make_builder::<Option<FixedSizeList<u8>>>(config, init_capacity);
+            let result = builder.extend_from_value_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                check_validity(&builder, &vec![true; slice.len()])?;
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_from_options(
+            (config, list_size, items) in config_and_slice(),
+            init_capacity in length_or_capacity(),
+            is_valid: Vec<bool>,
+            truncate_validity: bool,
+        ) {
+            let values = FixedSizeListSlice::<u8>::new(list_size, &items).unwrap();
+            let mut is_valid = (0..values.len())
+                .map(|idx| is_valid.get(idx).copied().unwrap_or(true))
+                .collect::<Vec<_>>();
+            if truncate_validity {
+                is_valid.pop();
+            }
+            let valid = list_size == config.list_size() && is_valid.len() == values.len();
+
+            let mut builder = make_builder::<Option<FixedSizeList<u8>>>(config, init_capacity);
+            let result = builder.extend_from_slice(OptionSlice {
+                values,
+                is_valid: &is_valid,
+            });
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, values.len())?;
+                check_validity(&builder, &is_valid)?;
+                let array = builder.finish();
+                let expected = values.iter().zip(&is_valid).map(|(v, valid)| valid.then_some(v));
+                prop_assert!(array.iter().eq(expected));
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_with_nulls(
+            config in config(),
+            init_capacity in length_or_capacity(),
+            num_nulls in length_or_capacity()
+        ) {
+            check_extend_with_nulls::<FixedSizeList<u8>>(config, init_capacity, num_nulls)?;
+        }
+    }
+}
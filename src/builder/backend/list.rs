@@ -0,0 +1,625 @@
+//! Strong typing layer on top of [`GenericListArray`]
+//!
+//! arrow-rs' `GenericListBuilder` can only append lists one by one, by pushing
+//! items into its child builder, and it does not track capacity. Therefore, we
+//! use our own builder, which keeps track of list offsets and validity on top
+//! of a strongly typed child builder.
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::{BackendConfig, BuilderConfig},
+    types::list::{GenericListSlice, LargeList, List},
+    ArrayElement, ElementSlice, InsertionResult, OptionSlice,
+};
+use arrow_array::{builder::ArrayBuilder, ArrayRef, GenericListArray, OffsetSizeTrait};
+use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef};
+use std::{any::Any, sync::Arc};
+
+/// Builder of [`GenericListArray`]s of `T`
+#[derive(Debug)]
+pub struct ListBuilder<T: ArrayElement + ?Sized, O: OffsetSizeTrait = i32> {
+    /// Builder of concatenated list items
+    items: T::BuilderBackend,
+
+    /// Start and end position of each list within `items`
+    offsets: Vec<O>,
+
+    /// Validity bitmap
+    nulls: NullBufferBuilder,
+
+    /// Field of the list items
+    field: FieldRef,
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> ListBuilder<T, O> {
+    /// Default number of lists that builders can hold without reallocating
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Field of the list items, given the backend configuration of `T`
+    fn item_field(config: &BackendConfig<T>) -> Field {
+        T::field_with_config("item", config)
+    }
+
+    /// Arrow data type of the arrays produced by this builder
+    fn list_data_type(config: &BackendConfig<T>) -> DataType {
+        GenericListArray::<O>::DATA_TYPE_CONSTRUCTOR(Arc::new(Self::item_field(config)))
+    }
+
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, config: BackendConfig<T>) -> Self {
+        let capacity = capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(O::usize_as(0));
+        Self {
+            field: Arc::new(Self::item_field(&config)),
+            items: <T::BuilderBackend as TypedBackend<T>>::new(BuilderConfig::with_backend(
+                None, config,
+            )),
+            offsets,
+            nulls: NullBufferBuilder::new(capacity),
+        }
+    }
+
+    /// List offset that `num_items` more items would lead to, if it does not
+    /// overflow `O`
+    fn next_offset(&self, num_items: usize) -> Result<O, ArrowError> {
+        self.items
+            .len()
+            .checked_add(num_items)
+            .and_then(O::from_usize)
+            .ok_or_else(|| ArrowError::InvalidArgumentError("list offset overflow".to_string()))
+    }
+
+    /// Check that the items of a list can be inserted, and return the offset
+    /// of the end of this list
+    fn check_list(&self, items: &T::Slice<'_>) -> Result<O, ArrowError> {
+        let end = self.next_offset(items.slice_len()?)?;
+        self.items.check_extend_from_slice(items)?;
+        Ok(end)
+    }
+
+    /// Append a list with the specified validity
+    ///
+    /// The builder is left untouched if the items cannot be inserted.
+    fn append_list(&mut self, items: T::Slice<'_>, valid: bool) -> Result<(), ArrowError> {
+        let end = self.check_list(&items)?;
+        self.items.extend_from_slice(items).into_result()?;
+        self.offsets.push(end);
+        self.nulls.append(valid);
+        Ok(())
+    }
+
+    /// Append a valid list whose items are pushed one by one
+    ///
+    /// The builder is left untouched if an item is rejected.
+    pub(crate) fn append_items<'a>(
+        &mut self,
+        items: impl IntoIterator<Item = T::Value<'a>>,
+    ) -> Result<(), ArrowError> {
+        let start = self.items.len();
+        let end = items
+            .into_iter()
+            .try_for_each(|item| self.items.push(item).into_result())
+            .and_then(|()| self.next_offset(0));
+        match end {
+            Ok(end) => {
+                self.offsets.push(end);
+                self.nulls.append_non_null();
+                Ok(())
+            }
+            Err(error) => {
+                // Items cannot be checked in advance, as they are only
+                // available one by one, so roll back the preceding items
+                self.items.truncate(start);
+                Err(error)
+            }
+        }
+    }
+
+    /// Check that a slice of lists, with optional validity, can be appended
+    fn check_list_slice(
+        &self,
+        lists: &GenericListSlice<'_, T, O>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        if let Some(is_valid) = is_valid {
+            if lists.len() != is_valid.len() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Value and validity lengths must be equal".to_string(),
+                ));
+            }
+        }
+        lists.validate()?;
+        let (Some(first), Some(last)) = (lists.offsets.first(), lists.offsets.last()) else {
+            return Ok(());
+        };
+        self.next_offset(last.as_usize() - first.as_usize())?;
+        let items = lists
+            .values
+            .clone()
+            .slice_range(first.as_usize()..last.as_usize());
+        self.items.check_extend_from_slice(&items)
+    }
+
+    /// Append a slice of lists, with optional validity
+    ///
+    /// The builder is left untouched if the input is invalid.
+    fn extend_from_list_slice(
+        &mut self,
+        lists: GenericListSlice<'_, T, O>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        self.check_list_slice(&lists, is_valid)?;
+        let len = lists.len();
+        let (Some(first), Some(last)) = (lists.offsets.first(), lists.offsets.last()) else {
+            return Ok(());
+        };
+        let (first, last) = (first.as_usize(), last.as_usize());
+        let start = self.items.len();
+        self.items
+            .extend_from_slice(lists.values.slice_range(first..last))
+            .into_result()?;
+        // Offsets cannot overflow, as the end of the last list was checked
+        self.offsets.extend(
+            lists.offsets[1..]
+                .iter()
+                .map(|offset| O::usize_as(start + offset.as_usize() - first)),
+        );
+        match is_valid {
+            Some(is_valid) => self.nulls.append_slice(is_valid),
+            None => self.nulls.append_n_non_nulls(len),
+        }
+        Ok(())
+    }
+
+    /// Build an array from the current items, offsets and validity
+    fn make_array(
+        &self,
+        offsets: Vec<O>,
+        items: ArrayRef,
+        nulls: Option<NullBuffer>,
+    ) -> GenericListArray<O> {
+        // SAFETY: Offsets are only ever appended in increasing order, starting
+        //         from 0, as items are appended to the child builder.
+        let offsets = unsafe { OffsetBuffer::new_unchecked(ScalarBuffer::from(offsets)) };
+        GenericListArray::new(self.field.clone(), offsets, items, nulls)
+    }
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> ArrayBuilder for ListBuilder<T, O> {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> Backend for ListBuilder<T, O> {
+    type Array = GenericListArray<O>;
+
+    fn capacity(&self) -> usize {
+        self.offsets.capacity() - 1
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        let last = *self.offsets.last().expect("there is always a first offset");
+        self.offsets.extend(std::iter::repeat_n(last, n));
+        self.nulls.append_n_nulls(n);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.offsets.truncate(len + 1);
+        self.items.truncate(self.offsets[len].as_usize());
+        super::truncate_nulls(&mut self.nulls, len);
+    }
+
+    fn finish(&mut self) -> GenericListArray<O> {
+        let offsets = std::mem::replace(&mut self.offsets, vec![O::usize_as(0)]);
+        let items = Arc::new(Backend::finish(&mut self.items));
+        let nulls = self.nulls.finish();
+        self.make_array(offsets, items, nulls)
+    }
+
+    fn finish_cloned(&self) -> GenericListArray<O> {
+        let items = Arc::new(Backend::finish_cloned(&self.items));
+        self.make_array(self.offsets.clone(), items, self.nulls.finish_cloned())
+    }
+}
+
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> ValiditySlice for ListBuilder<T, O> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.nulls.as_slice()
+    }
+}
+
+// Enable strongly typed builders of lists with 32-bit and 64-bit offsets
+macro_rules! impl_list_backend {
+    ($($list:ident => $offset:ty),*) => {
+        $(
+            impl<T: ArrayElement + ?Sized> TypedBackend<$list<T>> for ListBuilder<T, $offset> {
+                type Config = BackendConfig<T>;
+
+                fn data_type(config: &BackendConfig<T>) -> DataType {
+                    Self::list_data_type(config)
+                }
+
+                fn new(config: BuilderConfig<$list<T>>) -> Self {
+                    Self::from_config(config.capacity, config.backend)
+                }
+
+                #[inline]
+                fn push(&mut self, v: T::Slice<'_>) -> T::ExtendFromSliceResult {
+                    T::ExtendFromSliceResult::from_result(self.append_list(v, true))
+                }
+
+                fn extend_from_slice(
+                    &mut self,
+                    s: GenericListSlice<'_, T, $offset>,
+                ) -> Result<(), ArrowError> {
+                    self.extend_from_list_slice(s, None)
+                }
+
+                fn check_push(&self, v: &T::Slice<'_>) -> Result<(), ArrowError> {
+                    self.check_list(v).map(|_| ())
+                }
+
+                fn check_extend_from_slice(
+                    &self,
+                    s: &GenericListSlice<'_, T, $offset>,
+                ) -> Result<(), ArrowError> {
+                    self.check_list_slice(s, None)
+                }
+            }
+
+            impl<T: ArrayElement + ?Sized> TypedBackend<Option<$list<T>>> for ListBuilder<T, $offset> {
+                type Config = BackendConfig<T>;
+
+                fn data_type(config: &BackendConfig<T>) -> DataType {
+                    Self::list_data_type(config)
+                }
+
+                fn new(config: BuilderConfig<Option<$list<T>>>) -> Self {
+                    Self::from_config(config.capacity, config.backend)
+                }
+
+                #[inline]
+                fn push(&mut self, v: Option<T::Slice<'_>>) -> T::ExtendFromSliceResult {
+                    T::ExtendFromSliceResult::from_result(match v {
+                        Some(v) => self.append_list(v, true),
+                        None => {
+                            self.extend_with_nulls(1);
+                            Ok(())
+                        }
+                    })
+                }
+
+                fn extend_from_slice(
+                    &mut self,
+                    slice: OptionSlice<'_, $list<T>>,
+                ) -> Result<(), ArrowError> {
+                    self.extend_from_list_slice(slice.values, Some(slice.is_valid))
+                }
+
+                fn check_push(&self, v: &Option<T::Slice<'_>>) -> Result<(), ArrowError> {
+                    v.as_ref().map_or(Ok(()), |v| self.check_list(v).map(|_| ()))
+                }
+
+                fn check_extend_from_slice(
+                    &self,
+                    slice: &OptionSlice<'_, $list<T>>,
+                ) -> Result<(), ArrowError> {
+                    self.check_list_slice(&slice.values, Some(slice.is_valid))
+                }
+            }
+        )*
+    };
+}
+//
+impl_list_backend!(List => i32, LargeList => i64);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{
+        builder::tests::{
+            check_extend_outcome, check_extend_with_nulls, check_init_default_optional,
+            check_init_with_capacity_optional, check_init_with_capacity_outcome, check_push,
+            check_validity,
+        },
+        tests::length_or_capacity,
+        types::{
+            bytes::StringSlice,
+            list::{LargeList, LargeListSlice, List, ListSlice},
+            primitive::Null,
+        },
+        OptionSlice, TypedBuilder,
+    };
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    /// Generate flattened list items and list offsets, which are valid most of
+    /// the time and may not start at 0
+    pub fn offsets_and_values() -> impl Strategy<Value = (Vec<i32>, Vec<u8>)> {
+        let valid = (any::<Vec<Vec<u8>>>(), any::<Vec<u8>>(), any::<Vec<u8>>()).prop_map(
+            |(lists, prefix, suffix)| {
+                let mut offsets = vec![prefix.len() as i32];
+                let mut values = prefix;
+                for list in lists {
+                    values.extend_from_slice(&list);
+                    offsets.push(values.len() as i32);
+                }
+                values.extend_from_slice(&suffix);
+                (offsets, values)
+            },
+        );
+        prop_oneof![
+            4 => valid,
+            1 => any::<(Vec<i32>, Vec<u8>)>(),
+        ]
+    }
+
+    /// Truth that list offsets are valid for some flattened items
+    pub fn offsets_are_valid(offsets: &[i32], values_len: usize) -> bool {
+        offsets
+            .iter()
+            .all(|&offset| offset >= 0 && offset as usize <= values_len)
+            && offsets.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    /// Lists that are expected from valid offsets and flattened items
+    pub fn expected_lists<'a>(offsets: &[i32], values: &'a [u8]) -> Vec<&'a [u8]> {
+        offsets
+            .windows(2)
+            .map(|pair| &values[pair[0] as usize..pair[1] as usize])
+            .collect()
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default_optional::<List<u8>>()?;
+        check_init_default_optional::<List<String>>()?;
+        check_init_default_optional::<List<List<Option<u8>>>>()?;
+        check_init_default_optional::<LargeList<u8>>()
+    }
+
+    #[test]
+    fn offset_overflow() -> TestCaseResult {
+        // Null items take no memory, so lists of nulls can cheaply fill the
+        // offset range
+        let max = i32::MAX as usize;
+        let mut builder = TypedBuilder::<List<Null>>::new();
+        builder.push(max);
+        let lists = ListSlice::<Null> {
+            offsets: &[0, 0, 1],
+            values: 1,
+        };
+        prop_assert!(builder.extend_from_slice(lists).is_err());
+        builder.extend_from_slice(ListSlice::<Null> {
+            offsets: &[0, 0],
+            values: 0,
+        })?;
+        prop_assert_eq!(builder.len(), 2);
+
+        let mut builder = TypedBuilder::<(u8, List<Null>)>::new();
+        builder.push((1, max))?;
+        prop_assert!(builder.push((2, 1)).is_err());
+        prop_assert_eq!(builder.finish().len(), 1);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<List<u8>>(|| (), capacity)?;
+            check_init_with_capacity_optional::<LargeList<u8>>(|| (), capacity)?;
+        }
+
+        #[test]
+        fn push_value(init_capacity in length_or_capacity(), value: Vec<u8>) {
+            check_push::<List<u8>>((), init_capacity, &value)?;
+            let mut builder = TypedBuilder::<List<u8>>::with_capacity(init_capacity);
+            builder.push(&value);
+            builder.push(&value[value.len() / 2..]);
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), &value[..]);
+            prop_assert_eq!(array.value(1), &value[value.len() / 2..]);
+        }
+
+        #[test]
+        fn push_option(init_capacity in length_or_capacity(), value: Option<Vec<u8>>) {
+            let mut builder = TypedBuilder::<Option<List<u8>>>::with_capacity(init_capacity);
+            builder.push(value.as_deref());
+            check_extend_outcome(&builder, init_capacity, 1)?;
+            check_validity(&builder, &[value.is_some()])?;
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), value.as_deref());
+        }
+
+        #[test]
+        fn push_fallible(init_capacity in length_or_capacity(), value: Vec<Option<u8>>, truncate: bool) {
+            let values = value.iter().map(|v| v.unwrap_or_default()).collect::<Vec<_>>();
+            let mut is_valid = value.iter().map(Option::is_some).collect::<Vec<_>>();
+            if truncate {
+                is_valid.pop();
+            }
+            let valid = is_valid.len() == values.len();
+
+            let mut builder = TypedBuilder::<List<Option<u8>>>::with_capacity(init_capacity);
+            let result = builder.push(OptionSlice { values: &values, is_valid: &is_valid });
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, 1)?;
+                let array = builder.finish();
+                prop_assert!(array.value(0).is_valid.is_none_or(|v| v == &is_valid[..]));
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn push_strings(init_capacity in length_or_capacity(), value: Vec<String>) {
+            let offsets = std::iter::once(0)
+                .chain(value.iter().scan(0, |end, s| {
+                    *end += s.len() as i32;
+                    Some(*end)
+                }))
+                .collect::<Vec<_>>();
+            let values = value.concat();
+            let mut builder = TypedBuilder::<List<String>>::with_capacity(init_capacity);
+            builder.push(StringSlice { offsets: &offsets, values: &values })?;
+            check_extend_outcome(&builder, init_capacity, 1)?;
+            let array = builder.finish();
+            prop_assert!(array.value(0).iter().eq(value.iter().map(String::as_str)));
+        }
+
+        #[test]
+        fn extend_from_values(
+            init_capacity in length_or_capacity(),
+            (offsets, values) in offsets_and_values(),
+        ) {
+            let slice = ListSlice::<u8> { offsets: &offsets, values: &values };
+            let valid = offsets_are_valid(&offsets, values.len());
+
+            let mut builder = TypedBuilder::<List<u8>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if !valid {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            let expected = expected_lists(&offsets, &values);
+            check_extend_outcome(&builder, init_capacity, expected.len())?;
+            let array = builder.finish();
+            prop_assert!(array.iter().eq(expected.iter().copied()));
+
+            let mut builder = TypedBuilder::<Option<List<u8>>>::with_capacity(init_capacity);
+            builder.extend_from_value_slice(slice)?;
+            check_extend_outcome(&builder, init_capacity, expected.len())?;
+            check_validity(&builder, &vec![true; expected.len()])?;
+        }
+
+        #[test]
+        fn extend_large(
+            init_capacity in length_or_capacity(),
+            (offsets, values) in offsets_and_values(),
+        ) {
+            let large_offsets = offsets.iter().map(|&o| i64::from(o)).collect::<Vec<_>>();
+            let slice = LargeListSlice::<u8> { offsets: &large_offsets, values: &values };
+            let valid = offsets_are_valid(&offsets, values.len());
+
+            let mut builder = TypedBuilder::<LargeList<u8>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if !valid {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            let expected = expected_lists(&offsets, &values);
+            check_extend_outcome(&builder, init_capacity, expected.len())?;
+            let array = builder.finish();
+            prop_assert!(array.iter().eq(expected.iter().copied()));
+            prop_assert!(array.as_slice().offsets().first().is_none_or(|&o| o == 0));
+        }
+
+        #[test]
+        fn extend_from_options(
+            init_capacity in length_or_capacity(),
+            (offsets, values) in offsets_and_values(),
+            is_valid: Vec<bool>,
+            truncate_validity: bool,
+        ) {
+            let values = ListSlice::<u8> { offsets: &offsets, values: &values };
+            let mut is_valid = (0..values.len())
+                .map(|idx| is_valid.get(idx).copied().unwrap_or(true))
+                .collect::<Vec<_>>();
+            if truncate_validity {
+                is_valid.pop();
+            }
+            let valid =
+                offsets_are_valid(&offsets, values.values.len()) && is_valid.len() == values.len();
+
+            let mut builder = TypedBuilder::<Option<List<u8>>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(OptionSlice { values, is_valid: &is_valid });
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, values.len())?;
+                check_validity(&builder, &is_valid)?;
+                let array = builder.finish();
+                let expected = expected_lists(&offsets, values.values)
+                    .into_iter()
+                    .zip(&is_valid)
+                    .map(|(list, valid)| valid.then_some(list));
+                prop_assert!(array.iter().eq(expected));
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_nested(
+            init_capacity in length_or_capacity(),
+            (inner_offsets, values) in offsets_and_values(),
+            outer_offsets: Vec<i32>,
+        ) {
+            // Generate outer offsets that index into the inner lists
+            let num_inner = inner_offsets.len().saturating_sub(1) as i32;
+            let mut outer_offsets = outer_offsets
+                .into_iter()
+                .map(|offset| offset.rem_euclid(num_inner + 1))
+                .collect::<Vec<_>>();
+            outer_offsets.sort_unstable();
+            let valid = offsets_are_valid(&inner_offsets, values.len());
+
+            let mut builder = TypedBuilder::<List<List<u8>>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(ListSlice {
+                offsets: &outer_offsets,
+                values: ListSlice { offsets: &inner_offsets, values: &values },
+            });
+            // Inner offsets are only validated if some of them are inserted
+            if outer_offsets.len() < 2 || outer_offsets.first() == outer_offsets.last() || valid {
+                prop_assert!(result.is_ok());
+            }
+            if result.is_err() {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            check_extend_outcome(&builder, init_capacity, outer_offsets.len().saturating_sub(1))?;
+            let array = builder.finish();
+            for (lists, range) in array.iter().zip(outer_offsets.windows(2)) {
+                let expected = inner_offsets
+                    .get(range[0] as usize..=range[1] as usize)
+                    .unwrap_or_default()
+                    .windows(2)
+                    .map(|pair| values.get(pair[0] as usize..pair[1] as usize))
+                    .collect::<Option<Vec<_>>>();
+                prop_assert!(expected.is_some());
+                prop_assert!(lists.iter().eq(expected.unwrap()));
+            }
+        }
+
+        #[test]
+        fn extend_with_nulls(
+            init_capacity in length_or_capacity(),
+            num_nulls in length_or_capacity()
+        ) {
+            check_extend_with_nulls::<List<u8>>((), init_capacity, num_nulls)?;
+        }
+    }
+}
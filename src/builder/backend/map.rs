@@ -0,0 +1,477 @@
+//! Strong typing layer on top of [`MapArray`]
+//!
+//! arrow-rs' `MapBuilder` does not track capacity, always marks map keys as
+//! unsorted, and can only append entries one by one. Since map arrays are
+//! physically lists of key-value structs, we instead build them as lists of
+//! key-value tuples, which are turned into map arrays when the builder is
+//! finished.
+
+use super::{list::ListBuilder, Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::{BackendConfig, BuilderConfig},
+    types::{
+        list::List,
+        map::{Map, MapConfig, MapSlice},
+    },
+    ArrayElement, OptionSlice,
+};
+use arrow_array::{builder::ArrayBuilder, Array, ArrayRef, ListArray, MapArray, StructArray};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields};
+use std::{any::Any, sync::Arc};
+
+/// Builder of [`MapArray`]s from keys of type `K` to values of type `V`
+#[derive(Debug)]
+pub struct MapBuilder<K: ArrayElement, V: ArrayElement> {
+    /// Builder of the map entries, as lists of key-value tuples
+    entries: ListBuilder<(K, V)>,
+
+    /// Field of the map entries
+    field: FieldRef,
+
+    /// Truth that the keys of each map are sorted
+    sorted: bool,
+}
+//
+impl<K: ArrayElement, V: ArrayElement> MapBuilder<K, V> {
+    /// Field of the map entries, given the backend configuration of the keys
+    /// and values
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is nullable, as arrow does not allow map keys to be null.
+    fn entries_field(keys: &BackendConfig<K>, values: &BackendConfig<V>) -> Field {
+        assert!(
+            !K::NULLABLE,
+            "map keys cannot be nullable, but {} is",
+            std::any::type_name::<K>()
+        );
+        let fields = Fields::from(vec![
+            K::field_with_config("keys", keys),
+            V::field_with_config("values", values),
+        ]);
+        Field::new("entries", DataType::Struct(fields), false)
+    }
+
+    /// Arrow data type of the arrays produced by this builder
+    fn map_data_type(config: &MapConfig<K, V>) -> DataType {
+        DataType::Map(
+            Arc::new(Self::entries_field(&config.keys, &config.values)),
+            config.sorted,
+        )
+    }
+
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, config: MapConfig<K, V>) -> Self {
+        let field = Arc::new(Self::entries_field(&config.keys, &config.values));
+        let entries = <ListBuilder<(K, V)> as TypedBackend<List<(K, V)>>>::new(
+            BuilderConfig::with_backend(capacity, (config.keys, config.values)),
+        );
+        Self {
+            entries,
+            field,
+            sorted: config.sorted,
+        }
+    }
+
+    /// Append a valid map whose entries are pushed one by one
+    ///
+    /// The builder is left untouched if an entry is rejected.
+    pub(crate) fn append_entries<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = (K::Value<'a>, V::Value<'a>)>,
+    ) -> Result<(), ArrowError> {
+        self.entries.append_items(entries)
+    }
+
+    /// Turn a list array of key-value tuples into a map array
+    fn make_array(&self, lists: ListArray) -> MapArray {
+        let (_field, offsets, entries, nulls) = lists.into_parts();
+        let (_fields, columns, _nulls) = entries
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .expect("map entries should be built as a struct array")
+            .clone()
+            .into_parts();
+        let DataType::Struct(fields) = self.field.data_type() else {
+            unreachable!("map entries should have a struct data type")
+        };
+        let entries = StructArray::new(fields.clone(), columns, None);
+        MapArray::new(self.field.clone(), offsets, entries, nulls, self.sorted)
+    }
+}
+//
+impl<K: ArrayElement, V: ArrayElement> ArrayBuilder for MapBuilder<K, V> {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Backend for MapBuilder<K, V> {
+    type Array = MapArray;
+
+    fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        self.entries.extend_with_nulls(n)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.entries.truncate(len)
+    }
+
+    fn finish(&mut self) -> MapArray {
+        let lists = Backend::finish(&mut self.entries);
+        self.make_array(lists)
+    }
+
+    fn finish_cloned(&self) -> MapArray {
+        self.make_array(Backend::finish_cloned(&self.entries))
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> ValiditySlice for MapBuilder<K, V> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.entries.validity_slice()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> TypedBackend<Map<K, V>> for MapBuilder<K, V> {
+    type Config = MapConfig<K, V>;
+
+    fn data_type(config: &MapConfig<K, V>) -> DataType {
+        Self::map_data_type(config)
+    }
+
+    fn new(config: BuilderConfig<Map<K, V>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push<'a>(&mut self, v: (K::Slice<'a>, V::Slice<'a>)) -> Result<(), ArrowError> {
+        TypedBackend::<List<(K, V)>>::push(&mut self.entries, v)
+    }
+
+    fn extend_from_slice(&mut self, s: MapSlice<'_, K, V>) -> Result<(), ArrowError> {
+        TypedBackend::<List<(K, V)>>::extend_from_slice(&mut self.entries, s)
+    }
+
+    fn check_push<'a>(&self, v: &(K::Slice<'a>, V::Slice<'a>)) -> Result<(), ArrowError> {
+        TypedBackend::<List<(K, V)>>::check_push(&self.entries, v)
+    }
+
+    fn check_extend_from_slice(&self, s: &MapSlice<'_, K, V>) -> Result<(), ArrowError> {
+        TypedBackend::<List<(K, V)>>::check_extend_from_slice(&self.entries, s)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> TypedBackend<Option<Map<K, V>>> for MapBuilder<K, V> {
+    type Config = MapConfig<K, V>;
+
+    fn data_type(config: &MapConfig<K, V>) -> DataType {
+        Self::map_data_type(config)
+    }
+
+    fn new(config: BuilderConfig<Option<Map<K, V>>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push<'a>(&mut self, v: Option<(K::Slice<'a>, V::Slice<'a>)>) -> Result<(), ArrowError> {
+        TypedBackend::<Option<List<(K, V)>>>::push(&mut self.entries, v)
+    }
+
+    fn extend_from_slice(&mut self, slice: OptionSlice<'_, Map<K, V>>) -> Result<(), ArrowError> {
+        TypedBackend::<Option<List<(K, V)>>>::extend_from_slice(
+            &mut self.entries,
+            OptionSlice {
+                values: slice.values,
+                is_valid: slice.is_valid,
+            },
+        )
+    }
+
+    fn check_push<'a>(&self, v: &Option<(K::Slice<'a>, V::Slice<'a>)>) -> Result<(), ArrowError> {
+        TypedBackend::<Option<List<(K, V)>>>::check_push(&self.entries, v)
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &OptionSlice<'_, Map<K, V>>,
+    ) -> Result<(), ArrowError> {
+        TypedBackend::<Option<List<(K, V)>>>::check_extend_from_slice(
+            &self.entries,
+            &OptionSlice {
+                values: slice.values.clone(),
+                is_valid: slice.is_valid,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::{
+            tests::{
+                check_extend_outcome, check_extend_with_nulls, check_init_default_optional,
+                check_init_with_capacity_optional, check_init_with_capacity_outcome, check_push,
+                check_validity,
+            },
+            BuilderConfig,
+        },
+        tests::length_or_capacity,
+        types::{
+            fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig},
+            map::{Map, MapConfig, MapSlice},
+        },
+        ArrayElement, OptionSlice, TypedBuilder,
+    };
+    use arrow_array::{Array, MapArray};
+    use arrow_schema::{DataType, Field, Fields};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+    use std::collections::BTreeMap;
+
+    /// Generate map offsets and flattened keys and values, which are valid
+    /// most of the time
+    fn offsets_and_entries() -> impl Strategy<Value = (Vec<i32>, Vec<u8>, Vec<i16>)> {
+        let valid = any::<Vec<Vec<(u8, i16)>>>().prop_map(|maps| {
+            let mut offsets = vec![0];
+            let (mut keys, mut values) = (Vec::new(), Vec::new());
+            for map in maps {
+                for (key, value) in map {
+                    keys.push(key);
+                    values.push(value);
+                }
+                offsets.push(keys.len() as i32);
+            }
+            (offsets, keys, values)
+        });
+        prop_oneof![
+            4 => valid,
+            1 => any::<(Vec<i32>, Vec<u8>, Vec<i16>)>(),
+        ]
+    }
+
+    /// Truth that map offsets are valid for some flattened keys and values
+    fn entries_are_valid(offsets: &[i32], keys: &[u8], values: &[i16]) -> bool {
+        keys.len() == values.len()
+            && offsets
+                .iter()
+                .all(|&offset| offset >= 0 && offset as usize <= keys.len())
+            && offsets.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default_optional::<Map<u8, i16>>()?;
+        check_init_default_optional::<Map<String, Option<Map<u8, bool>>>>()
+    }
+
+    #[test]
+    #[should_panic]
+    fn nullable_keys() {
+        TypedBuilder::<Map<Option<u8>, u8>>::new();
+    }
+
+    #[test]
+    fn data_type() {
+        let entries = |value_type, value_nullable| {
+            Field::new(
+                "entries",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("keys", DataType::UInt8, false),
+                    Field::new("values", value_type, value_nullable),
+                ])),
+                false,
+            )
+        };
+        assert_eq!(
+            Map::<u8, i16>::data_type(&MapConfig::default()),
+            DataType::Map(entries(DataType::Int16, false).into(), false)
+        );
+        assert_eq!(
+            Map::<u8, Option<i16>>::data_type(&MapConfig {
+                sorted: true,
+                ..Default::default()
+            }),
+            DataType::Map(entries(DataType::Int16, true).into(), true)
+        );
+    }
+
+    #[test]
+    fn reject_entry() -> TestCaseResult {
+        let config = MapConfig {
+            keys: (),
+            values: FixedSizeBinaryConfig::new(2).unwrap(),
+            sorted: false,
+        };
+        let mut builder = TypedBuilder::<Map<u8, FixedSizeBinary>>::with_config(
+            BuilderConfig::with_backend(None, config),
+        );
+        prop_assert!(builder
+            .push_entries([(1, &b"ab"[..]), (2, &b"c"[..])])
+            .is_err());
+        prop_assert!(builder.is_empty());
+        builder.push_entries([(3, &b"de"[..])])?;
+        let array = builder.finish().into_array_ref();
+        let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+        prop_assert_eq!(array.len(), 1);
+        prop_assert_eq!(array.entries().len(), 1);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<Map<u8, i16>>(MapConfig::default, capacity)?;
+        }
+
+        #[test]
+        fn push_value(
+            init_capacity in length_or_capacity(),
+            entries: Vec<(u8, i16)>,
+            sorted: bool,
+        ) {
+            let (keys, values): (Vec<u8>, Vec<i16>) = entries.iter().copied().unzip();
+            let config = MapConfig { sorted, ..Default::default() };
+            check_push::<Map<u8, i16>>(config.clone(), init_capacity, (&keys, &values))?;
+
+            let mut builder = TypedBuilder::<Map<u8, i16>>::with_config(
+                BuilderConfig::with_backend(Some(init_capacity), config.clone()),
+            );
+            builder.push((&keys, &values))?;
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), (&keys[..], &values[..]));
+            let array = array.into_array_ref();
+            prop_assert_eq!(array.data_type(), &Map::<u8, i16>::data_type(&config));
+        }
+
+        #[test]
+        fn push_option(init_capacity in length_or_capacity(), entries: Option<Vec<(u8, i16)>>) {
+            let (keys, values): (Vec<u8>, Vec<i16>) = entries.iter().flatten().copied().unzip();
+            let mut builder = TypedBuilder::<Option<Map<u8, i16>>>::with_capacity(init_capacity);
+            builder.push(entries.is_some().then_some((&keys[..], &values[..])))?;
+            check_extend_outcome(&builder, init_capacity, 1)?;
+            check_validity(&builder, &[entries.is_some()])?;
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), entries.is_some().then_some((&keys[..], &values[..])));
+        }
+
+        #[test]
+        fn push_entries(init_capacity in length_or_capacity(), maps: Vec<BTreeMap<u8, i16>>) {
+            let mut builder = TypedBuilder::<Map<u8, i16>>::with_capacity(init_capacity);
+            let mut opt_builder = TypedBuilder::<Option<Map<u8, i16>>>::with_capacity(init_capacity);
+            for map in &maps {
+                builder.push_entries(map.iter().map(|(&key, &value)| (key, value)))?;
+                opt_builder.push_entries(map.clone())?;
+            }
+            check_extend_outcome(&builder, init_capacity, maps.len())?;
+            check_extend_outcome(&opt_builder, init_capacity, maps.len())?;
+            check_validity(&opt_builder, &vec![true; maps.len()])?;
+            let array = builder.finish();
+            for (map, (keys, values)) in maps.iter().zip(array.iter()) {
+                prop_assert!(map.keys().eq(keys));
+                prop_assert!(map.values().eq(values));
+            }
+        }
+
+        #[test]
+        fn push_strings(entries: Vec<(String, Option<bool>)>) {
+            let mut builder = TypedBuilder::<Map<String, Option<bool>>>::new();
+            builder.push_entries(entries.iter().map(|(key, value)| (key.as_str(), *value)))?;
+            let array = builder.finish();
+            let (keys, values) = array.value(0);
+            prop_assert!(keys.iter().eq(entries.iter().map(|(key, _)| key.as_str())));
+            for (index, (_, value)) in entries.iter().enumerate() {
+                let valid = values.is_valid.is_none_or(|is_valid| is_valid.get(index) == Some(true));
+                prop_assert_eq!(valid, value.is_some());
+                if let Some(value) = value {
+                    prop_assert_eq!(values.values.get(index), Some(*value));
+                }
+            }
+        }
+
+        #[test]
+        fn extend_from_values(
+            init_capacity in length_or_capacity(),
+            (offsets, keys, values) in offsets_and_entries(),
+        ) {
+            let slice = MapSlice::<u8, i16> { offsets: &offsets, values: (&keys, &values) };
+            let valid = entries_are_valid(&offsets, &keys, &values);
+
+            let mut builder = TypedBuilder::<Map<u8, i16>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), valid);
+            if !valid {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            check_extend_outcome(&builder, init_capacity, slice.len())?;
+            let array = builder.finish();
+            for (range, map) in offsets.windows(2).zip(array.iter()) {
+                let range = range[0] as usize..range[1] as usize;
+                prop_assert_eq!(map, (&keys[range.clone()], &values[range]));
+            }
+
+            let mut builder = TypedBuilder::<Option<Map<u8, i16>>>::with_capacity(init_capacity);
+            builder.extend_from_value_slice(slice)?;
+            check_extend_outcome(&builder, init_capacity, slice.len())?;
+            check_validity(&builder, &vec![true; slice.len()])?;
+        }
+
+        #[test]
+        fn extend_from_options(
+            init_capacity in length_or_capacity(),
+            (offsets, keys, values) in offsets_and_entries(),
+            is_valid: Vec<bool>,
+        ) {
+            let slice = MapSlice::<u8, i16> { offsets: &offsets, values: (&keys, &values) };
+            let is_valid = (0..slice.len())
+                .map(|index| is_valid.get(index).copied().unwrap_or(true))
+                .collect::<Vec<_>>();
+            let valid = entries_are_valid(&offsets, &keys, &values);
+
+            let mut builder = TypedBuilder::<Option<Map<u8, i16>>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(OptionSlice { values: slice, is_valid: &is_valid });
+            prop_assert_eq!(result.is_ok(), valid);
+            if valid {
+                check_extend_outcome(&builder, init_capacity, slice.len())?;
+                check_validity(&builder, &is_valid)?;
+                let array = builder.finish();
+                prop_assert!(array.iter().map(|map| map.is_some()).eq(is_valid.iter().copied()));
+            } else {
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+            }
+        }
+
+        #[test]
+        fn extend_with_nulls(
+            init_capacity in length_or_capacity(),
+            num_nulls in length_or_capacity()
+        ) {
+            check_extend_with_nulls::<Map<u8, i16>>(MapConfig::default(), init_capacity, num_nulls)?;
+        }
+    }
+}
@@ -1,30 +1,40 @@
 //! Strong typing layer on top of Arrow builders
 
 mod bool;
+mod byte_view;
+mod bytes;
+mod decimal;
+mod dictionary;
+mod fixed_size_binary;
+mod fixed_size_list;
+pub(crate) mod list;
+mod map;
 mod null;
 mod primitive;
+mod run_end;
+mod structs;
+mod timestamp;
+mod union;
 
-// TODO: Still need to interface remaining API of PrimitiveBuilder and then...
-//
-// - FixedSizeBinaryBuilder
-// - FixedSizeListBuilder
-// - GenericByteBuilder
-// - GenericByteDictionaryBuilder
-// - GenericByteRunBuilder
-// - GenericByteViewBuilder
-// - GenericListBuilder
-// - MapBuilder
-// - PrimitiveDictionaryBuilder
-// - PrimitiveRunBuilder
-// - StructBuilder
-// - UnionBuilder
-//
-// I should probably start with PrimitiveBuilder, then ListBuilder, then
-// StructBuilder, then UnionBuilder, and finish with special cases.
+pub use self::{
+    byte_view::ByteViewBuilder,
+    bytes::ByteBuilder,
+    decimal::DecimalBuilder,
+    dictionary::{ArrowDictionaryBuilder, ByteDictionaryBuilder, DictionaryBuilder},
+    fixed_size_binary::FixedBinaryBuilder,
+    fixed_size_list::FixedListBuilder,
+    list::ListBuilder,
+    map::MapBuilder,
+    run_end::RunEndBuilder,
+    structs::{FieldBuilders, StructBuilder, TupleBuilders},
+    union::{UnionBuilder, UnionBuilders},
+};
 
 use super::BuilderConfig;
-use crate::ArrayElement;
-use arrow_array::builder::ArrayBuilder;
+use crate::{ArrayElement, ElementSlice};
+use arrow_array::{builder::ArrayBuilder, Array};
+use arrow_buffer::NullBufferBuilder;
+use arrow_schema::{ArrowError, DataType};
 use std::fmt::Debug;
 
 /// Arrow builder that can accept strongly typed entries of type `T`
@@ -32,6 +42,9 @@ pub trait TypedBackend<T: ArrayElement + ?Sized>: Backend {
     /// Configuration needed to construct a builder backend for this type
     type Config: Clone + Debug + Eq + PartialEq;
 
+    /// Arrow data type of the arrays produced by this builder
+    fn data_type(config: &Self::Config) -> DataType;
+
     /// Create a new builder backend
     fn new(config: BuilderConfig<T>) -> Self;
 
@@ -39,14 +52,36 @@ pub trait TypedBackend<T: ArrayElement + ?Sized>: Backend {
     ///
     /// Implementors should almost always make this operation `#[inline]` to
     /// allow for cross-crate inlining.
-    fn push(&mut self, v: T::Value<'_>);
+    fn push(&mut self, v: T::Value<'_>) -> T::PushResult;
 
     /// Append values into the builder in bulk
     fn extend_from_slice(&mut self, s: T::Slice<'_>) -> T::ExtendFromSliceResult;
+
+    /// Check that [`push()`](Self::push) would accept a value, without
+    /// modifying the builder
+    ///
+    /// Builders of tuples and structs use this to check every field before
+    /// appending anything. The default implementation is only suitable for
+    /// builders whose `push()` cannot fail.
+    fn check_push(&self, _v: &T::Value<'_>) -> Result<(), ArrowError> {
+        Ok(())
+    }
+
+    /// Check that [`extend_from_slice()`](Self::extend_from_slice) would
+    /// accept a slice, without modifying the builder
+    ///
+    /// The default implementation only checks that the slice is well-formed,
+    /// which is enough for builders that accept any well-formed slice.
+    fn check_extend_from_slice(&self, s: &T::Slice<'_>) -> Result<(), ArrowError> {
+        s.slice_len().map(|_| ())
+    }
 }
 
 /// Subset of `TypedBackend<T>` functionality that does not depend on `T`
 pub trait Backend: ArrayBuilder + Debug {
+    /// Concrete array type produced by this builder
+    type Array: Array + Clone + Debug;
+
     /// Number of elements the array can hold without reallocating
     ///
     /// In the case of types that are internally stored as multiple columnar
@@ -60,6 +95,38 @@ pub trait Backend: ArrayBuilder + Debug {
 
     /// Efficiently append `n` null values into the builder
     fn extend_with_nulls(&mut self, n: usize);
+
+    /// Remove all elements past the first `len` ones
+    ///
+    /// This is used to roll back insertions that failed partway. Builders
+    /// that cannot be truncated in place are rebuilt from their retained
+    /// elements, so this should only be done on error paths.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `len` is greater than the number of elements.
+    fn truncate(&mut self, len: usize);
+
+    /// Build the array and reset the builder
+    fn finish(&mut self) -> Self::Array;
+
+    /// Build the array without resetting the builder
+    fn finish_cloned(&self) -> Self::Array;
+}
+
+/// Remove all entries of a validity bitmap past the first `len` ones
+///
+/// arrow-rs' `NullBufferBuilder` cannot be truncated in place, so it is
+/// rebuilt from its retained entries.
+fn truncate_nulls(nulls: &mut NullBufferBuilder, len: usize) {
+    match nulls.finish() {
+        Some(buffer) => {
+            for valid in buffer.iter().take(len) {
+                nulls.append(valid);
+            }
+        }
+        None => nulls.append_n_non_nulls(len),
+    }
 }
 
 /// Access the current null buffer as a slice
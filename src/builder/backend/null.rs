@@ -2,9 +2,12 @@
 
 use super::{Backend, TypedBackend};
 use crate::{builder::BuilderConfig, types::primitive::Null};
-use arrow_array::builder::NullBuilder;
+use arrow_array::{builder::NullBuilder, NullArray};
+use arrow_schema::DataType;
 
 impl Backend for NullBuilder {
+    type Array = NullArray;
+
     fn capacity(&self) -> usize {
         usize::MAX
     }
@@ -12,11 +15,28 @@ impl Backend for NullBuilder {
     fn extend_with_nulls(&mut self, n: usize) {
         self.append_nulls(n)
     }
+
+    fn truncate(&mut self, len: usize) {
+        self.finish();
+        self.append_nulls(len);
+    }
+
+    fn finish(&mut self) -> NullArray {
+        self.finish()
+    }
+
+    fn finish_cloned(&self) -> NullArray {
+        self.finish_cloned()
+    }
 }
 
 impl TypedBackend<Null> for NullBuilder {
     type Config = ();
 
+    fn data_type(_config: &()) -> DataType {
+        DataType::Null
+    }
+
     fn new(_config: BuilderConfig<Null>) -> Self {
         // FIXME: We do not forward the capacity to NullBuilder as it does not
         //        handle it in a manner that is consistent with other builders,
@@ -6,11 +6,13 @@ use crate::{
     types::primitive::{NativeType, PrimitiveType},
     ArrayElement, OptionSlice,
 };
-use arrow_array::{builder::PrimitiveBuilder, types::ArrowPrimitiveType};
-use arrow_schema::ArrowError;
+use arrow_array::{builder::PrimitiveBuilder, types::ArrowPrimitiveType, PrimitiveArray};
+use arrow_schema::{ArrowError, DataType};
 use std::{fmt::Debug, panic::AssertUnwindSafe};
 
 impl<T: ArrowPrimitiveType + Debug> Backend for PrimitiveBuilder<T> {
+    type Array = PrimitiveArray<T>;
+
     fn capacity(&self) -> usize {
         self.capacity()
     }
@@ -18,6 +20,19 @@ impl<T: ArrowPrimitiveType + Debug> Backend for PrimitiveBuilder<T> {
     fn extend_with_nulls(&mut self, n: usize) {
         self.append_nulls(n)
     }
+
+    fn truncate(&mut self, len: usize) {
+        let array = self.finish().slice(0, len);
+        self.extend(array.iter());
+    }
+
+    fn finish(&mut self) -> PrimitiveArray<T> {
+        self.finish()
+    }
+
+    fn finish_cloned(&self) -> PrimitiveArray<T> {
+        self.finish_cloned()
+    }
 }
 
 impl<T: ArrowPrimitiveType + Debug> ValiditySlice for PrimitiveBuilder<T> {
@@ -36,12 +51,17 @@ where
 {
     type Config = ();
 
+    fn data_type(_config: &()) -> DataType {
+        T::arrow_data_type()
+    }
+
     fn new(config: BuilderConfig<T>) -> Self {
-        if let Some(capacity) = config.capacity {
+        let builder = if let Some(capacity) = config.capacity {
             Self::with_capacity(capacity)
         } else {
             Self::new()
-        }
+        };
+        builder.with_data_type(T::arrow_data_type())
     }
 
     #[inline]
@@ -71,18 +91,23 @@ where
     //
     // FIXME: Remove these bounds once it becomes possible to blanket-impl
     //        ArrayElement for Option<T: PrimitiveType>, making them obvious.
-    Option<T>: ArrayElement<ExtendFromSliceResult = Result<(), ArrowError>>,
+    Option<T>: ArrayElement<PushResult = (), ExtendFromSliceResult = Result<(), ArrowError>>,
     for<'a> <Option<T> as ArrayElement>::Value<'a>: Into<Option<T::Value<'a>>>,
     for<'a> <Option<T> as ArrayElement>::Slice<'a>: Into<OptionSlice<'a, T>>,
 {
     type Config = ();
 
+    fn data_type(_config: &()) -> DataType {
+        T::arrow_data_type()
+    }
+
     fn new(config: BuilderConfig<Option<T>>) -> Self {
-        if let Some(capacity) = config.capacity {
+        let builder = if let Some(capacity) = config.capacity {
             Self::with_capacity(capacity)
         } else {
             Self::new()
-        }
+        };
+        builder.with_data_type(T::arrow_data_type())
     }
 
     #[inline]
@@ -119,8 +144,9 @@ mod tests {
         },
         tests::length_or_capacity,
         types::primitive::{
-            Date32, Date64, Duration, IntervalDayTime, IntervalMonthDayNano, IntervalYearMonth,
-            Microsecond, Millisecond, Nanosecond, Second, Time,
+            Date32, Date64, Decimal128, Decimal256, Duration, IntervalDayTime,
+            IntervalMonthDayNano, IntervalYearMonth, Microsecond, Millisecond, Nanosecond, Second,
+            Time, Timestamp, Utc,
         },
         OptionSlice,
     };
@@ -188,6 +214,8 @@ mod tests {
     test_primitives!(
         date32: Date32,
         date64: Date64,
+        decimal128: Decimal128<20, 4>,
+        decimal256: Decimal256<50, -2>,
         duration_micros: Duration<Microsecond>,
         duration_millis: Duration<Millisecond>,
         duration_nanos: Duration<Nanosecond>,
@@ -201,6 +229,10 @@ mod tests {
         time_millis: Time<Millisecond>,
         time_nanos: Time<Nanosecond>,
         time_secs: Time<Second>,
+        timestamp_micros_utc: Timestamp<Microsecond, Utc>,
+        timestamp_millis_utc: Timestamp<Millisecond, Utc>,
+        timestamp_nanos_utc: Timestamp<Nanosecond, Utc>,
+        timestamp_secs_utc: Timestamp<Second, Utc>,
         u8, u16, u32, u64
     );
 
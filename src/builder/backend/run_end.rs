@@ -0,0 +1,493 @@
+//! Strong typing layer on top of [`RunArray`]
+//!
+//! arrow-rs' `PrimitiveRunBuilder` and `GenericByteRunBuilder` can only append
+//! values one by one, panic on run end overflow, and do not support other value
+//! types like booleans. We therefore build the run ends ourselves, along with
+//! the run values which are built using the builder backend of the value type.
+
+use super::{Backend, TypedBackend};
+use crate::{
+    builder::{BackendConfig, BuilderBackend, BuilderConfig},
+    types::{
+        primitive::NativeType,
+        run_end::{RunEnd, RunEndIndex, RunEndValue},
+    },
+    ElementSlice, OptionSlice,
+};
+use arrow_array::{
+    builder::ArrayBuilder, types::ArrowPrimitiveType, ArrayRef, PrimitiveArray, RunArray,
+};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType, Field};
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+/// Builder of [`RunArray`]s with run ends of type `R` and values of type `V`,
+/// which merges repeated values into runs as they are inserted
+///
+/// Capacity is requested in number of elements, and is used to preallocate
+/// space for as many runs since the number of runs is not known in advance.
+#[derive(Debug)]
+pub struct RunEndBuilder<R: RunEndIndex, V: RunEndValue + ?Sized> {
+    /// Logical end of each run
+    run_ends: Vec<NativeType<R>>,
+
+    /// Builder of the run values, which has one entry per run
+    values: BuilderBackend<V>,
+
+    /// Value of the last run, if any, with `None` standing for null runs
+    last_value: Option<Option<V::Owned>>,
+
+    /// Number of elements that was requested at construction time
+    capacity: usize,
+}
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> RunEndBuilder<R, V> {
+    /// Default number of elements that builders can hold without reallocating
+    ///
+    /// This is the same as what arrow-rs' run builders use.
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create a builder from the components of a typed builder configuration
+    fn from_config(capacity: Option<usize>, values: BackendConfig<V>) -> Self {
+        let capacity = capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        Self {
+            run_ends: Vec::with_capacity(capacity),
+            values: <BuilderBackend<V> as TypedBackend<V>>::new(BuilderConfig::with_backend(
+                Some(capacity),
+                values,
+            )),
+            last_value: None,
+            capacity,
+        }
+    }
+
+    /// Arrow data type of the arrays produced by this builder
+    fn run_end_data_type(values: &BackendConfig<V>) -> DataType {
+        DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", R::Arrow::DATA_TYPE, false)),
+            Arc::new(Field::new("values", V::data_type(values), true)),
+        )
+    }
+
+    /// Number of elements that were appended into this builder
+    fn num_elements(&self) -> usize {
+        self.run_ends.last().map_or(0, |run_end| run_end.as_usize())
+    }
+
+    /// Run end that `count` more elements would lead to, if it does not
+    /// overflow `R`
+    fn next_run_end(&self, count: usize) -> Result<NativeType<R>, ArrowError> {
+        self.num_elements()
+            .checked_add(count)
+            .and_then(NativeType::<R>::from_usize)
+            .ok_or(ArrowError::RunEndIndexOverflowError)
+    }
+
+    /// Append `count` repetitions of a value, which may be null
+    ///
+    /// If the value is identical to that of the last run, the last run is
+    /// extended, otherwise a new run is started. Nothing is appended if the
+    /// resulting array length would overflow `R`.
+    pub(crate) fn extend_run(
+        &mut self,
+        value: Option<V::Value<'_>>,
+        count: usize,
+    ) -> Result<(), ArrowError> {
+        if count == 0 {
+            return Ok(());
+        }
+        let run_end = self.next_run_end(count)?;
+        let same_value = match (&self.last_value, &value) {
+            (Some(Some(last)), Some(value)) => V::is_same(last, value),
+            (Some(None), None) => true,
+            _ => false,
+        };
+        if same_value {
+            *self
+                .run_ends
+                .last_mut()
+                .expect("there is a last run if there is a last value") = run_end;
+        } else {
+            self.run_ends.push(run_end);
+            if let Some(value) = value {
+                self.last_value = Some(Some(V::to_owned_value(&value)));
+                self.values.push(value);
+            } else {
+                self.last_value = Some(None);
+                self.values.extend_with_nulls(1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that a slice of values, with optional validity, can be appended
+    fn check_values(
+        &self,
+        values: &V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        let len = values.slice_len()?;
+        if is_valid.is_some_and(|is_valid| is_valid.len() != len) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Value and validity lengths must be equal".to_string(),
+            ));
+        }
+        self.next_run_end(len)?;
+        V::slice_values(values.clone()).map(|_| ())
+    }
+
+    /// Append a slice of values, with optional validity
+    ///
+    /// Nothing is appended if the input is invalid or if the resulting array
+    /// length would overflow `R`.
+    fn extend_from_values(
+        &mut self,
+        values: V::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError> {
+        self.check_values(&values, is_valid)?;
+        for (index, value) in V::slice_values(values)?.enumerate() {
+            let value = is_valid
+                .is_none_or(|is_valid| is_valid[index])
+                .then_some(value);
+            self.extend_run(value, 1)
+                .expect("run end overflow was checked above");
+        }
+        Ok(())
+    }
+
+    /// Build a run array from run ends and run values
+    fn make_array(
+        run_ends: Vec<NativeType<R>>,
+        values: <BuilderBackend<V> as Backend>::Array,
+    ) -> RunArray<R::Arrow> {
+        let run_ends = PrimitiveArray::<R::Arrow>::from_iter_values(run_ends);
+        RunArray::try_new(&run_ends, &values).expect("run ends should be valid by construction")
+    }
+}
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> ArrayBuilder for RunEndBuilder<R, V> {
+    fn len(&self) -> usize {
+        self.num_elements()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> Backend for RunEndBuilder<R, V> {
+    type Array = RunArray<R::Arrow>;
+
+    fn capacity(&self) -> usize {
+        self.capacity.max(self.num_elements())
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        self.extend_run(None, n)
+            .expect("number of nulls should not overflow the run end type")
+    }
+
+    fn truncate(&mut self, len: usize) {
+        // Keep the runs that start before the new end, shortening the last one
+        let num_runs = if len == 0 {
+            0
+        } else {
+            self.run_ends
+                .partition_point(|run_end| run_end.as_usize() < len)
+                + 1
+        };
+        if num_runs < self.run_ends.len() {
+            self.run_ends.truncate(num_runs);
+            self.values.truncate(num_runs);
+            // Subsequent values will start a new run, which is less compact
+            // but still valid
+            self.last_value = None;
+        }
+        if let Some(last) = self.run_ends.last_mut() {
+            *last = NativeType::<R>::usize_as(len);
+        }
+    }
+
+    fn finish(&mut self) -> RunArray<R::Arrow> {
+        self.capacity = 0;
+        self.last_value = None;
+        Self::make_array(
+            std::mem::take(&mut self.run_ends),
+            Backend::finish(&mut self.values),
+        )
+    }
+
+    fn finish_cloned(&self) -> RunArray<R::Arrow> {
+        Self::make_array(self.run_ends.clone(), Backend::finish_cloned(&self.values))
+    }
+}
+
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> TypedBackend<RunEnd<R, V>> for RunEndBuilder<R, V> {
+    type Config = BackendConfig<V>;
+
+    fn data_type(config: &Self::Config) -> DataType {
+        Self::run_end_data_type(config)
+    }
+
+    fn new(config: BuilderConfig<RunEnd<R, V>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: V::Value<'_>) -> Result<(), ArrowError> {
+        self.extend_run(Some(v), 1)
+    }
+
+    fn extend_from_slice(&mut self, s: V::Slice<'_>) -> Result<(), ArrowError> {
+        self.extend_from_values(s, None)
+    }
+
+    fn check_push(&self, _v: &V::Value<'_>) -> Result<(), ArrowError> {
+        self.next_run_end(1).map(|_| ())
+    }
+
+    fn check_extend_from_slice(&self, s: &V::Slice<'_>) -> Result<(), ArrowError> {
+        self.check_values(s, None)
+    }
+}
+
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> TypedBackend<Option<RunEnd<R, V>>>
+    for RunEndBuilder<R, V>
+{
+    type Config = BackendConfig<V>;
+
+    fn data_type(config: &Self::Config) -> DataType {
+        Self::run_end_data_type(config)
+    }
+
+    fn new(config: BuilderConfig<Option<RunEnd<R, V>>>) -> Self {
+        Self::from_config(config.capacity, config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<V::Value<'_>>) -> Result<(), ArrowError> {
+        self.extend_run(v, 1)
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: OptionSlice<'_, RunEnd<R, V>>,
+    ) -> Result<(), ArrowError> {
+        self.extend_from_values(slice.values, Some(slice.is_valid))
+    }
+
+    fn check_push(&self, _v: &Option<V::Value<'_>>) -> Result<(), ArrowError> {
+        self.next_run_end(1).map(|_| ())
+    }
+
+    fn check_extend_from_slice(
+        &self,
+        slice: &OptionSlice<'_, RunEnd<R, V>>,
+    ) -> Result<(), ArrowError> {
+        self.check_values(&slice.values, Some(slice.is_valid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::backend::Backend,
+        builder::tests::{
+            check_encoding, check_init_default_optional, check_init_with_capacity_optional,
+            check_string_encoding, repeated_values,
+        },
+        tests::length_or_capacity,
+        types::{bytes::StringSlice, run_end::RunEnd},
+        ArrayElement, TypedBuilder,
+    };
+    use arrow_array::{
+        cast::AsArray,
+        types::{Int16Type, Int32Type, RunEndIndexType},
+        Array, RunArray,
+    };
+    use arrow_buffer::ArrowNativeType;
+    use arrow_schema::{ArrowError, DataType, Field};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+    use std::{fmt::Debug, sync::Arc};
+
+    /// Split a sequence of values into runs of identical values
+    fn runs<T: Copy + PartialEq>(values: &[T]) -> Vec<(T, usize)> {
+        let mut runs = Vec::<(T, usize)>::new();
+        for &value in values {
+            match runs.last_mut() {
+                Some((last, len)) if *last == value => *len += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+        runs
+    }
+
+    /// Check that a finished run array holds the expected runs
+    fn check_runs<R: RunEndIndexType, T: Copy + PartialEq + Debug>(
+        array: &dyn Array,
+        expected: &[(Option<T>, usize)],
+        decode: impl Fn(&dyn Array, usize) -> T,
+    ) -> TestCaseResult {
+        let array = array
+            .as_any()
+            .downcast_ref::<RunArray<R>>()
+            .expect("should be a run array");
+        let run_ends = array.run_ends().values();
+        prop_assert_eq!(run_ends.len(), expected.len());
+        prop_assert!(array.values().len() >= expected.len());
+        let mut run_start = 0;
+        for (run, (&run_end, &(value, len))) in run_ends.iter().zip(expected).enumerate() {
+            prop_assert_eq!(run_end.as_usize() - run_start, len);
+            run_start = run_end.as_usize();
+            prop_assert_eq!(array.values().is_valid(run), value.is_some());
+            if let Some(value) = value {
+                prop_assert_eq!(decode(array.values().as_ref(), run), value);
+            }
+        }
+        prop_assert_eq!(array.len(), run_start);
+        Ok(())
+    }
+
+    /// Decode a run value of type `i16`
+    fn decode_i16(values: &dyn Array, run: usize) -> i16 {
+        values.as_primitive::<Int16Type>().value(run)
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default_optional::<RunEnd<i16, u8>>()?;
+        check_init_default_optional::<RunEnd<i64, String>>()
+    }
+
+    #[test]
+    fn data_type() {
+        let field = |name, data_type, nullable| Arc::new(Field::new(name, data_type, nullable));
+        assert_eq!(
+            RunEnd::<i32, f64>::data_type(&()),
+            DataType::RunEndEncoded(
+                field("run_ends", DataType::Int32, false),
+                field("values", DataType::Float64, true)
+            )
+        );
+        assert_eq!(
+            Option::<RunEnd<i16, [u8]>>::data_type(&()),
+            DataType::RunEndEncoded(
+                field("run_ends", DataType::Int16, false),
+                field("values", DataType::Binary, true)
+            )
+        );
+    }
+
+    #[test]
+    fn run_end_overflow() {
+        let mut builder = TypedBuilder::<RunEnd<i16, u8>>::new();
+        builder.extend_run(1, i16::MAX as usize - 2).unwrap();
+        assert!(matches!(
+            builder.extend_run(2, 3),
+            Err(ArrowError::RunEndIndexOverflowError)
+        ));
+        assert!(builder.extend_from_slice(&[1, 1, 2]).is_err());
+        builder.extend_from_slice(&[1, 2]).unwrap();
+        assert!(builder.push(2).is_err());
+        let array = builder.finish();
+        assert_eq!(array.len(), i16::MAX as usize);
+        assert!(array
+            .as_slice()
+            .runs()
+            .eq([(1, i16::MAX as usize - 1), (2, 1)]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn null_overflow() {
+        let mut builder = TypedBuilder::<Option<RunEnd<i16, u8>>>::new();
+        builder.extend_with_nulls(i16::MAX as usize + 1);
+    }
+
+    #[test]
+    fn null_run_overflow() {
+        let mut builder = TypedBuilder::<Option<RunEnd<i16, u8>>>::new();
+        assert!(matches!(
+            builder.extend_run(None, i16::MAX as usize + 1),
+            Err(ArrowError::RunEndIndexOverflowError)
+        ));
+        assert!(builder.is_empty());
+        builder.extend_run(None, i16::MAX as usize).unwrap();
+        assert_eq!(builder.len(), i16::MAX as usize);
+    }
+
+    #[test]
+    fn bitwise_identity() {
+        let mut builder = TypedBuilder::<RunEnd<i32, f32>>::new();
+        builder
+            .extend_from_slice(&[0.0, 0.0, -0.0, f32::NAN, f32::NAN])
+            .unwrap();
+        let array = Backend::finish(&mut builder.0);
+        assert_eq!(array.run_ends().values(), [2, 3, 5]);
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<RunEnd<i16, i16>>(|| (), capacity)?;
+            check_init_with_capacity_optional::<RunEnd<i64, str>>(|| (), capacity)?;
+        }
+
+        #[test]
+        fn encoding(init_capacity in length_or_capacity(), values in repeated_values()) {
+            check_encoding::<RunEnd<i16, i16>>(init_capacity, &values, |array, expected| {
+                check_runs::<Int16Type, _>(array, &runs(expected), decode_i16)
+            })?;
+            check_string_encoding::<RunEnd<i32, String>>(&values, |array, expected| {
+                check_runs::<Int32Type, _>(array, &runs(expected), |strings, run| {
+                    strings.as_string::<i32>().value(run).parse().unwrap()
+                })
+            })?;
+        }
+
+        #[test]
+        fn extend_run(runs in any::<Vec<(Option<bool>, u8)>>()) {
+            let mut builder = TypedBuilder::<Option<RunEnd<i32, bool>>>::new();
+            let mut expected = Vec::new();
+            for &(value, count) in &runs {
+                builder.extend_run(value, usize::from(count))?;
+                expected.extend(std::iter::repeat_n(value, usize::from(count)));
+            }
+            let array = builder.finish().into_array_ref();
+            check_runs::<Int32Type, _>(array.as_ref(), &self::runs(&expected), |values, run| {
+                values.as_boolean().value(run)
+            })?;
+        }
+
+        #[test]
+        fn extend_from_strings(offsets: Vec<i32>, values: String) {
+            let slice = StringSlice { offsets: &offsets, values: &values };
+            let mut builder = TypedBuilder::<RunEnd<i32, String>>::new();
+            let result = builder.extend_from_slice(slice);
+            prop_assert_eq!(result.is_ok(), slice.validate().is_ok());
+            if result.is_ok() {
+                let array = builder.finish();
+                prop_assert!(array.iter().eq(slice.iter()));
+            } else {
+                prop_assert!(builder.is_empty());
+            }
+        }
+    }
+}
@@ -0,0 +1,857 @@
+//! Strong typing layer on top of [`StructArray`]
+//!
+//! arrow-rs' `StructBuilder` stores its field builders as type-erased trait
+//! objects, which must be downcast to their concrete type on every insertion.
+//! Therefore, we use our own builder, which keeps track of struct validity on
+//! top of a tuple of strongly typed field builders.
+//!
+//! This builder is used for both tuples and [`StructElement`]s, the latter
+//! being built as the tuple of their fields with different field names.
+
+use super::{Backend, TypedBackend, ValiditySlice};
+use crate::{
+    builder::{BackendConfig, BuilderConfig},
+    types::{
+        structs::StructElement,
+        tuple::{for_each_tuple, TupleElement},
+    },
+    ArrayElement, ElementSlice, InsertionResult, OptionSlice,
+};
+use arrow_array::{builder::ArrayBuilder, ArrayRef, StructArray};
+use arrow_buffer::NullBufferBuilder;
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use std::{any::Any, collections::HashMap, fmt::Debug, sync::Arc};
+
+/// Builder of [`StructArray`]s
+///
+/// `Builders` is a tuple of the builder backends of the struct fields.
+#[derive(Debug)]
+pub struct StructBuilder<Builders: FieldBuilders> {
+    /// Builders of the struct fields
+    builders: Builders,
+
+    /// Validity bitmap
+    nulls: NullBufferBuilder,
+
+    /// Fields of the struct
+    fields: Fields,
+}
+//
+impl<Builders: FieldBuilders> StructBuilder<Builders> {
+    /// Default number of structs that builders can hold without reallocating
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create a builder whose fields are the elements of tuple type `T`
+    fn from_tuple_config<T: ArrayElement>(
+        capacity: Option<usize>,
+        config: Builders::Config,
+        fields: Fields,
+    ) -> Self
+    where
+        Builders: TupleBuilders<T>,
+    {
+        Self {
+            builders: Builders::new(capacity, config),
+            nulls: NullBufferBuilder::new(capacity.unwrap_or(Self::DEFAULT_CAPACITY)),
+            fields,
+        }
+    }
+
+    /// Check that a valid struct, whose fields are the elements of tuple `v`,
+    /// can be appended
+    fn check_fields<T: ArrayElement>(&self, v: &T::Value<'_>) -> Result<(), ArrowError>
+    where
+        Builders: TupleBuilders<T>,
+    {
+        self.builders.check_push(v)
+    }
+
+    /// Append a valid struct, whose fields are the elements of tuple `v`
+    ///
+    /// The builder is left untouched if a tuple element is rejected.
+    fn append_fields<T: ArrayElement>(&mut self, v: T::Value<'_>) -> Result<(), ArrowError>
+    where
+        Builders: TupleBuilders<T>,
+    {
+        self.builders.push(v)?;
+        self.nulls.append_non_null();
+        Ok(())
+    }
+
+    /// Check that a slice of structs, whose fields are the elements of tuple
+    /// slice `s`, can be appended with optional validity
+    fn check_field_slices<T: ArrayElement>(
+        &self,
+        s: &T::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError>
+    where
+        Builders: TupleBuilders<T>,
+    {
+        let len = s.slice_len()?;
+        if is_valid.is_some_and(|is_valid| is_valid.len() != len) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Value and validity lengths must be equal".to_string(),
+            ));
+        }
+        self.builders.check_extend_from_slice(s)
+    }
+
+    /// Append a slice of structs, whose fields are the elements of tuple slice
+    /// `s`, with optional validity
+    ///
+    /// The builder is left untouched if the field slices do not have the same
+    /// length, if `is_valid` does not have the same length as the field
+    /// slices, or if a field slice is rejected.
+    fn extend_from_fields<T: ArrayElement>(
+        &mut self,
+        s: T::Slice<'_>,
+        is_valid: Option<&[bool]>,
+    ) -> Result<(), ArrowError>
+    where
+        Builders: TupleBuilders<T>,
+    {
+        self.check_field_slices::<T>(&s, is_valid)?;
+        let len = s.slice_len()?;
+        self.builders.extend_from_slice(s)?;
+        match is_valid {
+            Some(is_valid) => self.nulls.append_slice(is_valid),
+            None => self.nulls.append_n_non_nulls(len),
+        }
+        Ok(())
+    }
+}
+//
+impl<Builders: FieldBuilders> ArrayBuilder for StructBuilder<Builders> {
+    fn len(&self) -> usize {
+        self.nulls.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<Builders: FieldBuilders> Backend for StructBuilder<Builders> {
+    type Array = StructArray;
+
+    fn capacity(&self) -> usize {
+        self.builders.capacity()
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        self.builders.extend_with_nulls(n);
+        self.nulls.append_n_nulls(n);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.builders.truncate(len);
+        super::truncate_nulls(&mut self.nulls, len);
+    }
+
+    fn finish(&mut self) -> StructArray {
+        let columns = self.builders.finish();
+        StructArray::new(self.fields.clone(), columns, self.nulls.finish())
+    }
+
+    fn finish_cloned(&self) -> StructArray {
+        let columns = self.builders.finish_cloned();
+        StructArray::new(self.fields.clone(), columns, self.nulls.finish_cloned())
+    }
+}
+
+impl<Builders: FieldBuilders> ValiditySlice for StructBuilder<Builders> {
+    fn validity_slice(&self) -> Option<&[u8]> {
+        self.nulls.as_slice()
+    }
+}
+
+/// Tuple of builder backends, which build the fields of a [`StructArray`]
+pub trait FieldBuilders: Debug + Send + Sync + 'static {
+    /// Lower bound on the capacity of all field builders
+    fn capacity(&self) -> usize;
+
+    /// Append `n` nulls to every field
+    ///
+    /// These nulls are masked by the nulls of the parent struct, so they are
+    /// also appended to fields whose element type is not nullable.
+    fn extend_with_nulls(&mut self, n: usize);
+
+    /// Number of elements in the `index`-th field
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn field_len(&self, index: usize) -> usize;
+
+    /// Append `n` nulls to the `index`-th field
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn extend_field_with_nulls(&mut self, index: usize, n: usize);
+
+    /// Remove all elements past the first `len` ones from every field
+    fn truncate(&mut self, len: usize);
+
+    /// Remove all elements past the first `len` ones from the `index`-th field
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn truncate_field(&mut self, index: usize, len: usize);
+
+    /// Build the fields and reset the field builders
+    fn finish(&mut self) -> Vec<ArrayRef>;
+
+    /// Build the fields without resetting the field builders
+    fn finish_cloned(&self) -> Vec<ArrayRef>;
+}
+
+/// [`FieldBuilders`] which accept strongly typed tuples of type `T`
+pub trait TupleBuilders<T: ArrayElement>: FieldBuilders {
+    /// Tuple of the backend configurations of the tuple elements
+    type Config: Clone + Debug + Eq + PartialEq;
+
+    /// Arrow fields of the struct, named after the position of each element
+    fn fields(config: &Self::Config) -> Fields;
+
+    /// Create the field builders
+    fn new(capacity: Option<usize>, config: Self::Config) -> Self;
+
+    /// Append the elements of a tuple to the matching field builders
+    ///
+    /// Every tuple element is checked before anything is appended, and the
+    /// field builders are rolled back if a tuple element is still rejected,
+    /// so they are left untouched on error.
+    fn push(&mut self, v: T::Value<'_>) -> Result<(), ArrowError>;
+
+    /// Append the elements of a tuple of slices to the matching field builders
+    ///
+    /// The slices must have been checked to be of equal length. As with
+    /// [`push()`](Self::push), the field builders are left untouched if a
+    /// slice is rejected.
+    fn extend_from_slice(&mut self, s: T::Slice<'_>) -> Result<(), ArrowError>;
+
+    /// Check that the elements of a tuple can be appended to the matching
+    /// field builders, without modifying them
+    fn check_push(&self, v: &T::Value<'_>) -> Result<(), ArrowError>;
+
+    /// Check that the elements of a tuple of slices can be appended to the
+    /// matching field builders, without modifying them
+    fn check_extend_from_slice(&self, s: &T::Slice<'_>) -> Result<(), ArrowError>;
+}
+
+// Enable strongly typed builders of tuples
+macro_rules! impl_tuple_backend {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t: Backend),+> FieldBuilders for ($($t,)+) {
+            fn capacity(&self) -> usize {
+                [$(self.$idx.capacity()),+]
+                    .into_iter()
+                    .min()
+                    .expect("tuples have at least one field")
+            }
+
+            fn extend_with_nulls(&mut self, n: usize) {
+                $(self.$idx.extend_with_nulls(n);)+
+            }
+
+            fn field_len(&self, index: usize) -> usize {
+                match index {
+                    $($idx => self.$idx.len(),)+
+                    _ => panic!("field index {index} is out of bounds"),
+                }
+            }
+
+            fn extend_field_with_nulls(&mut self, index: usize, n: usize) {
+                match index {
+                    $($idx => self.$idx.extend_with_nulls(n),)+
+                    _ => panic!("field index {index} is out of bounds"),
+                }
+            }
+
+            fn truncate(&mut self, len: usize) {
+                $(self.$idx.truncate(len);)+
+            }
+
+            fn truncate_field(&mut self, index: usize, len: usize) {
+                match index {
+                    $($idx => self.$idx.truncate(len),)+
+                    _ => panic!("field index {index} is out of bounds"),
+                }
+            }
+
+            fn finish(&mut self) -> Vec<ArrayRef> {
+                vec![$(Arc::new(Backend::finish(&mut self.$idx)) as ArrayRef),+]
+            }
+
+            fn finish_cloned(&self) -> Vec<ArrayRef> {
+                vec![$(Arc::new(Backend::finish_cloned(&self.$idx)) as ArrayRef),+]
+            }
+        }
+
+        impl<$($t: ArrayElement),+> TupleBuilders<($($t,)+)> for ($($t::BuilderBackend,)+) {
+            type Config = ($(BackendConfig<$t>,)+);
+
+            fn fields(config: &($(BackendConfig<$t>,)+)) -> Fields {
+                Fields::from(vec![$($t::field_with_config(stringify!($idx), &config.$idx)),+])
+            }
+
+            fn new(capacity: Option<usize>, config: ($(BackendConfig<$t>,)+)) -> Self {
+                ($(
+                    <$t::BuilderBackend as TypedBackend<$t>>::new(
+                        BuilderConfig::with_backend(capacity, config.$idx),
+                    ),
+                )+)
+            }
+
+            #[inline]
+            fn push(&mut self, v: <($($t,)+) as ArrayElement>::Value<'_>) -> Result<(), ArrowError> {
+                <Self as TupleBuilders<($($t,)+)>>::check_push(self, &v)?;
+                let lens = [$(self.$idx.len()),+];
+                let result = (|| {
+                    $(
+                        <$t::BuilderBackend as TypedBackend<$t>>::push(&mut self.$idx, v.$idx)
+                            .into_result()?;
+                    )+
+                    Ok(())
+                })();
+                if result.is_err() {
+                    // Some nested checks cannot anticipate every error, roll
+                    // back the tuple elements that were already appended
+                    $(self.$idx.truncate(lens[$idx]);)+
+                }
+                result
+            }
+
+            fn extend_from_slice(
+                &mut self,
+                s: <($($t,)+) as ArrayElement>::Slice<'_>,
+            ) -> Result<(), ArrowError> {
+                <Self as TupleBuilders<($($t,)+)>>::check_extend_from_slice(self, &s)?;
+                let lens = [$(self.$idx.len()),+];
+                let result = (|| {
+                    $(
+                        <$t::BuilderBackend as TypedBackend<$t>>::extend_from_slice(&mut self.$idx, s.$idx)
+                            .into_result()?;
+                    )+
+                    Ok(())
+                })();
+                if result.is_err() {
+                    // Same as above
+                    $(self.$idx.truncate(lens[$idx]);)+
+                }
+                result
+            }
+
+            fn check_push(&self, v: &<($($t,)+) as ArrayElement>::Value<'_>) -> Result<(), ArrowError> {
+                $(<$t::BuilderBackend as TypedBackend<$t>>::check_push(&self.$idx, &v.$idx)?;)+
+                Ok(())
+            }
+
+            fn check_extend_from_slice(
+                &self,
+                s: &<($($t,)+) as ArrayElement>::Slice<'_>,
+            ) -> Result<(), ArrowError> {
+                $(
+                    <$t::BuilderBackend as TypedBackend<$t>>::check_extend_from_slice(&self.$idx, &s.$idx)?;
+                )+
+                Ok(())
+            }
+        }
+
+        impl<$($t: ArrayElement),+> TypedBackend<($($t,)+)> for StructBuilder<($($t::BuilderBackend,)+)> {
+            type Config = ($(BackendConfig<$t>,)+);
+
+            fn data_type(config: &Self::Config) -> DataType {
+                DataType::Struct(<($($t::BuilderBackend,)+) as TupleBuilders<($($t,)+)>>::fields(config))
+            }
+
+            fn new(config: BuilderConfig<($($t,)+)>) -> Self {
+                let fields = <($($t::BuilderBackend,)+) as TupleBuilders<($($t,)+)>>::fields(&config.backend);
+                Self::from_tuple_config::<($($t,)+)>(config.capacity, config.backend, fields)
+            }
+
+            #[inline]
+            fn push(&mut self, v: <($($t,)+) as ArrayElement>::Value<'_>) -> Result<(), ArrowError> {
+                self.append_fields::<($($t,)+)>(v)
+            }
+
+            fn extend_from_slice(
+                &mut self,
+                s: <($($t,)+) as ArrayElement>::Slice<'_>,
+            ) -> Result<(), ArrowError> {
+                self.extend_from_fields::<($($t,)+)>(s, None)
+            }
+
+            fn check_push(&self, v: &<($($t,)+) as ArrayElement>::Value<'_>) -> Result<(), ArrowError> {
+                self.check_fields::<($($t,)+)>(v)
+            }
+
+            fn check_extend_from_slice(
+                &self,
+                s: &<($($t,)+) as ArrayElement>::Slice<'_>,
+            ) -> Result<(), ArrowError> {
+                self.check_field_slices::<($($t,)+)>(s, None)
+            }
+        }
+
+        impl<$($t: ArrayElement),+> TypedBackend<Option<($($t,)+)>> for StructBuilder<($($t::BuilderBackend,)+)> {
+            type Config = ($(BackendConfig<$t>,)+);
+
+            fn data_type(config: &Self::Config) -> DataType {
+                <Self as TypedBackend<($($t,)+)>>::data_type(config)
+            }
+
+            fn new(config: BuilderConfig<Option<($($t,)+)>>) -> Self {
+                <Self as TypedBackend<($($t,)+)>>::new(BuilderConfig::with_backend(
+                    config.capacity,
+                    config.backend,
+                ))
+            }
+
+            #[inline]
+            fn push(&mut self, v: <Option<($($t,)+)> as ArrayElement>::Value<'_>) -> Result<(), ArrowError> {
+                match v {
+                    Some(v) => self.append_fields::<($($t,)+)>(v),
+                    None => {
+                        self.extend_with_nulls(1);
+                        Ok(())
+                    }
+                }
+            }
+
+            fn extend_from_slice(
+                &mut self,
+                slice: OptionSlice<'_, ($($t,)+)>,
+            ) -> Result<(), ArrowError> {
+                self.extend_from_fields::<($($t,)+)>(slice.values, Some(slice.is_valid))
+            }
+
+            fn check_push(
+                &self,
+                v: &<Option<($($t,)+)> as ArrayElement>::Value<'_>,
+            ) -> Result<(), ArrowError> {
+                v.as_ref()
+                    .map_or(Ok(()), |v| self.check_fields::<($($t,)+)>(v))
+            }
+
+            fn check_extend_from_slice(
+                &self,
+                slice: &OptionSlice<'_, ($($t,)+)>,
+            ) -> Result<(), ArrowError> {
+                self.check_field_slices::<($($t,)+)>(&slice.values, Some(slice.is_valid))
+            }
+        }
+    };
+}
+//
+for_each_tuple!(impl_tuple_backend);
+
+/// Backend configuration of a [`StructElement`], which is that of its fields
+type StructConfig<T> =
+    <<<T as StructElement>::Fields as TupleElement>::Builders as TupleBuilders<
+        <T as StructElement>::Fields,
+    >>::Config;
+
+/// Arrow fields of a [`StructElement`], given its backend configuration
+fn struct_fields<T: StructElement>(config: &StructConfig<T>) -> Fields {
+    <<T::Fields as TupleElement>::Builders as TupleBuilders<T::Fields>>::fields(config)
+        .iter()
+        .zip(T::FIELD_NAMES)
+        .zip(T::FIELD_METADATA)
+        .map(|((field, name), metadata)| {
+            let metadata = metadata
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>();
+            Field::clone(field).with_name(*name).with_metadata(metadata)
+        })
+        .collect()
+}
+
+impl<T: StructElement> TypedBackend<T> for StructBuilder<<T::Fields as TupleElement>::Builders> {
+    type Config = StructConfig<T>;
+
+    fn data_type(config: &Self::Config) -> DataType {
+        DataType::Struct(struct_fields::<T>(config))
+    }
+
+    fn new(config: BuilderConfig<T>) -> Self {
+        let fields = struct_fields::<T>(&config.backend);
+        Self::from_tuple_config::<T::Fields>(config.capacity, config.backend, fields)
+    }
+
+    #[inline]
+    fn push(&mut self, v: T) -> Result<(), ArrowError> {
+        self.append_fields::<T::Fields>(v.as_fields())
+    }
+
+    fn extend_from_slice(&mut self, s: T::Slice<'_>) -> Result<(), ArrowError> {
+        self.extend_from_fields::<T::Fields>(T::slice_fields(s), None)
+    }
+
+    fn check_push(&self, v: &T) -> Result<(), ArrowError> {
+        self.check_fields::<T::Fields>(&v.as_fields())
+    }
+
+    fn check_extend_from_slice(&self, s: &T::Slice<'_>) -> Result<(), ArrowError> {
+        self.check_field_slices::<T::Fields>(&T::slice_fields(s.clone()), None)
+    }
+}
+
+impl<T: StructElement> TypedBackend<Option<T>>
+    for StructBuilder<<T::Fields as TupleElement>::Builders>
+{
+    type Config = StructConfig<T>;
+
+    fn data_type(config: &Self::Config) -> DataType {
+        <Self as TypedBackend<T>>::data_type(config)
+    }
+
+    fn new(config: BuilderConfig<Option<T>>) -> Self {
+        <Self as TypedBackend<T>>::new(BuilderConfig::with_backend(config.capacity, config.backend))
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<T>) -> Result<(), ArrowError> {
+        match v {
+            Some(v) => self.append_fields::<T::Fields>(v.as_fields()),
+            None => {
+                self.extend_with_nulls(1);
+                Ok(())
+            }
+        }
+    }
+
+    fn extend_from_slice(&mut self, slice: OptionSlice<'_, T>) -> Result<(), ArrowError> {
+        self.extend_from_fields::<T::Fields>(T::slice_fields(slice.values), Some(slice.is_valid))
+    }
+
+    fn check_push(&self, v: &Option<T>) -> Result<(), ArrowError> {
+        v.as_ref()
+            .map_or(Ok(()), |v| self.check_fields::<T::Fields>(&v.as_fields()))
+    }
+
+    fn check_extend_from_slice(&self, slice: &OptionSlice<'_, T>) -> Result<(), ArrowError> {
+        self.check_field_slices::<T::Fields>(
+            &T::slice_fields(slice.values.clone()),
+            Some(slice.is_valid),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::{
+            tests::{
+                check_extend_outcome, check_extend_with_nulls, check_init_default,
+                check_init_default_optional, check_init_with_capacity_optional,
+                check_init_with_capacity_outcome, check_push, check_push_option, check_truncate,
+                check_validity,
+            },
+            BuilderConfig,
+        },
+        tests::length_or_capacity,
+        types::{
+            bytes::StringSlice,
+            fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig, FixedSizeBinarySlice},
+        },
+        ArrayElement, OptionSlice, TypedBuilder,
+    };
+    use arrow_schema::{DataType, Field};
+    use proptest::{prelude::*, sample::SizeRange, test_runner::TestCaseResult};
+    #[cfg(feature = "derive")]
+    use std::collections::HashMap;
+
+    /// Tuple type used in most tests
+    type Element = (u16, bool);
+
+    /// Struct type with the same fields as `Element`
+    #[cfg(feature = "derive")]
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    struct Record {
+        int: u16,
+        #[array_element(rename = "flag", metadata("origin" = "test", "kind" = "bool"))]
+        bool: bool,
+    }
+    //
+    #[cfg(feature = "derive")]
+    impl From<Element> for Record {
+        fn from((int, bool): Element) -> Self {
+            Self { int, bool }
+        }
+    }
+
+    /// Generate the columns of a tuple slice, which usually have the same
+    /// length
+    fn columns() -> impl Strategy<Value = (Vec<u16>, Vec<bool>)> {
+        prop_oneof![
+            4 => (0..=SizeRange::default().end_incl()).prop_flat_map(|len| {
+                (
+                    prop::collection::vec(any::<u16>(), len),
+                    prop::collection::vec(any::<bool>(), len),
+                )
+            }),
+            1 => any::<(Vec<u16>, Vec<bool>)>(),
+        ]
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default_optional::<Element>()?;
+        check_init_default_optional::<(String,)>()?;
+        check_init_default_optional::<(Option<u8>, (bool, f32))>()?;
+        check_init_default::<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>()
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn init_default_struct() -> TestCaseResult {
+        check_init_default_optional::<Record>()
+    }
+
+    #[test]
+    fn data_type() {
+        assert_eq!(
+            <(u8, Option<bool>)>::data_type(&((), ())),
+            DataType::Struct(
+                vec![
+                    Field::new("0", DataType::UInt8, false),
+                    Field::new("1", DataType::Boolean, true),
+                ]
+                .into()
+            )
+        );
+        assert_eq!(
+            Option::<(u8, Option<bool>)>::field("pair"),
+            Field::new("pair", <(u8, Option<bool>)>::data_type(&((), ())), true)
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn struct_data_type() {
+        assert_eq!(
+            Record::data_type(&((), ())),
+            DataType::Struct(
+                vec![
+                    Field::new("int", DataType::UInt16, false),
+                    Field::new("flag", DataType::Boolean, false).with_metadata(HashMap::from([
+                        ("origin".to_owned(), "test".to_owned()),
+                        ("kind".to_owned(), "bool".to_owned()),
+                    ])),
+                ]
+                .into()
+            )
+        );
+        assert_eq!(
+            Option::<Record>::field("record"),
+            Field::new("record", Record::data_type(&((), ())), true)
+        );
+    }
+
+    #[test]
+    fn reject_field() -> TestCaseResult {
+        let config = ((), FixedSizeBinaryConfig::new(3).unwrap());
+        let mut builder = TypedBuilder::<(u32, FixedSizeBinary)>::with_config(
+            BuilderConfig::with_backend(None, config),
+        );
+        prop_assert!(builder.push((1, b"ab")).is_err());
+        let values = FixedSizeBinarySlice::new(2, b"cdef").unwrap();
+        prop_assert!(builder.extend_from_slice((&[2, 3], values)).is_err());
+        prop_assert!(builder.is_empty());
+        builder.push((4, b"ghi"))?;
+        let array = builder.finish();
+        prop_assert_eq!(array.len(), 1);
+        prop_assert_eq!(array.value(0), (4, &b"ghi"[..]));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn init_with_capacity(capacity in length_or_capacity()) {
+            check_init_with_capacity_optional::<Element>(|| ((), ()), capacity)?;
+        }
+
+        #[test]
+        fn push_value(init_capacity in length_or_capacity(), value: (u8, String)) {
+            check_push::<(u8, String)>(((), ()), init_capacity, (value.0, &value.1))?;
+            let mut builder = TypedBuilder::<(u8, String)>::with_capacity(init_capacity);
+            builder.push((value.0, &value.1))?;
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), (value.0, value.1.as_str()));
+        }
+
+        #[test]
+        fn push_option(init_capacity in length_or_capacity(), value: Option<Element>) {
+            check_push_option::<Element>(((), ()), init_capacity, value)?;
+            let mut builder = TypedBuilder::<Option<Element>>::with_capacity(init_capacity);
+            builder.push(value)?;
+            builder.push(None)?;
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), value);
+            prop_assert_eq!(array.value(1), None);
+        }
+
+        #[cfg(feature = "derive")]
+        #[test]
+        fn push_struct(init_capacity in length_or_capacity(), value: Option<Element>) {
+            let value = value.map(Record::from);
+            check_push_option::<Record>(((), ()), init_capacity, value)?;
+            let mut builder = TypedBuilder::<Option<Record>>::with_capacity(init_capacity);
+            builder.push(value)?;
+            builder.push(None)?;
+            let array = builder.finish();
+            prop_assert_eq!(array.value(0), value);
+            prop_assert_eq!(array.value(1), None);
+        }
+
+        #[test]
+        fn extend_from_values(
+            init_capacity in length_or_capacity(),
+            (ints, bools) in columns(),
+        ) {
+            let mut builder = TypedBuilder::<Element>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice((&ints, &bools));
+            if ints.len() != bools.len() {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            prop_assert!(result.is_ok());
+            check_extend_outcome(&builder, init_capacity, ints.len())?;
+            let array = builder.finish();
+            prop_assert!(array.iter().eq(ints.iter().copied().zip(bools.iter().copied())));
+
+            let mut builder = TypedBuilder::<Option<Element>>::with_capacity(init_capacity);
+            builder.extend_from_value_slice((&ints, &bools))?;
+            check_extend_outcome(&builder, init_capacity, ints.len())?;
+            check_validity(&builder, &vec![true; ints.len()])?;
+        }
+
+        #[cfg(feature = "derive")]
+        #[test]
+        fn extend_from_structs(
+            init_capacity in length_or_capacity(),
+            ((ints, bools), is_valid) in columns().prop_flat_map(|columns| {
+                let len = columns.0.len();
+                (Just(columns), prop::collection::vec(any::<bool>(), len))
+            }),
+        ) {
+            let slice = RecordSlice { int: &ints, bool: &bools };
+            let mut builder = TypedBuilder::<Record>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(slice.clone());
+            if ints.len() != bools.len() {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            prop_assert!(result.is_ok());
+            check_extend_outcome(&builder, init_capacity, ints.len())?;
+            let array = builder.finish();
+            let expected = ints.iter().copied().zip(bools.iter().copied()).map(Record::from);
+            prop_assert!(array.iter().eq(expected.clone()));
+
+            let mut builder = TypedBuilder::<Option<Record>>::with_capacity(init_capacity);
+            builder.extend_from_slice(OptionSlice { values: slice, is_valid: &is_valid })?;
+            check_extend_outcome(&builder, init_capacity, ints.len())?;
+            check_validity(&builder, &is_valid)?;
+            let array = builder.finish();
+            prop_assert!(array
+                .iter()
+                .eq(expected.zip(&is_valid).map(|(record, &valid)| valid.then_some(record))));
+        }
+
+        #[test]
+        fn extend_from_options(
+            init_capacity in length_or_capacity(),
+            ((ints, bools), is_valid) in columns().prop_flat_map(|columns| {
+                let len = columns.0.len();
+                let is_valid = prop_oneof![
+                    4 => prop::collection::vec(any::<bool>(), len),
+                    1 => any::<Vec<bool>>(),
+                ];
+                (Just(columns), is_valid)
+            }),
+        ) {
+            let mut builder = TypedBuilder::<Option<Element>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(OptionSlice {
+                values: (&ints, &bools),
+                is_valid: &is_valid,
+            });
+            if ints.len() != bools.len() || ints.len() != is_valid.len() {
+                prop_assert!(result.is_err());
+                check_init_with_capacity_outcome(&builder, init_capacity)?;
+                return Ok(());
+            }
+            prop_assert!(result.is_ok());
+            check_extend_outcome(&builder, init_capacity, ints.len())?;
+            check_validity(&builder, &is_valid)?;
+            let array = builder.finish();
+            let expected = ints
+                .iter()
+                .zip(&bools)
+                .zip(&is_valid)
+                .map(|((&int, &bool), &valid)| valid.then_some((int, bool)));
+            prop_assert!(array.iter().eq(expected));
+        }
+
+        #[test]
+        fn extend_from_strings(strings: Vec<String>) {
+            let values = strings.concat();
+            let mut offsets = vec![0];
+            offsets.extend(strings.iter().scan(0, |end, string| {
+                *end += string.len() as i32;
+                Some(*end)
+            }));
+            let lens = strings.iter().map(|s| s.len() as u64).collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<(String, u64)>::new();
+            builder.extend_from_slice((StringSlice { offsets: &offsets, values: &values }, &lens[..]))?;
+            let array = builder.finish();
+            prop_assert!(array.iter().eq(strings.iter().map(|s| (s.as_str(), s.len() as u64))));
+        }
+
+        #[test]
+        fn truncate(values: Vec<Option<(u8, Option<String>)>>, len: prop::sample::Index) {
+            let len = len.index(values.len() + 1);
+            let mut builder = TypedBuilder::<Option<(u8, Option<String>)>>::new();
+            let mut expected = TypedBuilder::<Option<(u8, Option<String>)>>::new();
+            for (index, value) in values.iter().enumerate() {
+                let value = value.as_ref().map(|(int, string)| (*int, string.as_deref()));
+                builder.push(value)?;
+                if index < len {
+                    expected.push(value)?;
+                }
+            }
+            check_truncate(builder, &expected)?;
+        }
+
+        #[test]
+        fn extend_with_nulls(
+            init_capacity in length_or_capacity(),
+            num_nulls in length_or_capacity()
+        ) {
+            check_extend_with_nulls::<Element>(((), ()), init_capacity, num_nulls)?;
+            check_extend_with_nulls::<(Option<u8>, (bool, String))>(((), ((), ())), init_capacity, num_nulls)?;
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Strong typing layer on top of [`PrimitiveBuilder`] for timestamp types
+//!
+//! Timestamps with a runtime timezone are built by regular arrow-rs primitive
+//! builders, whose data type is adjusted at construction time to carry the
+//! configured timezone. Timestamps with a compile-time timezone are primitive
+//! types, and are handled by the primitive builder backend instead.
+
+use super::TypedBackend;
+use crate::{
+    builder::BuilderConfig,
+    types::primitive::{TimeUnit, Timestamp, TimestampConfig},
+    OptionSlice,
+};
+use arrow_array::builder::PrimitiveBuilder;
+use arrow_schema::{ArrowError, DataType};
+
+/// Create a timestamp builder from the components of a typed builder
+/// configuration
+fn from_config<Unit: TimeUnit>(
+    capacity: Option<usize>,
+    config: &TimestampConfig,
+) -> PrimitiveBuilder<Unit::ArrowTimestamp> {
+    let builder = if let Some(capacity) = capacity {
+        PrimitiveBuilder::with_capacity(capacity)
+    } else {
+        PrimitiveBuilder::new()
+    };
+    builder.with_timezone_opt(config.timezone())
+}
+
+/// Reinterpret a slice of timestamps as a slice of their native values
+fn as_native<Unit: TimeUnit>(timestamps: &[Timestamp<Unit>]) -> &[i64] {
+    // SAFETY: Timestamp is a repr(transparent) wrapper over i64
+    unsafe { std::slice::from_raw_parts(timestamps.as_ptr().cast::<i64>(), timestamps.len()) }
+}
+
+impl<Unit: TimeUnit> TypedBackend<Timestamp<Unit>> for PrimitiveBuilder<Unit::ArrowTimestamp> {
+    type Config = TimestampConfig;
+
+    fn data_type(config: &TimestampConfig) -> DataType {
+        config.data_type::<Unit>()
+    }
+
+    fn new(config: BuilderConfig<Timestamp<Unit>>) -> Self {
+        from_config::<Unit>(config.capacity, &config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Timestamp<Unit>) {
+        self.append_value(v.into())
+    }
+
+    fn extend_from_slice(&mut self, s: &[Timestamp<Unit>]) {
+        self.append_slice(as_native(s))
+    }
+}
+
+impl<Unit: TimeUnit> TypedBackend<Option<Timestamp<Unit>>>
+    for PrimitiveBuilder<Unit::ArrowTimestamp>
+{
+    type Config = TimestampConfig;
+
+    fn data_type(config: &TimestampConfig) -> DataType {
+        config.data_type::<Unit>()
+    }
+
+    fn new(config: BuilderConfig<Option<Timestamp<Unit>>>) -> Self {
+        from_config::<Unit>(config.capacity, &config.backend)
+    }
+
+    #[inline]
+    fn push(&mut self, v: Option<Timestamp<Unit>>) {
+        self.append_option(v.map(i64::from))
+    }
+
+    fn extend_from_slice(
+        &mut self,
+        slice: OptionSlice<'_, Timestamp<Unit>>,
+    ) -> Result<(), ArrowError> {
+        if slice.values.len() != slice.is_valid.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Value and validity lengths must be equal".to_string(),
+            ));
+        }
+        self.append_values(as_native(slice.values), slice.is_valid);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builder::tests::{
+            check_extend_from_options, check_extend_from_values, check_extend_with_nulls,
+            check_init_default_optional, check_init_with_capacity_optional, check_push,
+            check_push_option, option_vec,
+        },
+        tests::length_or_capacity,
+        types::primitive::{Microsecond, Millisecond, Nanosecond, Second},
+        TypedBuilder,
+    };
+    use arrow_array::Array;
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    /// Generate a timestamp configuration, with or without a timezone
+    fn timestamp_config() -> impl Strategy<Value = TimestampConfig> {
+        prop_oneof![
+            Just(TimestampConfig::default()),
+            prop::sample::select(vec!["UTC", "Europe/Paris", "+07:30"])
+                .prop_map(TimestampConfig::with_timezone),
+        ]
+    }
+
+    macro_rules! test_timestamps {
+        ($($mod_name:ident : $unit:ty),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    type Element = Timestamp<$unit>;
+
+                    #[test]
+                    fn init_default() -> TestCaseResult {
+                        check_init_default_optional::<Element>()
+                    }
+
+                    proptest! {
+                        #[test]
+                        fn init_with_capacity(
+                            config in timestamp_config(),
+                            capacity in length_or_capacity(),
+                        ) {
+                            check_init_with_capacity_optional::<Element>(|| config.clone(), capacity)?;
+                        }
+
+                        #[test]
+                        fn push_value(
+                            config in timestamp_config(),
+                            init_capacity in length_or_capacity(),
+                            value: Element,
+                        ) {
+                            check_push::<Element>(config, init_capacity, value)?;
+                        }
+
+                        #[test]
+                        fn push_option(
+                            config in timestamp_config(),
+                            init_capacity in length_or_capacity(),
+                            value: Option<Element>,
+                        ) {
+                            check_push_option::<Element>(config, init_capacity, value)?;
+                        }
+
+                        #[test]
+                        fn extend_from_values(
+                            config in timestamp_config(),
+                            init_capacity in length_or_capacity(),
+                            values: Vec<Element>,
+                        ) {
+                            check_extend_from_values::<Element>(|| config.clone(), init_capacity, &values)?;
+                        }
+
+                        #[test]
+                        fn extend_from_options(
+                            config in timestamp_config(),
+                            init_capacity in length_or_capacity(),
+                            (values, is_valid) in option_vec::<Element>(),
+                        ) {
+                            check_extend_from_options::<Element>(config, init_capacity, OptionSlice {
+                                values: &values,
+                                is_valid: &is_valid,
+                            })?;
+                        }
+
+                        #[test]
+                        fn extend_with_nulls(
+                            config in timestamp_config(),
+                            init_capacity in length_or_capacity(),
+                            num_nulls in length_or_capacity(),
+                        ) {
+                            check_extend_with_nulls::<Element>(config, init_capacity, num_nulls)?;
+                        }
+                    }
+                }
+            )*
+        };
+    }
+    test_timestamps!(
+        micros: Microsecond,
+        millis: Millisecond,
+        nanos: Nanosecond,
+        secs: Second
+    );
+
+    #[test]
+    fn timezone() {
+        let config = TimestampConfig::with_timezone("Asia/Tokyo");
+        assert_eq!(config.timezone(), Some("Asia/Tokyo"));
+        assert_eq!(TimestampConfig::default().timezone(), None);
+
+        let mut builder = TypedBuilder::<Option<Timestamp<Second>>>::with_config(
+            BuilderConfig::with_backend(None, config),
+        );
+        builder.push(Some(Timestamp::from(42)));
+        builder.push(None);
+        let array = builder.finish().into_array_ref();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Timestamp(arrow_schema::TimeUnit::Second, Some("Asia/Tokyo".into()))
+        );
+        assert_eq!(array.null_count(), 1);
+    }
+}
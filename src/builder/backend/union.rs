@@ -0,0 +1,585 @@
+//! Strong typing layer on top of [`UnionArray`]
+//!
+//! arrow-rs' `UnionBuilder` only supports primitive children, so we use our own
+//! builder, which keeps track of type ids and offsets on top of a tuple of
+//! strongly typed child builders, like the builder of struct arrays.
+
+use super::{Backend, FieldBuilders, TupleBuilders, TypedBackend};
+use crate::{
+    array::{backend::TypedBackend as ArrayTypedBackend, ArrayBackend},
+    builder::BuilderConfig,
+    types::{
+        tuple::{for_each_tuple, TupleElement},
+        union::{UnionElement, UnionVariants},
+    },
+    ArrayElement, InsertionResult,
+};
+use arrow_array::{builder::ArrayBuilder, ArrayRef, UnionArray};
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, UnionFields, UnionMode};
+use std::{any::Any, collections::HashMap, sync::Arc};
+
+/// Builder of [`UnionArray`]s
+///
+/// `Builders` is a tuple of the builder backends of the union variants.
+#[derive(Debug)]
+pub struct UnionBuilder<Builders: FieldBuilders> {
+    /// Builders of the variants
+    builders: Builders,
+
+    /// Type id of each element
+    type_ids: Vec<i8>,
+
+    /// Offset of each element in its child array, if the union is dense
+    offsets: Option<Vec<i32>>,
+
+    /// Fields of the union
+    fields: UnionFields,
+
+    /// Variant that null entries are stored into
+    null_variant: usize,
+}
+//
+impl<Builders: FieldBuilders> UnionBuilder<Builders> {
+    /// Default number of elements that builders can hold without reallocating
+    const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Offset that the next value of the `variant`-th variant will have in its
+    /// child array, if the union is dense
+    ///
+    /// Fails if this offset does not fit in the offsets of dense unions.
+    fn next_offset(&self, variant: usize) -> Result<Option<i32>, ArrowError> {
+        if self.offsets.is_none() {
+            return Ok(None);
+        }
+        let offset = self.builders.field_len(variant);
+        i32::try_from(offset).map(Some).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "the child arrays of dense unions cannot hold more than {} elements",
+                i32::MAX
+            ))
+        })
+    }
+
+    /// Record that a value was just appended to the child of the `variant`-th
+    /// variant, at the `offset` given by [`next_offset()`](Self::next_offset)
+    fn append_variant(&mut self, variant: usize, offset: Option<i32>) {
+        let (type_id, _) = self
+            .fields
+            .iter()
+            .nth(variant)
+            .expect("variant index should be in bounds");
+        if let Some(offsets) = &mut self.offsets {
+            offsets.push(offset.expect("dense unions should have an offset"));
+        } else {
+            for other in (0..self.fields.len()).filter(|&other| other != variant) {
+                self.builders.extend_field_with_nulls(other, 1);
+            }
+        }
+        self.type_ids.push(type_id);
+    }
+
+    /// Build the union array from child arrays and the type ids and offsets
+    /// buffers
+    fn build(
+        &self,
+        type_ids: Vec<i8>,
+        offsets: Option<Vec<i32>>,
+        children: Vec<ArrayRef>,
+    ) -> UnionArray {
+        let (type_id_list, fields): (Vec<i8>, Vec<FieldRef>) = self
+            .fields
+            .iter()
+            .map(|(type_id, field)| (type_id, field.clone()))
+            .unzip();
+        let children = fields
+            .into_iter()
+            .map(|field| Field::clone(&field))
+            .zip(children)
+            .collect();
+        // SAFETY: Type ids are only ever pushed from the union's fields, dense
+        //         offsets point to the child array element that was appended
+        //         along with them, and in sparse unions every child array
+        //         receives one element per union element.
+        unsafe {
+            UnionArray::new_unchecked(
+                &type_id_list,
+                Buffer::from_vec(type_ids),
+                offsets.map(Buffer::from_vec),
+                children,
+            )
+        }
+    }
+}
+//
+impl<Builders: FieldBuilders> ArrayBuilder for UnionBuilder<Builders> {
+    fn len(&self) -> usize {
+        self.type_ids.len()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(Backend::finish(self))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(Backend::finish_cloned(self))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl<Builders: FieldBuilders> Backend for UnionBuilder<Builders> {
+    type Array = UnionArray;
+
+    fn capacity(&self) -> usize {
+        match &self.offsets {
+            // Each child of a dense union only holds the values of its variant
+            Some(offsets) => self.type_ids.capacity().min(offsets.capacity()),
+            None => self.builders.capacity().min(self.type_ids.capacity()),
+        }
+    }
+
+    fn extend_with_nulls(&mut self, n: usize) {
+        let (type_id, _) = self
+            .fields
+            .iter()
+            .nth(self.null_variant)
+            .expect("null variant index should be in bounds");
+        if let Some(offsets) = &mut self.offsets {
+            let start = self.builders.field_len(self.null_variant);
+            offsets.extend((start..start + n).map(|offset| {
+                i32::try_from(offset).expect(
+                    "the child arrays of dense unions cannot hold more than i32::MAX elements",
+                )
+            }));
+            self.builders.extend_field_with_nulls(self.null_variant, n);
+        } else {
+            self.builders.extend_with_nulls(n);
+        }
+        self.type_ids.extend(std::iter::repeat_n(type_id, n));
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if let Some(offsets) = &mut self.offsets {
+            // Each child of a dense union keeps the values of the retained
+            // elements of its variant
+            offsets.truncate(len);
+            for (variant, (type_id, _)) in self.fields.iter().enumerate() {
+                let count = self.type_ids[..len]
+                    .iter()
+                    .filter(|&&id| id == type_id)
+                    .count();
+                self.builders.truncate_field(variant, count);
+            }
+        } else {
+            self.builders.truncate(len);
+        }
+        self.type_ids.truncate(len);
+    }
+
+    fn finish(&mut self) -> UnionArray {
+        let children = self.builders.finish();
+        let type_ids = std::mem::take(&mut self.type_ids);
+        let offsets = self.offsets.as_mut().map(std::mem::take);
+        self.build(type_ids, offsets, children)
+    }
+
+    fn finish_cloned(&self) -> UnionArray {
+        let children = self.builders.finish_cloned();
+        self.build(self.type_ids.clone(), self.offsets.clone(), children)
+    }
+}
+
+/// [`TupleBuilders`] which can build the children of a union whose variants
+/// are the elements of tuple type `T`
+pub trait UnionBuilders<T: UnionVariants>: TupleBuilders<T> {
+    /// Index of the variant whose value is set
+    fn variant(&self, v: &T::Values<'_>) -> Result<usize, ArrowError>;
+
+    /// Append the value of a single variant to the matching child builder,
+    /// and return the index of this variant
+    fn push_variant(&mut self, v: T::Values<'_>) -> Result<usize, ArrowError>;
+
+    /// Check that [`push_variant()`](Self::push_variant) would accept the
+    /// value of a variant, and return the index of this variant
+    fn check_variant(&self, v: &T::Values<'_>) -> Result<usize, ArrowError>;
+}
+
+// Enable strongly typed builders of union children
+macro_rules! impl_union_backend {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t: ArrayElement),+> UnionBuilders<($($t,)+)> for ($($t::BuilderBackend,)+)
+        where
+            $(ArrayBackend<$t>: ArrayTypedBackend<$t>,)+
+        {
+            #[inline]
+            fn variant(
+                &self,
+                v: &<($($t,)+) as UnionVariants>::Values<'_>,
+            ) -> Result<usize, ArrowError> {
+                $(
+                    if v.$idx.is_some() {
+                        return Ok($idx);
+                    }
+                )+
+                Err(ArrowError::InvalidArgumentError(
+                    "union values should have exactly one variant".to_string(),
+                ))
+            }
+
+            #[inline]
+            fn push_variant(
+                &mut self,
+                v: <($($t,)+) as UnionVariants>::Values<'_>,
+            ) -> Result<usize, ArrowError> {
+                $(
+                    if let Some(v) = v.$idx {
+                        <$t::BuilderBackend as TypedBackend<$t>>::push(&mut self.$idx, v)
+                            .into_result()?;
+                        return Ok($idx);
+                    }
+                )+
+                Err(ArrowError::InvalidArgumentError(
+                    "union values should have exactly one variant".to_string(),
+                ))
+            }
+
+            fn check_variant(
+                &self,
+                v: &<($($t,)+) as UnionVariants>::Values<'_>,
+            ) -> Result<usize, ArrowError> {
+                $(
+                    if let Some(v) = &v.$idx {
+                        <$t::BuilderBackend as TypedBackend<$t>>::check_push(&self.$idx, v)?;
+                        return Ok($idx);
+                    }
+                )+
+                Err(ArrowError::InvalidArgumentError(
+                    "union values should have exactly one variant".to_string(),
+                ))
+            }
+        }
+    };
+}
+//
+for_each_tuple!(impl_union_backend);
+
+/// Backend configuration of a [`UnionElement`], which is that of its variants
+type UnionConfig<T> =
+    <<<T as UnionElement>::Variants as TupleElement>::Builders as TupleBuilders<
+        <T as UnionElement>::Variants,
+    >>::Config;
+
+/// Variant that the null entries of a nullable container of
+/// [`UnionElement`]s are stored into
+///
+/// This is the first nullable variant, or the first variant if there is none.
+fn null_variant<T: UnionElement>() -> usize {
+    <T::Variants as UnionVariants>::NULLABLE
+        .iter()
+        .position(|&nullable| nullable)
+        .unwrap_or(0)
+}
+
+/// Arrow fields of a [`UnionElement`], given its backend configuration
+fn union_fields<T: UnionElement>(config: &UnionConfig<T>) -> UnionFields {
+    let fields =
+        <<T::Variants as TupleElement>::Builders as TupleBuilders<T::Variants>>::fields(config);
+    let null_variant = null_variant::<T>();
+    let fields = fields
+        .iter()
+        .zip(T::VARIANT_NAMES)
+        .zip(T::VARIANT_METADATA)
+        .enumerate()
+        .map(|(variant, ((field, name), metadata))| {
+            let metadata = metadata
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>();
+            // The null variant receives the nulls of nullable containers, so
+            // its field must be nullable even if its type is not
+            let nullable = field.is_nullable() || variant == null_variant;
+            Field::clone(field)
+                .with_name(*name)
+                .with_metadata(metadata)
+                .with_nullable(nullable)
+        });
+    UnionFields::new(T::TYPE_IDS.iter().copied(), fields)
+}
+
+impl<T: UnionElement> TypedBackend<T> for UnionBuilder<<T::Variants as TupleElement>::Builders> {
+    type Config = UnionConfig<T>;
+
+    fn data_type(config: &Self::Config) -> DataType {
+        DataType::Union(union_fields::<T>(config), T::MODE)
+    }
+
+    fn new(config: BuilderConfig<T>) -> Self {
+        let capacity = config.capacity.unwrap_or(Self::DEFAULT_CAPACITY);
+        let fields = union_fields::<T>(&config.backend);
+        let builders =
+            <T::Variants as TupleElement>::Builders::new(config.capacity, config.backend);
+        Self {
+            builders,
+            type_ids: Vec::with_capacity(capacity),
+            offsets: (T::MODE == UnionMode::Dense).then(|| Vec::with_capacity(capacity)),
+            fields,
+            null_variant: null_variant::<T>(),
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, v: T::Value<'_>) -> Result<(), ArrowError> {
+        let values = T::into_variant(T::from_value(v));
+        let offset = self.next_offset(self.builders.variant(&values)?)?;
+        let variant = self.builders.push_variant(values)?;
+        self.append_variant(variant, offset);
+        Ok(())
+    }
+
+    fn extend_from_slice(&mut self, s: T::Slice<'_>) -> Result<(), ArrowError> {
+        let len = ArrayBuilder::len(self);
+        let result = T::slice_values(s).iter().try_for_each(|value| {
+            let values = value.clone().into_variant();
+            let offset = self.next_offset(self.builders.variant(&values)?)?;
+            let variant = self.builders.push_variant(values)?;
+            self.append_variant(variant, offset);
+            Ok(())
+        });
+        if result.is_err() {
+            // Roll back the values that were already appended
+            Backend::truncate(self, len);
+        }
+        result
+    }
+
+    fn check_push(&self, v: &T::Value<'_>) -> Result<(), ArrowError> {
+        let values = T::from_value_ref(v).clone().into_variant();
+        self.next_offset(self.builders.check_variant(&values)?)
+            .map(|_| ())
+    }
+
+    fn check_extend_from_slice(&self, s: &T::Slice<'_>) -> Result<(), ArrowError> {
+        // Values are checked independently of each other, so errors that
+        // depend on the preceding values of the slice are only detected by
+        // extend_from_slice(), which rolls them back.
+        T::slice_values(s.clone()).iter().try_for_each(|value| {
+            let values = value.clone().into_variant();
+            self.next_offset(self.builders.check_variant(&values)?)
+                .map(|_| ())
+        })
+    }
+}
+
+// All tests use derived enums, which need the derive feature
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{
+        builder::tests::{check_extend_outcome, check_init_default, check_push, check_truncate},
+        tests::length_or_capacity,
+        types::primitive::Null,
+        ArrayElement, TypedBuilder,
+    };
+    use arrow_array::{Array, StructArray, UnionArray};
+    use arrow_schema::{DataType, Field, UnionFields, UnionMode};
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+
+    /// Enum type used in most tests, stored as a dense union
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    enum Dense {
+        Empty,
+        Int(u16),
+        Pair(bool, i32),
+    }
+    //
+    impl Arbitrary for Dense {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+        fn arbitrary_with((): ()) -> Self::Strategy {
+            prop_oneof![
+                Just(Self::Empty),
+                any::<u16>().prop_map(Self::Int),
+                any::<(bool, i32)>().prop_map(|(b, i)| Self::Pair(b, i)),
+            ]
+            .boxed()
+        }
+    }
+
+    /// Same as `Dense`, but stored as a sparse union with custom type ids
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    #[array_element(sparse)]
+    enum Sparse {
+        #[array_element(type_id = 7, rename = "int")]
+        Int(u16),
+        #[array_element(type_id = 3, metadata("kind" = "pair"))]
+        Pair(bool, i32),
+        Empty,
+    }
+    //
+    impl From<Dense> for Sparse {
+        fn from(value: Dense) -> Self {
+            match value {
+                Dense::Empty => Self::Empty,
+                Dense::Int(i) => Self::Int(i),
+                Dense::Pair(b, i) => Self::Pair(b, i),
+            }
+        }
+    }
+
+    /// Type id of each element of a union array
+    fn type_ids(array: &dyn Array) -> Vec<i8> {
+        let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+        array.type_ids().to_vec()
+    }
+
+    #[test]
+    fn init_default() -> TestCaseResult {
+        check_init_default::<Dense>()?;
+        check_init_default::<Sparse>()
+    }
+
+    #[test]
+    fn data_type() {
+        assert_eq!(
+            Dense::data_type(&((), (), ((), ()))),
+            DataType::Union(
+                UnionFields::new(
+                    [0, 1, 2],
+                    [
+                        Field::new("Empty", DataType::Null, true),
+                        Field::new("Int", DataType::UInt16, false),
+                        Field::new("Pair", <(bool, i32)>::data_type(&((), ())), false),
+                    ]
+                ),
+                UnionMode::Dense
+            )
+        );
+        assert_eq!(
+            Sparse::data_type(&((), ((), ()), ())),
+            DataType::Union(
+                UnionFields::new(
+                    [7, 3, 2],
+                    [
+                        Field::new("int", DataType::UInt16, false),
+                        Field::new("Pair", <(bool, i32)>::data_type(&((), ())), false)
+                            .with_metadata([("kind".to_owned(), "pair".to_owned())].into()),
+                        Field::new("Empty", Null::data_type(&()), true),
+                    ]
+                ),
+                UnionMode::Sparse
+            )
+        );
+    }
+
+    /// Enum without nullable variants
+    #[derive(ArrayElement, Clone, Copy, Debug, PartialEq)]
+    enum Number {
+        Int(u16),
+        Real(f32),
+    }
+
+    #[test]
+    fn container_nulls() -> TestCaseResult {
+        let mut builder = TypedBuilder::<Option<(Number,)>>::new();
+        builder.push(Some((Number::Real(1.5),)))?;
+        builder.push(None)?;
+        builder.push(Some((Number::Int(42),)))?;
+        let array = builder.finish().into_array_ref();
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        let union = array
+            .column(0)
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .unwrap();
+        let DataType::Union(fields, _mode) = union.data_type() else {
+            unreachable!()
+        };
+        // Container nulls go to the first variant, which must be nullable
+        prop_assert_eq!(type_ids(union), vec![1, 0, 0]);
+        prop_assert_eq!(union.child(0).null_count(), 1);
+        for (type_id, field) in fields.iter() {
+            prop_assert!(field.is_nullable() || union.child(type_id).null_count() == 0);
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn push(init_capacity in length_or_capacity(), value: Dense) {
+            check_push::<Dense>(((), (), ((), ())), init_capacity, value)?;
+            check_push::<Sparse>(((), ((), ()), ()), init_capacity, value.into())?;
+        }
+
+        #[test]
+        fn extend_from_slice(init_capacity in length_or_capacity(), values: Vec<Dense>) {
+            let mut builder = TypedBuilder::<Dense>::with_capacity(init_capacity);
+            builder.extend_from_slice(&values)?;
+            check_extend_outcome(&builder, init_capacity, values.len())?;
+            let array = builder.finish().into_array_ref();
+            let expected_ids = values.iter().map(|value| match value {
+                Dense::Empty => 0,
+                Dense::Int(_) => 1,
+                Dense::Pair(..) => 2,
+            }).collect::<Vec<_>>();
+            prop_assert_eq!(type_ids(&array), expected_ids);
+
+            let values = values.into_iter().map(Sparse::from).collect::<Vec<_>>();
+            let mut builder = TypedBuilder::<Sparse>::with_capacity(init_capacity);
+            builder.extend_from_slice(&values)?;
+            check_extend_outcome(&builder, init_capacity, values.len())?;
+            let array = builder.finish().into_array_ref();
+            let expected_ids = values.iter().map(|value| match value {
+                Sparse::Int(_) => 7,
+                Sparse::Pair(..) => 3,
+                Sparse::Empty => 2,
+            }).collect::<Vec<_>>();
+            prop_assert_eq!(type_ids(&array), expected_ids);
+        }
+
+        #[test]
+        fn extend_with_nulls(values: Vec<Option<Dense>>) {
+            let mut builder = TypedBuilder::<Option<(Dense,)>>::new();
+            let mut sparse_builder = TypedBuilder::<Option<(Sparse,)>>::new();
+            for &value in &values {
+                builder.push(value.map(|value| (value,)))?;
+                sparse_builder.push(value.map(|value| (Sparse::from(value),)))?;
+            }
+            let array = builder.finish();
+            prop_assert_eq!(array.len(), values.len());
+            prop_assert!(array.iter().eq(values.iter().map(|value| value.map(|value| (value,)))));
+            let sparse_array = sparse_builder.finish();
+            prop_assert!(sparse_array
+                .iter()
+                .eq(values.iter().map(|value| value.map(|value| (Sparse::from(value),)))));
+        }
+
+        #[test]
+        fn truncate(values: Vec<Option<Dense>>, len: prop::sample::Index) {
+            let len = len.index(values.len() + 1);
+            let mut builder = TypedBuilder::<Option<(Dense,)>>::new();
+            let mut expected = TypedBuilder::<Option<(Dense,)>>::new();
+            let mut sparse_builder = TypedBuilder::<Option<(Sparse,)>>::new();
+            let mut sparse_expected = TypedBuilder::<Option<(Sparse,)>>::new();
+            for (index, &value) in values.iter().enumerate() {
+                builder.push(value.map(|value| (value,)))?;
+                sparse_builder.push(value.map(|value| (Sparse::from(value),)))?;
+                if index < len {
+                    expected.push(value.map(|value| (value,)))?;
+                    sparse_expected.push(value.map(|value| (Sparse::from(value),)))?;
+                }
+            }
+            check_truncate(builder, &expected)?;
+            check_truncate(sparse_builder, &sparse_expected)?;
+        }
+    }
+}
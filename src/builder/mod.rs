@@ -2,11 +2,20 @@
 
 pub(crate) mod backend;
 
-use self::backend::{Backend, TypedBackend};
+use self::backend::{Backend, MapBuilder, TypedBackend};
+use crate::{
+    array::TypedArray,
+    types::run_end::{RunEnd, RunEndIndex, RunEndValue},
+    validity::ValiditySlice,
+    ArrayElement, NullableElement,
+};
 #[cfg(doc)]
-use crate::{types::primitive::PrimitiveType, OptionSlice};
-use crate::{validity::ValiditySlice, ArrayElement, NullableElement};
+use crate::{
+    types::{map::Map, primitive::PrimitiveType},
+    OptionSlice,
+};
 use arrow_array::builder::ArrayBuilder;
+use arrow_schema::ArrowError;
 
 /// Strongly typed array builder
 #[derive(Debug)]
@@ -45,8 +54,17 @@ where
 //
 impl<T: ArrayElement + ?Sized> TypedBuilder<T> {
     /// Create a new array builder with an explicit configuration
-    //
-    // TODO: Add a usage example with an element type which actually needs a nontrivial config
+    ///
+    /// ```rust
+    /// # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::bytes::{ByteViewConfig, Utf8View}};
+    /// let builder = TypedBuilder::<Utf8View>::with_config(BuilderConfig::with_backend(
+    ///     None,
+    ///     ByteViewConfig {
+    ///         block_size: 1024 * 1024,
+    ///         deduplicate: false,
+    ///     },
+    /// ));
+    /// ```
     pub fn with_config(config: BuilderConfig<T>) -> Self {
         Self(BuilderBackend::<T>::new(config))
     }
@@ -84,12 +102,19 @@ impl<T: ArrayElement + ?Sized> TypedBuilder<T> {
     //
     // TODO: Example with a type where T::Value is less obvious
     ///
+    /// Pushing a value always succeeds for most types, but some types have
+    /// values whose shape is only known at runtime, like fixed-size binary data
+    /// whose byte width comes from the [backend configuration](BackendConfig).
+    /// For these types, pushing a value of the wrong shape fails, and this
+    /// method returns `Result<(), ArrowError>` instead of `()`. See
+    /// [`ArrayElement::PushResult`] for more information.
+    ///
     /// For types with a complex internal structure, such element-wise insertion
     /// may be inefficient. Therefore, if you intend to insert many values, it
     /// is advised that you do not do so by calling this method in a loop, but
     /// instead look into the bulk insertion methods below.
     #[inline]
-    pub fn push(&mut self, value: T::Value<'_>) {
+    pub fn push(&mut self, value: T::Value<'_>) -> T::PushResult {
         self.0.push(value)
     }
 
@@ -134,9 +159,17 @@ impl<T: ArrayElement + ?Sized> TypedBuilder<T> {
     /// composite slice types like `OptionSlice` may fail if the inner subslices
     /// have differing lengths. Accordingly, this method returns `()` when
     /// `T::Slice` is a simple Rust slice type, but `Result<(), ArrowError>`
-    /// when `T::Slice` is a composite slice type.
-    //
-    // TODO: Add an example with structs?
+    /// when `T::Slice` is a composite slice type. Tuples, for example, are
+    /// passed as tuples of slices whose lengths must match:
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<(u8, f64)>::new();
+    /// builder.extend_from_slice((&[1, 2][..], &[3.4, 5.6][..]))?;
+    /// assert!(builder.extend_from_slice((&[7][..], &[][..])).is_err());
+    /// assert_eq!(builder.len(), 2);
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
     pub fn extend_from_slice(&mut self, s: T::Slice<'_>) -> T::ExtendFromSliceResult {
         self.0.extend_from_slice(s)
     }
@@ -167,6 +200,82 @@ where
     }
 }
 //
+impl<M: ArrayElement<BuilderBackend = MapBuilder<K, V>>, K: ArrayElement, V: ArrayElement>
+    TypedBuilder<M>
+{
+    /// Append a valid map whose entries are yielded by an iterator
+    ///
+    /// This operation is available for `TypedBuilder`s of [`Map<K, V>`] and
+    /// `Option<Map<K, V>>`. It lets you push a map from any iterator of
+    /// key-value pairs, including Rust maps whose entries have the right type.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{TypedBuilder, types::map::Map};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut builder = TypedBuilder::<Option<Map<String, f64>>>::new();
+    /// builder.push_entries(HashMap::from([("pi", 3.14), ("e", 2.72)]))?;
+    /// builder.push_entries([("tau", 6.28)])?;
+    /// builder.push(None)?;
+    /// assert_eq!(builder.len(), 3);
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn push_entries<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = (K::Value<'a>, V::Value<'a>)>,
+    ) -> Result<(), ArrowError> {
+        self.0.append_entries(entries)
+    }
+}
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> TypedBuilder<RunEnd<R, V>> {
+    /// Append `count` repetitions of a value
+    ///
+    /// This operation is available for `TypedBuilder`s of [`RunEnd<R, V>`]. If
+    /// the value is identical to the last value that was pushed, the last run
+    /// is extended, otherwise a new run is started.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{TypedBuilder, types::run_end::RunEnd};
+    /// let mut builder = TypedBuilder::<RunEnd<i16, bool>>::new();
+    /// builder.extend_run(true, 100)?;
+    /// builder.push(true)?;
+    /// builder.extend_run(false, 10)?;
+    /// assert_eq!(builder.len(), 111);
+    /// assert!(builder.finish().as_slice().runs().eq([(true, 101), (false, 10)]));
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    ///
+    /// Nothing is appended if the length of the array would exceed the maximal
+    /// value of `R`.
+    pub fn extend_run(&mut self, value: V::Value<'_>, count: usize) -> Result<(), ArrowError> {
+        self.0.extend_run(Some(value), count)
+    }
+}
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> TypedBuilder<Option<RunEnd<R, V>>> {
+    /// Append `count` repetitions of a value, which may be null
+    ///
+    /// This is the `Option<RunEnd<R, V>>` counterpart of
+    /// [`TypedBuilder<RunEnd<R, V>>::extend_run()`](TypedBuilder::extend_run).
+    ///
+    /// ```rust
+    /// # use arrow_typing::{TypedBuilder, types::run_end::RunEnd};
+    /// let mut builder = TypedBuilder::<Option<RunEnd<i32, str>>>::new();
+    /// builder.extend_run(Some("ok"), 3)?;
+    /// builder.extend_run(None, 2)?;
+    /// assert!(builder.finish().as_slice().runs().eq([(Some("ok"), 3), (None, 2)]));
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn extend_run(
+        &mut self,
+        value: Option<V::Value<'_>>,
+        count: usize,
+    ) -> Result<(), ArrowError> {
+        self.0.extend_run(value, count)
+    }
+}
+//
 impl<T: ArrayElement + ?Sized> TypedBuilder<T> {
     /// Efficiently append multiple null values into the builder
     ///
@@ -211,8 +320,31 @@ impl<T: ArrayElement + ?Sized> TypedBuilder<T> {
         self.0.is_empty()
     }
 
-    // TODO: Some equivalent of ArrayBuilder::finish() and finish_cloned that
-    //       returns a TypedArrayRef
+    /// Build the array and reset the builder
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<f32>::new();
+    /// builder.extend_from_slice(&[1.0, 2.0, 3.0]);
+    /// let array = builder.finish();
+    /// assert!(builder.is_empty());
+    /// ```
+    pub fn finish(&mut self) -> TypedArray<T> {
+        TypedArray::new(Backend::finish(&mut self.0))
+    }
+
+    /// Build the array without resetting the builder
+    ///
+    /// ```rust
+    /// # use arrow_typing::TypedBuilder;
+    /// let mut builder = TypedBuilder::<f32>::new();
+    /// builder.extend_from_slice(&[1.0, 2.0, 3.0]);
+    /// let array = builder.finish_cloned();
+    /// assert_eq!(builder.len(), 3);
+    /// ```
+    pub fn finish_cloned(&self) -> TypedArray<T> {
+        TypedArray::new(Backend::finish_cloned(&self.0))
+    }
 }
 //
 impl<T> TypedBuilder<Option<T>>
@@ -223,7 +355,8 @@ where
     /// Current null buffer / validity slice
     ///
     /// This operation is only available on `TypedBuilder`s of optional `bool`s,
-    /// [primitive types](PrimitiveType), bytes and strings.
+    /// [primitive types](PrimitiveType), bytes, strings, fixed-size byte
+    /// arrays, lists and maps.
     ///
     /// It may return `None` when all elements are known to be valid. Otherwise,
     /// it will return a `&[bool]`-like [`ValiditySlice`] which can be used to
@@ -264,7 +397,11 @@ where
     }
 }
 //
-impl<'a, T: ArrayElement + ?Sized> Extend<T::Value<'a>> for TypedBuilder<T> {
+/// Element-wise insertion from an iterator
+///
+/// This is only available for element types whose [`push()`](Self::push)
+/// operation cannot fail.
+impl<'a, T: ArrayElement<PushResult = ()> + ?Sized> Extend<T::Value<'a>> for TypedBuilder<T> {
     fn extend<I: IntoIterator<Item = T::Value<'a>>>(&mut self, iter: I) {
         for item in iter {
             self.push(item)
@@ -304,6 +441,28 @@ where
     }
 }
 //
+impl<T: ArrayElement + ?Sized> BuilderConfig<T> {
+    /// Builder configuration with an explicit backend configuration
+    ///
+    /// `capacity` is the minimal number of elements that the builder should
+    /// be able to accept without reallocating, or `None` to let the builder
+    /// pick a default capacity.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{builder::BuilderConfig, types::bytes::{ByteViewConfig, Utf8View}};
+    /// let config = BuilderConfig::<Utf8View>::with_backend(
+    ///     Some(42),
+    ///     ByteViewConfig {
+    ///         deduplicate: true,
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    pub fn with_backend(capacity: Option<usize>, backend: BackendConfig<T>) -> Self {
+        Self { capacity, backend }
+    }
+}
+//
 impl<T: ArrayElement + ?Sized> Default for BuilderConfig<T>
 where
     BackendConfig<T>: Default,
@@ -317,7 +476,7 @@ where
 }
 
 /// Shortcut to the arrow builder type used to construct an array of Ts
-type BuilderBackend<T> = <T as ArrayElement>::BuilderBackend;
+pub(crate) type BuilderBackend<T> = <T as ArrayElement>::BuilderBackend;
 
 /// Array builder configuration that is specific to a given element type `T`
 ///
@@ -331,7 +490,7 @@ type BuilderBackend<T> = <T as ArrayElement>::BuilderBackend;
 /// be configured with an inner sublist size. In this case, the
 /// [`TypedBuilder::with_config()`] constructor must be used, and it will
 /// directly or indirectly receive this configuration type as a parameter.
-type BackendConfig<T> = <BuilderBackend<T> as TypedBackend<T>>::Config;
+pub type BackendConfig<T> = <BuilderBackend<T> as TypedBackend<T>>::Config;
 
 #[allow(private_bounds)]
 #[cfg(test)]
@@ -339,8 +498,9 @@ mod tests {
     use crate::OptionSlice;
 
     use super::*;
+    use arrow_array::Array;
     use arrow_schema::ArrowError;
-    use backend::ValiditySlice;
+    use backend::{Backend, ValiditySlice};
     use proptest::{prelude::*, sample::SizeRange, test_runner::TestCaseResult};
 
     /// Check the validity mask of a TypedBuilder that has the validity_slice()
@@ -362,13 +522,13 @@ mod tests {
     ///
     /// This does not work with `NullBuilder`, for which `len == capacity`
     pub fn check_init_with_capacity_outcome(
-        builder: &TypedBuilder<impl ArrayElement>,
+        builder: &TypedBuilder<impl ArrayElement + ?Sized>,
         capacity: usize,
     ) -> TestCaseResult {
         prop_assert!(builder.capacity() >= capacity);
         prop_assert_eq!(builder.len(), 0);
         prop_assert!(builder.is_empty());
-        // TODO: Build and check final array
+        prop_assert_eq!(builder.finish_cloned().into_array_ref().len(), 0);
         Ok(())
     }
 
@@ -402,7 +562,7 @@ mod tests {
     }
 
     /// Check outcome of initializing a `TypedBuilder` with the default capacity
-    pub fn check_init_default<T: ArrayElement>() -> TestCaseResult
+    pub fn check_init_default<T: ArrayElement + ?Sized>() -> TestCaseResult
     where
         BackendConfig<T>: Default,
     {
@@ -433,29 +593,46 @@ mod tests {
     /// This does not work as expected on `TypedBuilder<Null>` because the
     /// notion of length/capacity used by the underlying `NullBuilder` is weird.
     pub fn check_extend_outcome(
-        builder: &TypedBuilder<impl ArrayElement>,
+        builder: &TypedBuilder<impl ArrayElement + ?Sized>,
         init_capacity: usize,
         num_elements: usize,
     ) -> TestCaseResult {
         prop_assert!(builder.capacity() >= init_capacity.max(num_elements));
         prop_assert_eq!(builder.len(), num_elements);
         prop_assert_eq!(builder.is_empty(), num_elements == 0);
-        // TODO: Build and check final array
+        prop_assert_eq!(builder.finish_cloned().into_array_ref().len(), num_elements);
+        Ok(())
+    }
+
+    /// Check that truncating a builder to the length of another builder, which
+    /// received the same first elements, makes both build the same array
+    pub fn check_truncate<T: ArrayElement + ?Sized>(
+        mut builder: TypedBuilder<T>,
+        expected: &TypedBuilder<T>,
+    ) -> TestCaseResult {
+        Backend::truncate(&mut builder.0, expected.len());
+        prop_assert_eq!(builder.len(), expected.len());
+        let array = builder.finish().into_array_ref();
+        let expected = expected.finish_cloned().into_array_ref();
+        prop_assert_eq!(array.as_ref(), expected.as_ref());
         Ok(())
     }
 
     /// Check outcome of pushing a value into a newly created TypedBuilder
-    pub fn check_push<T: ArrayElement>(
+    pub fn check_push<T: ArrayElement + ?Sized>(
         backend_config: BackendConfig<T>,
         init_capacity: usize,
         value: T::Value<'_>,
     ) -> TestCaseResult {
+        let data_type = T::data_type(&backend_config);
         let mut builder = TypedBuilder::<T>::with_config(BuilderConfig {
             capacity: Some(init_capacity),
             backend: backend_config,
         });
         builder.push(value);
         check_extend_outcome(&builder, init_capacity, 1)?;
+        let array = builder.finish().into_array_ref();
+        prop_assert_eq!(array.data_type(), &data_type);
         Ok(())
     }
 
@@ -470,6 +647,7 @@ mod tests {
         BuilderBackend<Option<T>>: ValiditySlice,
         for<'a> Option<T>: Into<<Option<T> as ArrayElement>::Value<'a>>,
     {
+        let data_type = Option::<T>::data_type(&backend_config);
         let mut builder = TypedBuilder::<Option<T>>::with_config(BuilderConfig {
             capacity: Some(init_capacity),
             backend: backend_config,
@@ -478,18 +656,20 @@ mod tests {
         builder.push(value.into());
         check_extend_outcome(&builder, init_capacity, 1)?;
         check_validity(&builder, &[valid])?;
+        let array = builder.finish().into_array_ref();
+        prop_assert_eq!(array.data_type(), &data_type);
         Ok(())
     }
 
     /// Check outcome of extending a builder of T or Option<T> with a slice of
     /// values
-    pub fn check_extend_from_values<T: ArrayElement>(
+    pub fn check_extend_from_values<T: ArrayElement<PushResult = ()>>(
         make_backend_config: impl Fn() -> BackendConfig<T>,
         init_capacity: usize,
         values: T::Slice<'_>,
     ) -> TestCaseResult
     where
-        Option<T>: ArrayElement<BuilderBackend = BuilderBackend<T>>,
+        Option<T>: ArrayElement<BuilderBackend = BuilderBackend<T>, PushResult = ()>,
         BuilderBackend<T>: TypedBackend<Option<T>, Config = BackendConfig<T>>,
         BuilderBackend<Option<T>>: ValiditySlice,
         for<'a> T::Slice<'a>: Slice<T::Value<'a>> + Clone,
@@ -535,7 +715,7 @@ mod tests {
     //
     trait Slice<T>: Clone {
         fn slice_len(&self) -> usize;
-        fn slice_iter<'self_>(&'self_ self) -> impl Iterator<Item = &T> + 'self_
+        fn slice_iter<'self_>(&'self_ self) -> impl Iterator<Item = &'self_ T> + 'self_
         where
             T: 'self_;
     }
@@ -544,7 +724,7 @@ mod tests {
         fn slice_len(&self) -> usize {
             self.len()
         }
-        fn slice_iter<'self_>(&'self_ self) -> impl Iterator<Item = &T> + 'self_
+        fn slice_iter<'self_>(&'self_ self) -> impl Iterator<Item = &'self_ T> + 'self_
         where
             T: 'self_,
         {
@@ -634,4 +814,147 @@ mod tests {
         check_validity(&builder, &vec![false; num_nulls])?;
         Ok(())
     }
+    /// Generate `i16` values that are often repeated, with optional validity
+    ///
+    /// This is used to test encoded element types like dictionaries and
+    /// run-end encoded arrays, whose layout depends on repetitions.
+    pub fn repeated_values() -> impl Strategy<Value = Vec<Option<i16>>> {
+        any::<Vec<Option<u8>>>().prop_map(|values| {
+            values
+                .into_iter()
+                .map(|v| v.map(|v| i16::from(v % 3)))
+                .collect()
+        })
+    }
+
+    /// Check every insertion method of an encoded element type `E` of `i16`
+    /// values, and of `Option<E>`
+    ///
+    /// `check_array` checks that a finished array holds the expected values,
+    /// and the encoding-specific properties of its layout.
+    pub fn check_encoding<E>(
+        init_capacity: usize,
+        values: &[Option<i16>],
+        check_array: impl Fn(&dyn Array, &[Option<i16>]) -> TestCaseResult,
+    ) -> TestCaseResult
+    where
+        E: for<'a> ArrayElement<
+            Value<'a> = i16,
+            Slice<'a> = &'a [i16],
+            PushResult = Result<(), ArrowError>,
+            ExtendFromSliceResult = Result<(), ArrowError>,
+        >,
+        Option<E>: for<'a> ArrayElement<
+                Value<'a> = Option<i16>,
+                Slice<'a> = OptionSlice<'a, E>,
+                BuilderBackend = BuilderBackend<E>,
+                PushResult = Result<(), ArrowError>,
+                ExtendFromSliceResult = Result<(), ArrowError>,
+            > + NullableElement,
+        BuilderBackend<E>: TypedBackend<Option<E>>,
+        BackendConfig<E>: Default,
+        BackendConfig<Option<E>>: Default,
+    {
+        let valid_values = values.iter().copied().flatten().collect::<Vec<_>>();
+        let expected_valid = valid_values.iter().copied().map(Some).collect::<Vec<_>>();
+        let finish = |builder: &mut TypedBuilder<_>| builder.finish().into_array_ref();
+
+        // Values pushed one by one
+        if let Some(&value) = valid_values.first() {
+            check_push::<E>(Default::default(), init_capacity, value)?;
+        }
+        let mut builder = TypedBuilder::<E>::with_capacity(init_capacity);
+        for &value in &valid_values {
+            builder.push(value)?;
+        }
+        check_extend_outcome(&builder, init_capacity, valid_values.len())?;
+        check_array(builder.finish().into_array_ref().as_ref(), &expected_valid)?;
+        let mut builder = TypedBuilder::<Option<E>>::with_capacity(init_capacity);
+        for &value in values {
+            builder.push(value)?;
+        }
+        check_extend_outcome(&builder, init_capacity, values.len())?;
+        check_array(finish(&mut builder).as_ref(), values)?;
+
+        // Values inserted from slices
+        let mut builder = TypedBuilder::<E>::with_capacity(init_capacity);
+        builder.extend_from_slice(&valid_values)?;
+        check_extend_outcome(&builder, init_capacity, valid_values.len())?;
+        check_array(builder.finish().into_array_ref().as_ref(), &expected_valid)?;
+        let mut builder = TypedBuilder::<Option<E>>::with_capacity(init_capacity);
+        builder.extend_from_value_slice(&valid_values)?;
+        check_extend_outcome(&builder, init_capacity, valid_values.len())?;
+        check_array(finish(&mut builder).as_ref(), &expected_valid)?;
+
+        // Values inserted from slices with validity, which is rejected if it
+        // does not have the length of the values
+        let raw_values = values
+            .iter()
+            .map(|v| v.unwrap_or_default())
+            .collect::<Vec<_>>();
+        let is_valid = values.iter().map(Option::is_some).collect::<Vec<_>>();
+        let mut builder = TypedBuilder::<Option<E>>::with_capacity(init_capacity);
+        builder.extend_from_slice(OptionSlice {
+            values: &raw_values,
+            is_valid: &is_valid,
+        })?;
+        check_extend_outcome(&builder, init_capacity, values.len())?;
+        check_array(finish(&mut builder).as_ref(), values)?;
+        if let Some((_, is_valid)) = is_valid.split_last() {
+            let mut builder = TypedBuilder::<Option<E>>::with_capacity(init_capacity);
+            let result = builder.extend_from_slice(OptionSlice {
+                values: &raw_values,
+                is_valid,
+            });
+            prop_assert!(result.is_err());
+            check_init_with_capacity_outcome(&builder, init_capacity)?;
+        }
+
+        // Nulls, inserted twice so that encodings can merge them
+        let mut builder = TypedBuilder::<Option<E>>::with_capacity(init_capacity);
+        builder.extend_with_nulls(values.len());
+        builder.extend_with_nulls(values.len());
+        check_extend_outcome(&builder, init_capacity, 2 * values.len())?;
+        check_array(finish(&mut builder).as_ref(), &vec![None; 2 * values.len()])?;
+
+        // Truncation, which must preserve the encoding of the remaining values
+        let len = values.len() / 2;
+        let mut builder = TypedBuilder::<Option<E>>::new();
+        let mut expected = TypedBuilder::<Option<E>>::new();
+        for (index, &value) in values.iter().enumerate() {
+            builder.push(value)?;
+            if index < len {
+                expected.push(value)?;
+            }
+        }
+        check_truncate(builder, &expected)
+    }
+
+    /// Like [`check_encoding()`], but for an encoded element type `E` of
+    /// strings, which are the decimal representations of `values`
+    pub fn check_string_encoding<E>(
+        values: &[Option<i16>],
+        check_array: impl Fn(&dyn Array, &[Option<i16>]) -> TestCaseResult,
+    ) -> TestCaseResult
+    where
+        Option<E>:
+            for<'a> ArrayElement<Value<'a> = Option<&'a str>, PushResult = Result<(), ArrowError>>,
+        BackendConfig<Option<E>>: Default,
+    {
+        let strings = values
+            .iter()
+            .map(|v| v.map(|v| v.to_string()))
+            .collect::<Vec<_>>();
+        let len = values.len() / 2;
+        let mut builder = TypedBuilder::<Option<E>>::new();
+        let mut expected = TypedBuilder::<Option<E>>::new();
+        for (index, string) in strings.iter().enumerate() {
+            builder.push(string.as_deref())?;
+            if index < len {
+                expected.push(string.as_deref())?;
+            }
+        }
+        check_array(builder.finish_cloned().into_array_ref().as_ref(), values)?;
+        check_truncate(builder, &expected)
+    }
 }
@@ -1,19 +1,34 @@
 //! A layer on top of [`arrow`](https://docs.rs/arrow) which enables arrow
 //! arrays to be built and accessed using strongly typed Rust APIs.
 
+pub mod array;
 pub mod builder;
 pub mod types;
 pub mod validity;
 
-use crate::types::primitive::Null;
-#[cfg(doc)]
-use crate::types::primitive::PrimitiveType;
+// Let the derive macros refer to this crate as `::arrow_typing` in its own tests
+extern crate self as arrow_typing;
+
 #[cfg(doc)]
-use arrow_schema::ArrowError;
-use std::fmt::Debug;
+use crate::{builder::BuilderConfig, types::primitive::PrimitiveType};
+use crate::{
+    builder::{backend::TypedBackend, BackendConfig},
+    types::primitive::Null,
+};
+use arrow_schema::{ArrowError, DataType, Field};
+use std::{fmt::Debug, ops::Range};
 
+pub use array::TypedArray;
+#[cfg(feature = "derive")]
+pub use arrow_typing_derive::ArrayElement;
 pub use builder::TypedBuilder;
 
+/// Implementation details of the derive macros
+#[doc(hidden)]
+pub mod __private {
+    pub use arrow_schema::{ArrowError, UnionMode};
+}
+
 /// Strongly typed data which can be stored as an Arrow array element
 ///
 /// # Safety
@@ -43,7 +58,18 @@ pub unsafe trait ArrayElement: Debug + Send + Sync + 'static {
     /// For example, nullable primitive types like `Option<u16>` are
     /// bulk-manipulated using [`OptionSlice`] batches. And tuple types like
     /// `(T, U, V)` are bulk-manipulated using `(&[T], &[U], &[V])` batches.
-    type Slice<'a>: Debug;
+    type Slice<'a>: ElementSlice;
+
+    /// Return type of [`TypedBuilder::push()`].
+    ///
+    /// Pushing a value always succeeds for most types. But for types whose
+    /// values have a shape that is only known at runtime, like fixed-size
+    /// binary data whose byte width is [configured](BackendConfig) at runtime,
+    /// pushing can fail with [`ArrowError`] if the value has the wrong shape.
+    ///
+    /// Accordingly, the return type of `push()` is `()` for most types, and
+    /// `Result<(), ArrowError>` for types with runtime-defined shapes.
+    type PushResult: InsertionResult;
 
     /// Return type of [`TypedBuilder::extend_from_slice()`].
     ///
@@ -53,7 +79,69 @@ pub unsafe trait ArrayElement: Debug + Send + Sync + 'static {
     ///
     /// Accordingly, the return type of `extend_from_slice()` is `()` for
     /// simple slices, and `Result<(), ArrowError>` for composite slices.
-    type ExtendFromSliceResult: Debug;
+    type ExtendFromSliceResult: InsertionResult;
+
+    /// Truth that this element type can be null
+    ///
+    /// This is `true` for [`Null`] and options of valid array element types,
+    /// and `false` for all other types. It determines the nullability of the
+    /// fields produced by [`ArrayElement::field()`].
+    const NULLABLE: bool;
+
+    /// Arrow data type of arrays of this element type
+    ///
+    /// ```rust
+    /// # use arrow_typing::{ArrayElement, types::primitive::{Duration, Nanosecond}};
+    /// use arrow_schema::{DataType, TimeUnit};
+    ///
+    /// assert_eq!(f32::data_type(&()), DataType::Float32);
+    /// assert_eq!(
+    ///     Duration::<Nanosecond>::data_type(&()),
+    ///     DataType::Duration(TimeUnit::Nanosecond)
+    /// );
+    /// ```
+    ///
+    /// For some element types, the data type depends on the [backend
+    /// configuration](BackendConfig) that is passed to builders of this type
+    /// via [`BuilderConfig`]. For simple types, this configuration is `()`.
+    fn data_type(config: &BackendConfig<Self>) -> DataType {
+        <Self::BuilderBackend as TypedBackend<Self>>::data_type(config)
+    }
+
+    /// Arrow field for a column of this element type
+    ///
+    /// The nullability of the field is derived from the element type, so that
+    /// only columns of `Option<T>` and [`Null`] are nullable.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{ArrayElement, types::primitive::Date32};
+    /// use arrow_schema::{DataType, Field, Schema};
+    ///
+    /// let schema = Schema::new(vec![
+    ///     Date32::field("date"),
+    ///     Option::<f64>::field("price"),
+    /// ]);
+    /// assert_eq!(schema.field(0), &Field::new("date", DataType::Date32, false));
+    /// assert_eq!(schema.field(1), &Field::new("price", DataType::Float64, true));
+    /// ```
+    ///
+    /// This is only available for element types whose backend configuration
+    /// has a default value. Other element types must use
+    /// [`ArrayElement::field_with_config()`].
+    fn field(name: impl Into<String>) -> Field
+    where
+        BackendConfig<Self>: Default,
+    {
+        Self::field_with_config(name, &BackendConfig::<Self>::default())
+    }
+
+    /// Arrow field for a column of this element type, with an explicit backend
+    /// configuration
+    ///
+    /// See [`ArrayElement::field()`] for more information.
+    fn field_with_config(name: impl Into<String>, config: &BackendConfig<Self>) -> Field {
+        Field::new(name, Self::data_type(config), Self::NULLABLE)
+    }
 }
 
 /// [`ArrayElement`] which has a null value
@@ -67,6 +155,90 @@ impl NullableElement for Null {}
 //
 impl<T: ArrayElement> NullableElement for Option<T> where Option<T>: ArrayElement {}
 
+/// Bulk insertion layout of an [`ArrayElement`]
+///
+/// This trait is implemented by every [`ArrayElement::Slice`] type. It lets
+/// container types like [`List`](types::list::List) measure and split the
+/// slices of their inner element type.
+pub trait ElementSlice: Clone + Debug {
+    /// Number of elements in the slice
+    ///
+    /// This fails if the slice is made of several inner slices whose lengths
+    /// do not match, as in an [`OptionSlice`] whose `values` and `is_valid`
+    /// have a different length.
+    fn slice_len(&self) -> Result<usize, ArrowError>;
+
+    /// Sub-slice containing the elements within `range`
+    ///
+    /// # Panics
+    ///
+    /// May panic if `range` is out of bounds, or if
+    /// [`slice_len()`](Self::slice_len) fails.
+    fn slice_range(self, range: Range<usize>) -> Self;
+}
+//
+impl<T: Debug> ElementSlice for &[T] {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        Ok(self.len())
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        &self[range]
+    }
+}
+//
+/// Slices of [`Null`] are just a number of elements
+impl ElementSlice for usize {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        Ok(*self)
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= self,
+            "range is out of bounds"
+        );
+        range.len()
+    }
+}
+
+/// Outcome of an insertion into a [`TypedBuilder`]
+///
+/// This is the type of [`ArrayElement::PushResult`] and
+/// [`ArrayElement::ExtendFromSliceResult`]. It is implemented by `()` for
+/// insertions that cannot fail, and `Result<(), ArrowError>` for the others.
+pub trait InsertionResult: Debug {
+    /// Build an insertion outcome from the result of a fallible insertion
+    ///
+    /// # Panics
+    ///
+    /// Panics if `result` is an error and this outcome type is `()`.
+    fn from_result(result: Result<(), ArrowError>) -> Self;
+
+    /// Turn this insertion outcome into the result of a fallible insertion
+    fn into_result(self) -> Result<(), ArrowError>;
+}
+//
+impl InsertionResult for () {
+    fn from_result(result: Result<(), ArrowError>) -> Self {
+        result.expect("insertions of this element type cannot fail")
+    }
+
+    fn into_result(self) -> Result<(), ArrowError> {
+        Ok(())
+    }
+}
+//
+impl InsertionResult for Result<(), ArrowError> {
+    fn from_result(result: Result<(), ArrowError>) -> Self {
+        result
+    }
+
+    fn into_result(self) -> Result<(), ArrowError> {
+        self
+    }
+}
+
 /// Columnar alternative to `&[Option<T>]`
 #[derive(Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct OptionSlice<'a, T: ArrayElement> {
@@ -88,6 +260,25 @@ where
         }
     }
 }
+//
+impl<T: ArrayElement> ElementSlice for OptionSlice<'_, T> {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        let len = self.values.slice_len()?;
+        if len != self.is_valid.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Value and validity lengths must be equal".to_string(),
+            ));
+        }
+        Ok(len)
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        Self {
+            values: self.values.slice_range(range.clone()),
+            is_valid: &self.is_valid[range],
+        }
+    }
+}
 
 /// Shared test utilities
 #[cfg(test)]
@@ -101,4 +292,15 @@ mod tests {
     pub fn length_or_capacity() -> impl Strategy<Value = usize> {
         0..=MAX_CAPACITY
     }
+
+    /// Generate a vector, along with the offset and length of a subslice of it
+    pub fn vec_and_subslice<T: Arbitrary + Clone>() -> impl Strategy<Value = (Vec<T>, usize, usize)>
+    {
+        any::<Vec<T>>().prop_flat_map(|values| {
+            let len = values.len();
+            (Just(values), 0..=len).prop_flat_map(move |(values, offset)| {
+                (Just(values), Just(offset), 0..=len - offset)
+            })
+        })
+    }
 }
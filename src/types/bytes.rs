@@ -0,0 +1,857 @@
+//! Strongly typed interface to arrow-rs' variable-length string and binary
+//! [`DataType`]s
+
+use crate::{
+    builder::backend::{ByteBuilder, ByteViewBuilder},
+    ArrayElement, ElementSlice, OptionSlice,
+};
+use arrow_array::{
+    types::{
+        BinaryType, BinaryViewType, ByteArrayType, ByteViewType, GenericBinaryType,
+        GenericStringType, LargeBinaryType, LargeUtf8Type, StringViewType, Utf8Type,
+    },
+    OffsetSizeTrait,
+};
+use arrow_buffer::{ArrowNativeType, Buffer};
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::Range,
+};
+
+// === Strong value types matching non-std Arrow DataTypes ===
+
+/// UTF-8 string with 64-bit offsets
+///
+/// `str` and `String` are stored as arrow [`DataType::Utf8`] arrays, which use
+/// 32-bit offsets and can therefore only hold up to 2 GiB of string data. This
+/// marker element type is stored as [`DataType::LargeUtf8`] arrays instead,
+/// which use 64-bit offsets. Values are still written and read as `&str`.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, types::bytes::LargeUtf8};
+/// let mut builder = TypedBuilder::<LargeUtf8>::new();
+/// builder.push("hello");
+/// assert_eq!(builder.finish().value(0), "hello");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LargeUtf8;
+
+/// Binary data with 64-bit offsets
+///
+/// This is the [`DataType::LargeBinary`] counterpart of [`LargeUtf8`]. Values
+/// are written and read as `&[u8]`.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, types::bytes::LargeBinary};
+/// let mut builder = TypedBuilder::<Option<LargeBinary>>::new();
+/// builder.push(Some(b"\xde\xad\xbe\xef"));
+/// builder.push(None);
+/// assert_eq!(builder.finish().value(0), Some(&b"\xde\xad\xbe\xef"[..]));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LargeBinary;
+
+/// UTF-8 string stored in a view array
+///
+/// This marker element type is stored as [`DataType::Utf8View`] arrays, where
+/// each element is a 16-byte view that either inlines short strings (up to 12
+/// bytes) or points into a set of data buffers. Values are still written and
+/// read as `&str`, and bulk insertion uses the same [`StringSlice`] layout as
+/// `String`.
+///
+/// See [`ByteViewConfig`] for tuning of the underlying builder.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, types::bytes::Utf8View};
+/// let mut builder = TypedBuilder::<Utf8View>::new();
+/// builder.push("short");
+/// builder.push("a string that does not fit in a view");
+/// let array = builder.finish();
+/// assert_eq!(array.value(0), "short");
+/// assert_eq!(array.value(1), "a string that does not fit in a view");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Utf8View;
+
+/// Binary data stored in a view array
+///
+/// This is the [`DataType::BinaryView`] counterpart of [`Utf8View`]. Values
+/// are written and read as `&[u8]`, and bulk insertion uses the same
+/// [`BinarySlice`] layout as `Vec<u8>`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BinaryView;
+
+/// Configuration of builders of [`Utf8View`] and [`BinaryView`] arrays
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::bytes::{ByteViewConfig, Utf8View}};
+/// let mut builder = TypedBuilder::<Utf8View>::with_config(BuilderConfig::with_backend(
+///     None,
+///     ByteViewConfig {
+///         block_size: 64 * 1024,
+///         deduplicate: true,
+///     },
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ByteViewConfig {
+    /// Size of the data buffers that are allocated to hold non-inlined values
+    ///
+    /// Values that are larger than this get a dedicated buffer.
+    pub block_size: u32,
+
+    /// Truth that non-inlined values should be deduplicated
+    ///
+    /// When this is enabled, the builder keeps track of the values that were
+    /// previously inserted, and when a value is inserted again, the
+    /// corresponding view points to the previously stored data instead of
+    /// storing another copy of it. This saves memory when values are often
+    /// repeated, at the expense of slower insertion.
+    pub deduplicate: bool,
+}
+//
+impl ByteViewConfig {
+    /// Default data buffer size, same as arrow-rs' `GenericByteViewBuilder`
+    pub const DEFAULT_BLOCK_SIZE: u32 = 8 * 1024;
+}
+//
+impl Default for ByteViewConfig {
+    fn default() -> Self {
+        Self {
+            block_size: Self::DEFAULT_BLOCK_SIZE,
+            deduplicate: false,
+        }
+    }
+}
+
+// === Columnar slices of variable-length data ===
+
+/// Arrow [`ByteArrayType`] whose elements can be accessed in a strongly typed
+/// manner
+///
+/// This extends `ByteArrayType` with the few operations that are needed to
+/// manipulate its `Native` Rust type (`str` or `[u8]`) in a generic way.
+pub trait ByteArrayTypeExt: ByteArrayType<Native: Debug + PartialEq> {
+    /// Truth that `offset` is a valid element boundary within `values`
+    ///
+    /// `offset` must be at most `values.len()` and, for strings, it must fall
+    /// on a `char` boundary.
+    fn is_boundary(values: &Self::Native, offset: usize) -> bool;
+
+    /// Subslice of `values` without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `range` must be in bounds and both of its ends must be valid element
+    /// boundaries according to [`is_boundary()`](Self::is_boundary).
+    unsafe fn slice_unchecked(values: &Self::Native, range: Range<usize>) -> &Self::Native;
+
+    /// Reinterpret bytes as a `Native` value without validation
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be a valid `Native` value, e.g. valid UTF-8 for strings.
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self::Native;
+}
+//
+impl<O: OffsetSizeTrait> ByteArrayTypeExt for GenericStringType<O> {
+    #[inline]
+    fn is_boundary(values: &str, offset: usize) -> bool {
+        values.is_char_boundary(offset)
+    }
+
+    #[inline]
+    unsafe fn slice_unchecked(values: &str, range: Range<usize>) -> &str {
+        values.get_unchecked(range)
+    }
+
+    #[inline]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &str {
+        std::str::from_utf8_unchecked(bytes)
+    }
+}
+//
+impl<O: OffsetSizeTrait> ByteArrayTypeExt for GenericBinaryType<O> {
+    #[inline]
+    fn is_boundary(values: &[u8], offset: usize) -> bool {
+        offset <= values.len()
+    }
+
+    #[inline]
+    unsafe fn slice_unchecked(values: &[u8], range: Range<usize>) -> &[u8] {
+        values.get_unchecked(range)
+    }
+
+    #[inline]
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+}
+
+/// Columnar alternative to `&[&str]` and other slices of variable-length data
+///
+/// This uses the same layout as arrow: the `i`-th element of the slice is
+/// `&values[offsets[i]..offsets[i + 1]]`. Therefore, a slice of N elements
+/// has N+1 offsets, which must be sorted in increasing order.
+///
+/// As an exception to the above rule, an empty `offsets` slice is also
+/// accepted and denotes a slice with no elements.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, types::bytes::StringSlice};
+/// let mut builder = TypedBuilder::<String>::new();
+/// builder.extend_from_slice(StringSlice {
+///     offsets: &[0, 5, 5, 10],
+///     values: "helloworld",
+/// })?;
+/// let array = builder.finish();
+/// assert_eq!(array.value(0), "hello");
+/// assert_eq!(array.value(1), "");
+/// assert_eq!(array.value(2), "world");
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+///
+/// Offsets are validated before insertion, and invalid offsets will result in
+/// an [`ArrowError`] being returned.
+pub struct ByteSlice<'a, T: ByteArrayTypeExt> {
+    /// Start and end position of each element within `values`
+    pub offsets: &'a [T::Offset],
+
+    /// Concatenated elements
+    pub values: &'a T::Native,
+}
+//
+/// Columnar alternative to `&[&str]`
+pub type StringSlice<'a> = ByteSlice<'a, Utf8Type>;
+//
+/// Columnar alternative to `&[&[u8]]`
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, types::bytes::BinarySlice};
+/// let mut builder = TypedBuilder::<Vec<u8>>::new();
+/// builder.extend_from_slice(BinarySlice {
+///     offsets: &[0, 2, 6],
+///     values: &[0xca, 0xfe, 0xde, 0xad, 0xbe, 0xef],
+/// })?;
+/// let array = builder.finish();
+/// assert_eq!(array.value(0), [0xca, 0xfe]);
+/// assert_eq!(array.value(1), [0xde, 0xad, 0xbe, 0xef]);
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub type BinarySlice<'a> = ByteSlice<'a, BinaryType>;
+//
+/// Columnar alternative to `&[&str]` for [`LargeUtf8`]
+pub type LargeStringSlice<'a> = ByteSlice<'a, LargeUtf8Type>;
+//
+/// Columnar alternative to `&[&[u8]]` for [`LargeBinary`]
+pub type LargeBinarySlice<'a> = ByteSlice<'a, LargeBinaryType>;
+//
+impl<'a, T: ByteArrayTypeExt> ByteSlice<'a, T> {
+    /// Number of elements in the slice
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Truth that the slice contains no element
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Value of the `index`-th element, if in bounds
+    ///
+    /// # Panics
+    ///
+    /// May panic if this slice is not [valid](Self::validate).
+    pub fn get(&self, index: usize) -> Option<&'a T::Native> {
+        if index >= self.len() {
+            return None;
+        }
+        let start = self.offsets[index].as_usize();
+        let end = self.offsets[index + 1].as_usize();
+        let valid =
+            start <= end && T::is_boundary(self.values, start) && T::is_boundary(self.values, end);
+        assert!(valid, "invalid offsets");
+        // SAFETY: Range validity was checked above
+        Some(unsafe { T::slice_unchecked(self.values, start..end) })
+    }
+
+    /// Iterate over the elements of the slice
+    ///
+    /// # Panics
+    ///
+    /// Iteration may panic if this slice is not [valid](Self::validate).
+    pub fn iter(&self) -> ByteSliceIter<'a, T> {
+        ByteSliceIter {
+            slice: *self,
+            indices: 0..self.len(),
+        }
+    }
+
+    /// Check that the offsets of this slice are valid
+    ///
+    /// Offsets are valid if they are sorted in increasing order, fall within
+    /// the bounds of `values` and, for strings, lie on `char` boundaries.
+    pub fn validate(&self) -> Result<(), ArrowError> {
+        let mut last_offset = 0;
+        for (idx, offset) in self.offsets.iter().enumerate() {
+            let Some(offset) = offset.to_usize() else {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "offset #{idx} is negative"
+                )));
+            };
+            if idx > 0 && offset < last_offset {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "offset #{idx} is smaller than the previous offset"
+                )));
+            }
+            if !T::is_boundary(self.values, offset) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "offset #{idx} is out of bounds or does not lie on an element boundary"
+                )));
+            }
+            last_offset = offset;
+        }
+        Ok(())
+    }
+}
+//
+impl<T: ByteArrayTypeExt> Clone for ByteSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+//
+impl<T: ByteArrayTypeExt> Copy for ByteSlice<'_, T> {}
+//
+impl<T: ByteArrayTypeExt> Debug for ByteSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteSlice")
+            .field("offsets", &self.offsets)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+//
+impl<T: ByteArrayTypeExt> ElementSlice for ByteSlice<'_, T> {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        Ok(self.len())
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        Self {
+            offsets: super::slice_offsets(self.offsets, range),
+            values: self.values,
+        }
+    }
+}
+//
+impl<T: ByteArrayTypeExt> Default for ByteSlice<'_, T>
+where
+    for<'b> &'b T::Native: Default,
+{
+    fn default() -> Self {
+        Self {
+            offsets: &[],
+            values: Default::default(),
+        }
+    }
+}
+//
+impl<'a, T: ByteArrayTypeExt> IntoIterator for ByteSlice<'a, T> {
+    type Item = &'a T::Native;
+    type IntoIter = ByteSliceIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+//
+impl<T: ByteArrayTypeExt> PartialEq for ByteSlice<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offsets == other.offsets && self.values == other.values
+    }
+}
+
+/// Iterator over the elements of a [`ByteSlice`]
+pub struct ByteSliceIter<'a, T: ByteArrayTypeExt> {
+    /// Slice that is being iterated over
+    slice: ByteSlice<'a, T>,
+
+    /// Indices of the elements that have not been yielded yet
+    indices: Range<usize>,
+}
+//
+impl<T: ByteArrayTypeExt> Clone for ByteSliceIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            indices: self.indices.clone(),
+        }
+    }
+}
+//
+impl<T: ByteArrayTypeExt> Debug for ByteSliceIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteSliceIter")
+            .field("slice", &self.slice)
+            .field("indices", &self.indices)
+            .finish()
+    }
+}
+//
+impl<T: ByteArrayTypeExt> DoubleEndedIterator for ByteSliceIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back()?;
+        self.slice.get(index)
+    }
+}
+//
+impl<T: ByteArrayTypeExt> ExactSizeIterator for ByteSliceIter<'_, T> {}
+//
+impl<T: ByteArrayTypeExt> FusedIterator for ByteSliceIter<'_, T> {}
+//
+impl<'a, T: ByteArrayTypeExt> Iterator for ByteSliceIter<'a, T> {
+    type Item = &'a T::Native;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        self.slice.get(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+// === Views of variable-length data ===
+
+/// Arrow [`ByteViewType`] whose elements can be accessed in a strongly typed
+/// manner
+pub trait ByteViewTypeExt: ByteViewType<Native: Debug + PartialEq> {
+    /// Offset-based byte array type with the same `Native` Rust type
+    ///
+    /// Slices of this type are used for bulk insertion into view arrays.
+    type ByteArray: ByteArrayTypeExt<Native = Self::Native>;
+}
+//
+impl ByteViewTypeExt for StringViewType {
+    type ByteArray = Utf8Type;
+}
+//
+impl ByteViewTypeExt for BinaryViewType {
+    type ByteArray = BinaryType;
+}
+
+/// Length of the longest value that can be stored inside of a view
+pub(crate) const MAX_INLINE_LEN: usize = 12;
+
+/// Bulk readout of a view array
+///
+/// Each element of a view array is a 16-byte view, which either directly
+/// contains the element's data if it is at most 12 bytes long, or otherwise
+/// points into one of the array's data buffers.
+pub struct ByteViewSlice<'a, T: ByteViewTypeExt> {
+    /// One view per element
+    views: &'a [u128],
+
+    /// Data buffers that the views point into
+    buffers: &'a [Buffer],
+
+    /// Element type
+    element: PhantomData<T>,
+}
+//
+impl<'a, T: ByteViewTypeExt> ByteViewSlice<'a, T> {
+    /// Wrap the views and data buffers of a valid view array
+    ///
+    /// # Safety
+    ///
+    /// `views` and `buffers` must be valid according to
+    /// `ByteViewType::validate()`.
+    pub(crate) unsafe fn new_unchecked(views: &'a [u128], buffers: &'a [Buffer]) -> Self {
+        Self {
+            views,
+            buffers,
+            element: PhantomData,
+        }
+    }
+
+    /// Number of elements in the slice
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Truth that the slice contains no element
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Raw views, in the format documented by the arrow specification
+    pub fn views(&self) -> &'a [u128] {
+        self.views
+    }
+
+    /// Data buffers that the views point into
+    pub fn buffers(&self) -> &'a [Buffer] {
+        self.buffers
+    }
+
+    /// Value of the `index`-th element, if in bounds
+    pub fn get(&self, index: usize) -> Option<&'a T::Native> {
+        let view = self.views.get(index)?;
+        let len = *view as u32 as usize;
+        let bytes = if len <= MAX_INLINE_LEN {
+            // SAFETY: A view is 16 bytes long, and arrow only supports
+            //         little-endian targets, where the inlined data is stored
+            //         right after the 4-byte length.
+            let view_bytes = unsafe { &*(view as *const u128).cast::<[u8; 16]>() };
+            &view_bytes[4..4 + len]
+        } else {
+            let buffer_index = (*view >> 64) as u32 as usize;
+            let offset = (*view >> 96) as u32 as usize;
+            &self.buffers[buffer_index][offset..offset + len]
+        };
+        // SAFETY: Views were validated upon construction
+        Some(unsafe { T::ByteArray::from_bytes_unchecked(bytes) })
+    }
+
+    /// Iterate over the elements of the slice
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'a T::Native> + ExactSizeIterator + 'a {
+        let slice = *self;
+        (0..self.len()).map(move |index| slice.get(index).expect("index is in bounds"))
+    }
+}
+//
+impl<T: ByteViewTypeExt> Clone for ByteViewSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+//
+impl<T: ByteViewTypeExt> Copy for ByteViewSlice<'_, T> {}
+//
+impl<T: ByteViewTypeExt> Debug for ByteViewSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// === Equivalent of ByteArrayType for the types defined in this module ===
+
+/// Strong value type with a corresponding arrow [`ByteArrayType`]
+pub trait ByteType:
+    // TODO: Once Rust's trait solver supports it, use an ArrayElement<Value<'a>
+    //       = &'a NativeType<Self>, Slice<'a> = ByteSlice<'a, Self::Arrow>>
+    //       bound to simplify downstream usage.
+    ArrayElement<
+        BuilderBackend = ByteBuilder<Self::Arrow>,
+        PushResult = (),
+        ExtendFromSliceResult = Result<(), ArrowError>,
+    >
+{
+    /// Equivalent Arrow byte array type
+    type Arrow: ByteArrayTypeExt;
+}
+
+// Easy access to the Rust type that is used to read out elements of a ByteType
+pub(crate) type NativeType<T> = <<T as ByteType>::Arrow as ByteArrayType>::Native;
+
+// Enable strongly typed arrays of variable-length data
+macro_rules! impl_byte_element {
+    ($($element:ty => $arrow:ty),*) => {
+        $(
+            // SAFETY: This is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl ArrayElement for $element {
+                type BuilderBackend = ByteBuilder<$arrow>;
+                type Value<'a> = &'a <$arrow as ByteArrayType>::Native;
+                type Slice<'a> = ByteSlice<'a, $arrow>;
+                type PushResult = ();
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = false;
+            }
+            //
+            impl ByteType for $element {
+                type Arrow = $arrow;
+            }
+        )*
+    };
+}
+//
+impl_byte_element!(
+    str => Utf8Type,
+    String => Utf8Type,
+    [u8] => BinaryType,
+    Vec<u8> => BinaryType,
+    LargeUtf8 => LargeUtf8Type,
+    LargeBinary => LargeBinaryType
+);
+
+// Enable strongly typed arrays of optional variable-length data
+macro_rules! impl_option_byte_element {
+    ($($element:ty),*) => {
+        $(
+            // SAFETY: Option is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl ArrayElement for Option<$element> {
+                type BuilderBackend = <$element as ArrayElement>::BuilderBackend;
+                type Value<'a> = Option<<$element as ArrayElement>::Value<'a>>;
+                type Slice<'a> = OptionSlice<'a, $element>;
+                type PushResult = ();
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = true;
+            }
+        )*
+    };
+}
+//
+impl_option_byte_element!(
+    String,
+    Vec<u8>,
+    LargeUtf8,
+    LargeBinary,
+    Utf8View,
+    BinaryView
+);
+
+// === Equivalent of ByteViewType for the types defined in this module ===
+
+/// Strong value type with a corresponding arrow [`ByteViewType`]
+pub trait ViewType:
+    // TODO: Once Rust's trait solver supports it, use an ArrayElement<Value<'a>
+    //       = &'a ViewNativeType<Self>, Slice<'a> = ByteSlice<'a, ...>> bound
+    //       to simplify downstream usage.
+    ArrayElement<
+        BuilderBackend = ByteViewBuilder<Self::Arrow>,
+        PushResult = (),
+        ExtendFromSliceResult = Result<(), ArrowError>,
+    >
+{
+    /// Equivalent Arrow byte view type
+    type Arrow: ByteViewTypeExt;
+}
+
+// Easy access to the Rust type that is used to read out elements of a ViewType
+pub(crate) type ViewNativeType<T> = <<T as ViewType>::Arrow as ByteViewType>::Native;
+
+// Enable strongly typed view arrays
+macro_rules! impl_view_element {
+    ($($element:ty => $arrow:ty),*) => {
+        $(
+            // SAFETY: This is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl ArrayElement for $element {
+                type BuilderBackend = ByteViewBuilder<$arrow>;
+                type Value<'a> = &'a <$arrow as ByteViewType>::Native;
+                type Slice<'a> = ByteSlice<'a, <$arrow as ByteViewTypeExt>::ByteArray>;
+                type PushResult = ();
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = false;
+            }
+            //
+            impl ViewType for $element {
+                type Arrow = $arrow;
+            }
+        )*
+    };
+}
+//
+impl_view_element!(
+    Utf8View => StringViewType,
+    BinaryView => BinaryViewType
+);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::tests::MAX_CAPACITY;
+    use proptest::{prelude::*, test_runner::TestCaseResult};
+    use std::borrow::Borrow;
+
+    /// Byte array type which can be used in tests
+    pub trait TestByteArrayType: ByteArrayTypeExt {
+        /// Owned version of `Native`, used for test value generation
+        type Owned: Arbitrary + Borrow<Self::Native> + Clone + Debug;
+
+        /// Checked subslicing of `Native`, which is used as a reference to
+        /// check the offset validation logic against
+        fn get(values: &Self::Native, range: Range<usize>) -> Option<&Self::Native>;
+    }
+    //
+    impl<O: OffsetSizeTrait> TestByteArrayType for GenericStringType<O> {
+        type Owned = String;
+
+        fn get(values: &str, range: Range<usize>) -> Option<&str> {
+            values.get(range)
+        }
+    }
+    //
+    impl<O: OffsetSizeTrait> TestByteArrayType for GenericBinaryType<O> {
+        type Owned = Vec<u8>;
+
+        fn get(values: &[u8], range: Range<usize>) -> Option<&[u8]> {
+            values.get(range)
+        }
+    }
+
+    /// Concatenate values into the building blocks of a [`ByteSlice`]
+    pub fn concat<A: ByteArrayTypeExt>(
+        values: &[impl Borrow<A::Native>],
+    ) -> (Vec<A::Offset>, Vec<u8>) {
+        let mut offsets = vec![A::Offset::usize_as(0)];
+        let mut bytes = Vec::new();
+        for value in values {
+            bytes.extend_from_slice(AsRef::<[u8]>::as_ref(value.borrow()));
+            offsets.push(A::Offset::from_usize(bytes.len()).unwrap());
+        }
+        (offsets, bytes)
+    }
+
+    /// Reinterpret the output of [`concat()`] as a `Native` value
+    pub fn concatenated<A: ByteArrayTypeExt>(bytes: &[u8]) -> &A::Native {
+        // SAFETY: Concatenating valid Native values yields a valid Native value
+        unsafe { A::from_bytes_unchecked(bytes) }
+    }
+
+    /// Generate values, along with offsets into their concatenation that may
+    /// or may not be valid
+    pub fn values_and_offsets<A: TestByteArrayType>(
+    ) -> impl Strategy<Value = (Vec<A::Owned>, Vec<A::Offset>)> {
+        any::<Vec<A::Owned>>().prop_flat_map(|values| {
+            let (offsets, bytes) = concat::<A>(&values);
+            let max_offset = bytes.len() as i64 + 1;
+            let num_offsets = 0..=MAX_CAPACITY;
+            let to_offsets = |offsets: Vec<i64>| {
+                offsets
+                    .into_iter()
+                    .map(|offset| match usize::try_from(offset) {
+                        Ok(offset) => A::Offset::usize_as(offset),
+                        Err(_) => A::Offset::usize_as(0) - A::Offset::usize_as(1),
+                    })
+                    .collect::<Vec<_>>()
+            };
+            (
+                Just(values),
+                prop_oneof![
+                    4 => Just(offsets),
+                    1 => prop::collection::vec(-1..=max_offset, num_offsets.clone())
+                        .prop_map(to_offsets),
+                    1 => prop::collection::vec(0..=max_offset, num_offsets).prop_map(
+                        move |mut offsets| {
+                            offsets.sort_unstable();
+                            to_offsets(offsets)
+                        }
+                    ),
+                ],
+            )
+        })
+    }
+
+    /// Truth that some offsets are valid for some concatenated values, as
+    /// determined independently of [`ByteSlice::validate()`]
+    pub fn offsets_are_valid<A: TestByteArrayType>(
+        values: &A::Native,
+        offsets: &[A::Offset],
+    ) -> bool {
+        let Some(offsets) = offsets
+            .iter()
+            .map(|offset| offset.to_usize())
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        offsets
+            .windows(2)
+            .all(|pair| A::get(values, pair[0]..pair[1]).is_some())
+            && offsets
+                .iter()
+                .all(|&offset| A::get(values, offset..offset).is_some())
+    }
+
+    /// Check the behavior of a valid `ByteSlice`
+    fn check_valid_slice<A: TestByteArrayType>(values: &[A::Owned]) -> TestCaseResult {
+        let (offsets, bytes) = concat::<A>(values);
+        let slice = ByteSlice::<A> {
+            offsets: &offsets,
+            values: concatenated::<A>(&bytes),
+        };
+        prop_assert!(slice.validate().is_ok());
+        prop_assert_eq!(slice.len(), values.len());
+        prop_assert_eq!(slice.is_empty(), values.is_empty());
+        for (index, value) in values.iter().enumerate() {
+            prop_assert_eq!(slice.get(index), Some(value.borrow()));
+        }
+        prop_assert_eq!(slice.get(values.len()), None);
+        prop_assert!(slice.iter().eq(values.iter().map(Borrow::borrow)));
+        prop_assert!(slice
+            .iter()
+            .rev()
+            .eq(values.iter().rev().map(Borrow::borrow)));
+        Ok(())
+    }
+
+    /// Check the offset validation logic of `ByteSlice`
+    fn check_validate<A: TestByteArrayType>(
+        values: &[A::Owned],
+        offsets: &[A::Offset],
+    ) -> TestCaseResult {
+        let (_, bytes) = concat::<A>(values);
+        let values = concatenated::<A>(&bytes);
+        let slice = ByteSlice::<A> { offsets, values };
+        prop_assert_eq!(
+            slice.validate().is_ok(),
+            offsets_are_valid::<A>(values, offsets)
+        );
+        Ok(())
+    }
+
+    /// Check the behavior of an empty `ByteSlice`
+    fn check_empty<A: ByteArrayTypeExt>()
+    where
+        for<'b> &'b A::Native: Default,
+    {
+        let slice = ByteSlice::<A>::default();
+        assert!(slice.validate().is_ok());
+        assert_eq!(slice.len(), 0);
+        assert!(slice.is_empty());
+        assert_eq!(slice.iter().next(), None);
+    }
+
+    macro_rules! test_byte_slices {
+        ($($mod_name:ident : $arrow:ty),*) => {
+            $(
+                mod $mod_name {
+                    use super::*;
+
+                    proptest! {
+                        #[test]
+                        fn valid_slice(values in any::<Vec<<$arrow as TestByteArrayType>::Owned>>()) {
+                            check_valid_slice::<$arrow>(&values)?;
+                        }
+
+                        #[test]
+                        fn validate((values, offsets) in values_and_offsets::<$arrow>()) {
+                            check_validate::<$arrow>(&values, &offsets)?;
+                        }
+                    }
+
+                    #[test]
+                    fn empty() {
+                        check_empty::<$arrow>();
+                    }
+                }
+            )*
+        };
+    }
+    //
+    test_byte_slices!(
+        utf8: Utf8Type,
+        binary: BinaryType,
+        large_utf8: LargeUtf8Type,
+        large_binary: LargeBinaryType
+    );
+}
@@ -0,0 +1,170 @@
+//! Strongly typed interface to arrow-rs' [`DataType::Decimal128`] and
+//! [`DataType::Decimal256`]
+//!
+//! Arrow decimals are stored as integers, which are implicitly scaled down by
+//! `10^scale`, and which have at most `precision` decimal digits. When these
+//! parameters are only known at runtime, the [`Decimal128`] and [`Decimal256`]
+//! element types can be used, with a [`DecimalConfig`] that specifies them.
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::decimal::{Decimal128, DecimalConfig}};
+//! // Prices in cents, up to 999 999.99
+//! let config = DecimalConfig::<Decimal128>::new(8, 2)?;
+//! let mut builder =
+//!     TypedBuilder::<Decimal128>::with_config(BuilderConfig::with_backend(None, config));
+//! builder.push(1999)?;
+//! builder.extend_from_slice(&[250, 99_999_999])?;
+//! assert!(builder.push(100_000_000).is_err());
+//! let array = builder.finish();
+//! assert_eq!(array.as_slice(), [1999, 250, 99_999_999]);
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Values that have more than `precision` digits are rejected, and bulk
+//! insertions that contain such values leave the builder untouched.
+//!
+//! When the precision and scale are known at compile time, the
+//! [`primitive::Decimal128`](super::primitive::Decimal128) and
+//! [`primitive::Decimal256`](super::primitive::Decimal256) types can be used
+//! instead, without any builder configuration.
+
+use crate::{builder::backend::DecimalBuilder, ArrayElement, OptionSlice};
+use arrow_array::types::{Decimal128Type, Decimal256Type, DecimalType};
+use arrow_buffer::i256;
+use arrow_schema::{ArrowError, DataType, DECIMAL_DEFAULT_SCALE};
+use std::{fmt::Debug, hash::Hash, marker::PhantomData};
+
+/// 128-bit decimal whose precision and scale are chosen at runtime
+///
+/// This marker element type is stored as [`DataType::Decimal128`] arrays, with
+/// a precision and scale that are provided through a [`DecimalConfig`]. Values
+/// are written and read as unscaled `i128`s, and have a precision of at most
+/// 38 digits.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Decimal128;
+
+/// 256-bit decimal whose precision and scale are chosen at runtime
+///
+/// This is the [`DataType::Decimal256`] counterpart of [`Decimal128`]. Values
+/// are written and read as unscaled [`i256`]s, and have a precision of at most
+/// 76 digits.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Decimal256;
+
+// Enable strongly typed arrays of decimals
+macro_rules! impl_decimal_element {
+    ($($element:ty => $arrow:ty, $native:ty);*) => {
+        $(
+            // SAFETY: Decimal markers are not primitive types and are therefore
+            //         not affected by the safety precondition of ArrayElement
+            unsafe impl ArrayElement for $element {
+                type BuilderBackend = DecimalBuilder<$element>;
+                type Value<'a> = $native;
+                type Slice<'a> = &'a [$native];
+                type PushResult = Result<(), ArrowError>;
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = false;
+            }
+            //
+            // SAFETY: Option is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl ArrayElement for Option<$element> {
+                type BuilderBackend = DecimalBuilder<$element>;
+                type Value<'a> = Option<$native>;
+                type Slice<'a> = OptionSlice<'a, $element>;
+                type PushResult = Result<(), ArrowError>;
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = true;
+            }
+            //
+            impl DecimalElement for $element {
+                type Arrow = $arrow;
+            }
+        )*
+    };
+}
+//
+impl_decimal_element!(
+    Decimal128 => Decimal128Type, i128;
+    Decimal256 => Decimal256Type, i256
+);
+
+/// Decimal element type whose precision and scale are chosen at runtime
+///
+/// This is implemented for [`Decimal128`] and [`Decimal256`].
+pub trait DecimalElement:
+    ArrayElement + Clone + Copy + Default + Eq + Hash + Ord + PartialEq + PartialOrd
+{
+    /// Equivalent Arrow decimal type
+    type Arrow: DecimalType + Debug;
+}
+
+/// Configuration of builders of [`Decimal128`] and [`Decimal256`] arrays
+///
+/// The default configuration is the same as that of arrow-rs' decimal
+/// builders, namely the maximal precision and a scale of 10.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DecimalConfig<T: DecimalElement> {
+    /// Maximal number of decimal digits
+    precision: u8,
+
+    /// Number of decimal digits after the decimal point
+    scale: i8,
+
+    /// Decimal element type
+    element: PhantomData<T>,
+}
+//
+impl<T: DecimalElement> DecimalConfig<T> {
+    /// Configure builders to accept decimals with at most `precision` digits,
+    /// `scale` of which are after the decimal point
+    ///
+    /// The precision must be nonzero and at most the maximal precision of
+    /// `T`, and the scale must not exceed the precision. Negative scales are
+    /// allowed, and mean that values are implicitly scaled up instead of down.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{ArrayElement, types::decimal::{Decimal256, DecimalConfig}};
+    /// use arrow_schema::DataType;
+    ///
+    /// let config = DecimalConfig::<Decimal256>::new(50, -3)?;
+    /// assert_eq!(Decimal256::data_type(&config), DataType::Decimal256(50, -3));
+    /// assert!(DecimalConfig::<Decimal256>::new(0, 0).is_err());
+    /// assert!(DecimalConfig::<Decimal256>::new(77, 0).is_err());
+    /// assert!(DecimalConfig::<Decimal256>::new(10, 11).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn new(precision: u8, scale: i8) -> Result<Self, ArrowError> {
+        arrow_array::types::validate_decimal_precision_and_scale::<T::Arrow>(precision, scale)?;
+        Ok(Self {
+            precision,
+            scale,
+            element: PhantomData,
+        })
+    }
+
+    /// Maximal number of decimal digits
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Number of decimal digits after the decimal point
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// Arrow data type of decimals with this precision and scale
+    pub(crate) fn data_type(&self) -> DataType {
+        (T::Arrow::TYPE_CONSTRUCTOR)(self.precision, self.scale)
+    }
+}
+//
+impl<T: DecimalElement> Default for DecimalConfig<T> {
+    fn default() -> Self {
+        Self {
+            precision: T::Arrow::MAX_PRECISION,
+            scale: DECIMAL_DEFAULT_SCALE,
+            element: PhantomData,
+        }
+    }
+}
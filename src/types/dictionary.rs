@@ -0,0 +1,262 @@
+//! Strongly typed interface to arrow-rs' [`DataType::Dictionary`]
+//!
+//! Columns with few distinct values, like country codes or status enums, can
+//! be stored as arrays of [`Dictionary<K, V>`]. Each distinct value of type `V`
+//! is only stored once in a dictionary, and the array itself only contains
+//! integer keys of type `K` that point into this dictionary.
+//!
+//! Dictionary arrays are built from plain values, which the builder
+//! automatically deduplicates, and are read out as decoded values.
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::dictionary::Dictionary};
+//! let mut builder = TypedBuilder::<Dictionary<u8, String>>::new();
+//! for country in ["FR", "DE", "FR", "FR", "DE"] {
+//!     builder.push(country)?;
+//! }
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 5);
+//! assert_eq!(array.value(2), "FR");
+//! assert!(array.iter().eq(["FR", "DE", "FR", "FR", "DE"]));
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Bulk readout gives access to the underlying keys and dictionary values
+//! through a [`DictionaryArraySlice`].
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::dictionary::Dictionary};
+//! let mut builder = TypedBuilder::<Option<Dictionary<i32, f32>>>::new();
+//! builder.extend_from_value_slice(&[0.5, 1.5, 0.5])?;
+//! builder.push(None)?;
+//! let array = builder.finish();
+//! let slice = array.as_slice();
+//! assert_eq!(slice.values.keys()[..3], [0, 1, 0]);
+//! assert_eq!(slice.values.values(), [0.5, 1.5]);
+//! assert_eq!(slice.values.get(2), Some(0.5));
+//! assert_eq!(slice.is_valid.map(|is_valid| is_valid.at(3)), Some(false));
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Inserting values fails if the dictionary would then contain more distinct
+//! values than `K` can index. The builder is left untouched when this happens,
+//! even for bulk insertions, but the values are not inserted either.
+//! Therefore, `K` should be chosen large enough to index all distinct values.
+
+use crate::{
+    array::{backend::TypedBackend, ArrayBackend, ArraySlice, ArrayValue},
+    builder::backend::{ArrowDictionaryBuilder, ByteDictionaryBuilder, DictionaryBuilder},
+    types::{
+        bytes::{ByteType, LargeBinary, LargeUtf8},
+        primitive::{NativeType, PrimitiveType},
+    },
+    ArrayElement, OptionSlice,
+};
+use arrow_array::{builder::PrimitiveDictionaryBuilder, types::ArrowDictionaryKeyType, Array};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+/// Dictionary-encoded values of type `V`, indexed by keys of type `K`
+///
+/// This marker element type is stored as [`DataType::Dictionary`] arrays. It
+/// is written and read out like `V`, but deduplicates values behind the scenes.
+/// See the [module-level documentation](self) for more information.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, types::dictionary::Dictionary};
+/// use arrow_schema::DataType;
+///
+/// assert_eq!(
+///     Dictionary::<i16, str>::data_type(&()),
+///     DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8))
+/// );
+/// ```
+pub struct Dictionary<K: DictionaryKey, V: DictionaryValue + ?Sized>(
+    PhantomData<K>,
+    PhantomData<V>,
+);
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> Debug for Dictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Dictionary<{}, {}>",
+            std::any::type_name::<K>(),
+            std::any::type_name::<V>()
+        )
+    }
+}
+//
+// SAFETY: Dictionary is not a primitive type and is therefore not affected by
+//         the safety precondition of ArrayElement
+unsafe impl<K: DictionaryKey, V: DictionaryValue + ?Sized> ArrayElement for Dictionary<K, V> {
+    type BuilderBackend = DictionaryBuilder<K, V>;
+    type Value<'a> = V::Value<'a>;
+    type Slice<'a> = V::Slice<'a>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<K: DictionaryKey, V: DictionaryValue + ?Sized> ArrayElement
+    for Option<Dictionary<K, V>>
+{
+    type BuilderBackend = DictionaryBuilder<K, V>;
+    type Value<'a> = Option<V::Value<'a>>;
+    type Slice<'a> = OptionSlice<'a, Dictionary<K, V>>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Integer type that can be used as the keys of a [`Dictionary`]
+///
+/// This is implemented for all signed and unsigned integer types.
+pub trait DictionaryKey: PrimitiveType<Arrow: ArrowDictionaryKeyType> + Copy {}
+//
+impl<T: PrimitiveType<Arrow: ArrowDictionaryKeyType> + Copy> DictionaryKey for T {}
+
+/// Element type that can be used as the values of a [`Dictionary`]
+///
+/// This is implemented for [primitive types](PrimitiveType) and for
+/// variable-length strings and binary data.
+pub trait DictionaryValue: ArrayElement {
+    /// Arrow builder of dictionary arrays with values of this type
+    #[doc(hidden)]
+    type Builder<K: ArrowDictionaryKeyType>: ArrowDictionaryBuilder<K, Self>;
+}
+//
+impl<T: PrimitiveType> DictionaryValue for T
+where
+    // FIXME: Remove this bound once the Rust trait system supports adding the
+    //        appropriate bounds on PrimitiveType to let rustc figure out that
+    //        T::Value<'_> is just T for primitive types.
+    for<'a> T::Value<'a>: Into<NativeType<T>> + Copy,
+{
+    type Builder<K: ArrowDictionaryKeyType> = PrimitiveDictionaryBuilder<K, T::Arrow>;
+}
+//
+macro_rules! impl_byte_dictionary_value {
+    ($($element:ty),*) => {
+        $(
+            impl DictionaryValue for $element {
+                type Builder<K: ArrowDictionaryKeyType> =
+                    ByteDictionaryBuilder<K, <$element as ByteType>::Arrow>;
+            }
+        )*
+    };
+}
+//
+impl_byte_dictionary_value!(str, String, [u8], Vec<u8>, LargeUtf8, LargeBinary);
+
+/// Bulk readout of an array of [`Dictionary<K, V>`]
+///
+/// This gives access to the keys of the array and to the dictionary values
+/// that they point to, and can also be used to decode individual values.
+pub struct DictionaryArraySlice<'a, K: DictionaryKey, V: DictionaryValue + ?Sized>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    /// Index of each element's value within `values`
+    keys: &'a [K],
+
+    /// Array of dictionary values
+    values: &'a ArrayBackend<V>,
+}
+//
+impl<'a, K: DictionaryKey, V: DictionaryValue + ?Sized> DictionaryArraySlice<'a, K, V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    /// Wrap the keys and dictionary values of a valid dictionary array
+    pub(crate) fn new(keys: &'a [NativeType<K>], values: &'a ArrayBackend<V>) -> Self {
+        // SAFETY: Primitive types are repr(transparent) wrappers over the
+        //         corresponding Arrow native types, so it is safe to
+        //         reinterpret &[NativeType<K>] as &[K].
+        let keys = unsafe { std::slice::from_raw_parts(keys.as_ptr().cast::<K>(), keys.len()) };
+        Self { keys, values }
+    }
+
+    /// Number of elements in the slice
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Truth that the slice contains no element
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Index of each element's value within [`values()`](Self::values)
+    ///
+    /// The keys of null elements are unspecified.
+    pub fn keys(&self) -> &'a [K] {
+        self.keys
+    }
+
+    /// Distinct values of the dictionary
+    pub fn values(&self) -> ArraySlice<'a, V> {
+        self.values.as_slice()
+    }
+
+    /// Decoded value of the `index`-th element, if in bounds
+    ///
+    /// # Panics
+    ///
+    /// May panic if the `index`-th element is null, as the keys of null
+    /// elements do not need to point to a dictionary value.
+    pub fn get(&self, index: usize) -> Option<ArrayValue<'a, V>> {
+        let key: NativeType<K> = (*self.keys.get(index)?).into();
+        let key = key.as_usize();
+        assert!(key < self.values.len(), "dictionary key is out of bounds");
+        // SAFETY: Key is in bounds per the above check
+        Some(unsafe { self.values.value_unchecked(key) })
+    }
+
+    /// Iterate over the decoded values of the slice
+    ///
+    /// # Panics
+    ///
+    /// Iteration may panic if the slice contains null elements, see
+    /// [`get()`](Self::get).
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = ArrayValue<'a, V>> + ExactSizeIterator + 'a {
+        let slice = *self;
+        (0..self.len()).map(move |index| slice.get(index).expect("index is in bounds"))
+    }
+}
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> Clone for DictionaryArraySlice<'_, K, V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> Copy for DictionaryArraySlice<'_, K, V> where
+    ArrayBackend<V>: TypedBackend<V>
+{
+}
+//
+impl<K: DictionaryKey, V: DictionaryValue + ?Sized> Debug for DictionaryArraySlice<'_, K, V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DictionaryArraySlice")
+            .field("keys", &self.keys)
+            .field("values", &self.values())
+            .finish()
+    }
+}
@@ -0,0 +1,309 @@
+//! Strongly typed interface to arrow-rs' [`DataType::FixedSizeBinary`]
+//!
+//! When the byte width `N` is known at compile time, byte arrays `[u8; N]` can
+//! be used as array elements. They are written and read by value, and
+//! bulk-manipulated as `&[[u8; N]]` slices.
+//!
+//! ```rust
+//! # use arrow_typing::TypedBuilder;
+//! let mut builder = TypedBuilder::<[u8; 4]>::new();
+//! builder.push(*b"abcd");
+//! builder.extend_from_slice(&[*b"efgh", *b"ijkl"]);
+//! let array = builder.finish();
+//! assert_eq!(array.value(1), *b"efgh");
+//! assert_eq!(array.as_slice(), &[*b"abcd", *b"efgh", *b"ijkl"]);
+//! ```
+//!
+//! When the byte width is only known at runtime, the [`FixedSizeBinary`]
+//! element type must be used instead.
+
+use crate::{builder::backend::FixedBinaryBuilder, ArrayElement, ElementSlice, OptionSlice};
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::{iter::FusedIterator, ops::Range, slice::ChunksExact};
+
+// === Compile-time byte width ===
+
+// SAFETY: [u8; N] is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<const N: usize> ArrayElement for [u8; N] {
+    type BuilderBackend = FixedBinaryBuilder;
+    type Value<'a> = Self;
+    type Slice<'a> = &'a [Self];
+    type PushResult = ();
+    type ExtendFromSliceResult = ();
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<const N: usize> ArrayElement for Option<[u8; N]> {
+    type BuilderBackend = FixedBinaryBuilder;
+    type Value<'a> = Self;
+    type Slice<'a> = OptionSlice<'a, [u8; N]>;
+    type PushResult = ();
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Arrow byte width of `[u8; N]`
+///
+/// Arrow byte widths are stored as positive `i32`s, so using `[u8; N]` with
+/// `N == 0` or `N > i32::MAX` is a compile-time error.
+pub(crate) struct ByteWidth<const N: usize>;
+//
+impl<const N: usize> ByteWidth<N> {
+    /// Byte width, in the format expected by arrow-rs
+    pub(crate) const I32: i32 = {
+        assert!(
+            N > 0 && N <= i32::MAX as usize,
+            "arrow fixed-size binary byte widths must be in the 1..=i32::MAX range"
+        );
+        N as i32
+    };
+}
+
+// === Runtime byte width ===
+
+/// Binary data whose byte width is chosen at runtime
+///
+/// This marker element type is stored as [`DataType::FixedSizeBinary`] arrays,
+/// like `[u8; N]`, but the byte width is provided at runtime through a
+/// [`FixedSizeBinaryConfig`]. Values are written and read as `&[u8]`, and
+/// writing a value of the wrong length is an error.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig}};
+/// let config = FixedSizeBinaryConfig::new(3)?;
+/// let mut builder =
+///     TypedBuilder::<FixedSizeBinary>::with_config(BuilderConfig::with_backend(None, config));
+/// builder.push(b"abc")?;
+/// assert!(builder.push(b"abcd").is_err());
+/// assert_eq!(builder.finish().value(0), b"abc");
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FixedSizeBinary;
+//
+// SAFETY: FixedSizeBinary is not a primitive type and is therefore not affected
+//         by the safety precondition of ArrayElement
+unsafe impl ArrayElement for FixedSizeBinary {
+    type BuilderBackend = FixedBinaryBuilder;
+    type Value<'a> = &'a [u8];
+    type Slice<'a> = FixedSizeBinarySlice<'a>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl ArrayElement for Option<FixedSizeBinary> {
+    type BuilderBackend = FixedBinaryBuilder;
+    type Value<'a> = Option<&'a [u8]>;
+    type Slice<'a> = OptionSlice<'a, FixedSizeBinary>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Configuration of builders of [`FixedSizeBinary`] arrays
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FixedSizeBinaryConfig {
+    /// Number of bytes per value, in the format expected by arrow-rs
+    byte_width: i32,
+}
+//
+impl FixedSizeBinaryConfig {
+    /// Configure builders to accept values of `byte_width` bytes
+    ///
+    /// Arrow byte widths are stored as positive `i32`s, so `byte_width` must
+    /// be in the `1..=i32::MAX` range.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{ArrayElement, types::fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig}};
+    /// use arrow_schema::DataType;
+    ///
+    /// let config = FixedSizeBinaryConfig::new(16)?;
+    /// assert_eq!(FixedSizeBinary::data_type(&config), DataType::FixedSizeBinary(16));
+    /// assert!(FixedSizeBinaryConfig::new(0).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn new(byte_width: usize) -> Result<Self, ArrowError> {
+        match i32::try_from(byte_width) {
+            Ok(byte_width) if byte_width > 0 => Ok(Self { byte_width }),
+            _ => Err(ArrowError::InvalidArgumentError(format!(
+                "fixed-size binary byte width must be in the 1..=i32::MAX range, got {byte_width}"
+            ))),
+        }
+    }
+
+    /// Number of bytes per value
+    pub fn byte_width(&self) -> usize {
+        self.byte_width as usize
+    }
+
+    /// Number of bytes per value, in the format expected by arrow-rs
+    pub(crate) fn byte_width_i32(&self) -> i32 {
+        self.byte_width
+    }
+}
+
+/// Columnar slice of [`FixedSizeBinary`] values
+///
+/// Values are stored back to back in a single byte slice, whose length must be
+/// a multiple of the byte width.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig, FixedSizeBinarySlice}};
+/// let config = FixedSizeBinaryConfig::new(2)?;
+/// let mut builder =
+///     TypedBuilder::<FixedSizeBinary>::with_config(BuilderConfig::with_backend(None, config));
+/// builder.extend_from_slice(FixedSizeBinarySlice::new(2, b"abcdef")?)?;
+/// let array = builder.finish();
+/// assert_eq!(array.as_slice().get(1), Some(&b"cd"[..]));
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FixedSizeBinarySlice<'a> {
+    /// Number of bytes per value (always nonzero)
+    byte_width: usize,
+
+    /// Concatenated values
+    values: &'a [u8],
+}
+//
+impl<'a> FixedSizeBinarySlice<'a> {
+    /// Interpret `values` as a sequence of values of `byte_width` bytes
+    ///
+    /// This fails if `byte_width` is zero or does not divide the length of
+    /// `values`.
+    pub fn new(byte_width: usize, values: &'a [u8]) -> Result<Self, ArrowError> {
+        if byte_width == 0 || !values.len().is_multiple_of(byte_width) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot split {} bytes into values of {byte_width} bytes",
+                values.len()
+            )));
+        }
+        Ok(Self { byte_width, values })
+    }
+
+    /// Like `new()`, but without validation
+    ///
+    /// The caller must ensure that `byte_width` is nonzero and divides the
+    /// length of `values`, otherwise the resulting slice will misbehave.
+    pub(crate) fn new_unchecked(byte_width: usize, values: &'a [u8]) -> Self {
+        debug_assert!(byte_width != 0 && values.len().is_multiple_of(byte_width));
+        Self { byte_width, values }
+    }
+
+    /// Number of bytes per value
+    pub fn byte_width(&self) -> usize {
+        self.byte_width
+    }
+
+    /// Concatenated values
+    pub fn values(&self) -> &'a [u8] {
+        self.values
+    }
+
+    /// Number of values in this slice
+    pub fn len(&self) -> usize {
+        self.values.len() / self.byte_width
+    }
+
+    /// Truth that this slice contains no value
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Value at position `index`, if any
+    pub fn get(&self, index: usize) -> Option<&'a [u8]> {
+        let start = index.checked_mul(self.byte_width)?;
+        self.values.get(start..start.checked_add(self.byte_width)?)
+    }
+
+    /// Iterate over the values
+    pub fn iter(&self) -> FixedSizeBinaryIter<'a> {
+        FixedSizeBinaryIter(self.values.chunks_exact(self.byte_width))
+    }
+}
+//
+impl ElementSlice for FixedSizeBinarySlice<'_> {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        Ok(self.len())
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        let values = &self.values[range.start * self.byte_width..range.end * self.byte_width];
+        Self::new_unchecked(self.byte_width, values)
+    }
+}
+//
+impl<'a> IntoIterator for FixedSizeBinarySlice<'a> {
+    type Item = &'a [u8];
+    type IntoIter = FixedSizeBinaryIter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the values of a [`FixedSizeBinarySlice`]
+#[derive(Clone, Debug)]
+pub struct FixedSizeBinaryIter<'a>(ChunksExact<'a, u8>);
+//
+impl<'a> Iterator for FixedSizeBinaryIter<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+//
+impl DoubleEndedIterator for FixedSizeBinaryIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+//
+impl ExactSizeIterator for FixedSizeBinaryIter<'_> {}
+//
+impl FusedIterator for FixedSizeBinaryIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn slice(byte_width in 0..=8usize, values: Vec<u8>) {
+            let result = FixedSizeBinarySlice::new(byte_width, &values);
+            if byte_width == 0 || !values.len().is_multiple_of(byte_width) {
+                prop_assert!(result.is_err());
+                return Ok(());
+            }
+            let slice = result?;
+            prop_assert_eq!(slice.byte_width(), byte_width);
+            prop_assert_eq!(slice.values(), &values[..]);
+            prop_assert_eq!(slice.len(), values.len() / byte_width);
+            prop_assert_eq!(slice.is_empty(), values.is_empty());
+            let expected = values.chunks(byte_width).collect::<Vec<_>>();
+            for (idx, expected) in expected.iter().enumerate() {
+                prop_assert_eq!(slice.get(idx), Some(*expected));
+            }
+            prop_assert_eq!(slice.get(slice.len()), None);
+            prop_assert_eq!(slice.iter().len(), slice.len());
+            prop_assert!(slice.iter().eq(expected.iter().copied()));
+            prop_assert!(slice.iter().rev().eq(expected.iter().rev().copied()));
+        }
+    }
+}
@@ -0,0 +1,515 @@
+//! Strongly typed interface to arrow-rs' [`DataType::FixedSizeList`]
+//!
+//! When the list size `N` is known at compile time, arrays `[T; N]` of
+//! [primitive types](FixedSizeListItem) can be used as array elements. They
+//! are written and read by value, and bulk-manipulated as `&[[T; N]]` slices,
+//! which have the same memory layout as the list items of arrow's fixed-size
+//! lists. This makes bulk insertion and readout a simple memory copy.
+//!
+//! ```rust
+//! # use arrow_typing::TypedBuilder;
+//! // Vertices of a triangle
+//! let mut builder = TypedBuilder::<[f32; 3]>::new();
+//! builder.push([0.0, 0.0, 0.0]);
+//! builder.extend_from_slice(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+//! let array = builder.finish();
+//! assert_eq!(array.value(1), [1.0, 0.0, 0.0]);
+//! assert_eq!(
+//!     array.as_slice(),
+//!     &[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+//! );
+//! ```
+//!
+//! Note that byte arrays `[u8; N]` are stored as
+//! [fixed-size binary](super::fixed_size_binary) data instead.
+//!
+//! When the list size is only known at runtime, or when the list items are not
+//! primitive types, the [`FixedSizeList`] element type must be used instead.
+
+use super::primitive::{
+    Date32, Date64, Decimal128, Decimal256, Duration, IntervalDayTime, IntervalMonthDayNano,
+    IntervalYearMonth, Microsecond, Millisecond, Nanosecond, PrimitiveType, Second, Time, TimeUnit,
+    TimeZone, Timestamp,
+};
+use crate::{
+    array::{backend::TypedBackend, ArrayBackend, ArraySlice},
+    builder::{backend::FixedListBuilder, BackendConfig},
+    ArrayElement, ElementSlice, OptionSlice,
+};
+use arrow_array::Array;
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use half::f16;
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Range,
+};
+
+// === Compile-time list size ===
+
+/// Primitive type that can be used as the item type of `[T; N]` lists
+///
+/// This trait is implemented for every [`PrimitiveType`] except for `u8`,
+/// because `[u8; N]` is stored as [fixed-size binary](super::fixed_size_binary)
+/// data instead of fixed-size lists of bytes.
+pub trait FixedSizeListItem: PrimitiveType + Copy {}
+//
+macro_rules! impl_fixed_size_list_item {
+    ($($item:ty),*) => {
+        $(
+            impl FixedSizeListItem for $item {}
+        )*
+    };
+}
+//
+impl_fixed_size_list_item!(
+    Date32,
+    Date64,
+    Duration<Microsecond>,
+    Duration<Millisecond>,
+    Duration<Nanosecond>,
+    Duration<Second>,
+    f16,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    IntervalDayTime,
+    IntervalMonthDayNano,
+    IntervalYearMonth,
+    Time<Millisecond>,
+    Time<Second>,
+    Time<Microsecond>,
+    Time<Nanosecond>,
+    u16,
+    u32,
+    u64
+);
+//
+impl<const P: u8, const S: i8> FixedSizeListItem for Decimal128<P, S> {}
+//
+impl<const P: u8, const S: i8> FixedSizeListItem for Decimal256<P, S> {}
+//
+impl<Unit: TimeUnit, Tz: TimeZone> FixedSizeListItem for Timestamp<Unit, Tz> where Self: Copy {}
+
+// SAFETY: [T; N] is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<T: FixedSizeListItem, const N: usize> ArrayElement for [T; N] {
+    type BuilderBackend = FixedListBuilder<T>;
+    type Value<'a> = Self;
+    type Slice<'a> = &'a [Self];
+    type PushResult = ();
+    type ExtendFromSliceResult = ();
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<T: FixedSizeListItem, const N: usize> ArrayElement for Option<[T; N]> {
+    type BuilderBackend = FixedListBuilder<T>;
+    type Value<'a> = Self;
+    type Slice<'a> = OptionSlice<'a, [T; N]>;
+    type PushResult = ();
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Arrow list size of `[T; N]`
+///
+/// Arrow list sizes are stored as `i32`s, and arrow cannot tell how many lists
+/// an array of empty lists contains, so using `[T; N]` with `N == 0` or
+/// `N > i32::MAX` is a compile-time error.
+pub(crate) struct ListSize<const N: usize>;
+//
+impl<const N: usize> ListSize<N> {
+    /// List size, in the format expected by arrow-rs
+    pub(crate) const I32: i32 = {
+        assert!(
+            N > 0 && N <= i32::MAX as usize,
+            "arrow fixed-size list sizes must be in the 1..=i32::MAX range"
+        );
+        N as i32
+    };
+}
+
+// === Runtime list size ===
+
+/// Fixed-size list of values of type `T`, whose size is chosen at runtime
+///
+/// This marker element type is stored as [`DataType::FixedSizeList`] arrays,
+/// like `[T; N]`, but the list size is provided at runtime through a
+/// [`FixedSizeListConfig`], and the items can be of any [`ArrayElement`] type.
+/// Lists are written as [`T::Slice`](ArrayElement::Slice)s, and writing a list
+/// of the wrong length is an error.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::fixed_size_list::{FixedSizeList, FixedSizeListConfig}};
+/// // Embedding dimension from a model configuration file
+/// let dimension = 4;
+/// let config = FixedSizeListConfig::<f32>::new(dimension)?;
+/// let mut builder =
+///     TypedBuilder::<FixedSizeList<f32>>::with_config(BuilderConfig::with_backend(None, config));
+/// builder.push(&[0.1, 0.2, 0.3, 0.4])?;
+/// assert!(builder.push(&[0.1, 0.2, 0.3]).is_err());
+/// let array = builder.finish();
+/// assert_eq!(array.len(), 1);
+/// assert_eq!(array.value(0), &[0.1, 0.2, 0.3, 0.4]);
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+///
+/// Like other list types, fixed-size lists are read out using the [bulk
+/// readout layout](crate::TypedArray::as_slice) of their items.
+pub struct FixedSizeList<T: ArrayElement + ?Sized>(PhantomData<T>);
+//
+impl<T: ArrayElement + ?Sized> Debug for FixedSizeList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FixedSizeList<{}>", std::any::type_name::<T>())
+    }
+}
+//
+// SAFETY: FixedSizeList is not a primitive type and is therefore not affected
+//         by the safety precondition of ArrayElement
+unsafe impl<T: ArrayElement + ?Sized> ArrayElement for FixedSizeList<T> {
+    type BuilderBackend = FixedListBuilder<T>;
+    type Value<'a> = T::Slice<'a>;
+    type Slice<'a> = FixedSizeListSlice<'a, T>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<T: ArrayElement + ?Sized> ArrayElement for Option<FixedSizeList<T>> {
+    type BuilderBackend = FixedListBuilder<T>;
+    type Value<'a> = Option<T::Slice<'a>>;
+    type Slice<'a> = OptionSlice<'a, FixedSizeList<T>>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Configuration of builders of [`FixedSizeList`] arrays
+pub struct FixedSizeListConfig<T: ArrayElement + ?Sized> {
+    /// Number of items per list, in the format expected by arrow-rs
+    list_size: i32,
+
+    /// Backend configuration of the list items
+    items: BackendConfig<T>,
+}
+//
+impl<T: ArrayElement + ?Sized> FixedSizeListConfig<T> {
+    /// Configure builders to accept lists of `list_size` items
+    ///
+    /// This constructor is available for item types which can be built
+    /// without any extra configuration. Other item types must use
+    /// [`with_items()`](Self::with_items).
+    ///
+    /// Arrow list sizes are stored as positive `i32`s, so `list_size` must be
+    /// in the `1..=i32::MAX` range.
+    ///
+    /// ```rust
+    /// # use arrow_typing::{ArrayElement, types::fixed_size_list::{FixedSizeList, FixedSizeListConfig}};
+    /// use arrow_schema::{DataType, Field};
+    ///
+    /// let config = FixedSizeListConfig::<f32>::new(768)?;
+    /// assert_eq!(
+    ///     FixedSizeList::<f32>::data_type(&config),
+    ///     DataType::FixedSizeList(Field::new("item", DataType::Float32, false).into(), 768)
+    /// );
+    /// assert!(FixedSizeListConfig::<f32>::new(0).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn new(list_size: usize) -> Result<Self, ArrowError>
+    where
+        BackendConfig<T>: Default,
+    {
+        Self::with_items(list_size, Default::default())
+    }
+
+    /// Configure builders to accept lists of `list_size` items, which are
+    /// built using the specified backend configuration
+    ///
+    /// ```rust
+    /// # use arrow_typing::types::{fixed_size_binary::{FixedSizeBinary, FixedSizeBinaryConfig}, fixed_size_list::FixedSizeListConfig};
+    /// let items = FixedSizeBinaryConfig::new(16)?;
+    /// let config = FixedSizeListConfig::<FixedSizeBinary>::with_items(2, items)?;
+    /// assert_eq!(config.list_size(), 2);
+    /// assert_eq!(config.items(), &items);
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn with_items(list_size: usize, items: BackendConfig<T>) -> Result<Self, ArrowError> {
+        match i32::try_from(list_size) {
+            Ok(list_size) if list_size > 0 => Ok(Self { list_size, items }),
+            _ => Err(ArrowError::InvalidArgumentError(format!(
+                "fixed-size list size must be in the 1..=i32::MAX range, got {list_size}"
+            ))),
+        }
+    }
+
+    /// Number of items per list
+    pub fn list_size(&self) -> usize {
+        self.list_size as usize
+    }
+
+    /// Number of items per list, in the format expected by arrow-rs
+    pub(crate) fn list_size_i32(&self) -> i32 {
+        self.list_size
+    }
+
+    /// Backend configuration of the list items
+    pub fn items(&self) -> &BackendConfig<T> {
+        &self.items
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Clone for FixedSizeListConfig<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list_size: self.list_size,
+            items: self.items.clone(),
+        }
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Debug for FixedSizeListConfig<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedSizeListConfig")
+            .field("list_size", &self.list_size)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> PartialEq for FixedSizeListConfig<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.list_size == other.list_size && self.items == other.items
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Eq for FixedSizeListConfig<T> {}
+
+/// Columnar slice of [`FixedSizeList`] values
+///
+/// Lists are stored back to back as a single slice of items, whose length
+/// must be a multiple of the list size.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, builder::BuilderConfig, types::fixed_size_list::{FixedSizeList, FixedSizeListConfig, FixedSizeListSlice}};
+/// let config = FixedSizeListConfig::<u16>::new(2)?;
+/// let mut builder =
+///     TypedBuilder::<FixedSizeList<u16>>::with_config(BuilderConfig::with_backend(None, config));
+/// builder.extend_from_slice(FixedSizeListSlice::new(2, &[1, 2, 3, 4, 5, 6][..])?)?;
+/// let array = builder.finish();
+/// assert_eq!(array.as_slice().get(1), Some(&[3, 4][..]));
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct FixedSizeListSlice<'a, T: ArrayElement + ?Sized> {
+    /// Number of items per list (always nonzero)
+    list_size: usize,
+
+    /// Number of lists
+    len: usize,
+
+    /// Concatenated list items
+    values: T::Slice<'a>,
+}
+//
+impl<'a, T: ArrayElement + ?Sized> FixedSizeListSlice<'a, T> {
+    /// Interpret `values` as a sequence of lists of `list_size` items
+    ///
+    /// This fails if `list_size` is zero or does not divide the length of
+    /// `values`, or if the inner slices of `values` have inconsistent lengths.
+    pub fn new(list_size: usize, values: T::Slice<'a>) -> Result<Self, ArrowError> {
+        let num_items = values.slice_len()?;
+        if list_size == 0 || !num_items.is_multiple_of(list_size) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot split {num_items} items into lists of {list_size} items"
+            )));
+        }
+        Ok(Self {
+            list_size,
+            len: num_items / list_size,
+            values,
+        })
+    }
+
+    /// Number of items per list
+    pub fn list_size(&self) -> usize {
+        self.list_size
+    }
+
+    /// Concatenated list items
+    pub fn values(&self) -> T::Slice<'a> {
+        self.values.clone()
+    }
+
+    /// Number of lists in this slice
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Truth that this slice contains no list
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Items of the `index`-th list, if in bounds
+    pub fn get(&self, index: usize) -> Option<T::Slice<'a>> {
+        (index < self.len).then(|| {
+            let start = index * self.list_size;
+            self.values
+                .clone()
+                .slice_range(start..start + self.list_size)
+        })
+    }
+
+    /// Iterate over the lists of the slice
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T::Slice<'a>> + ExactSizeIterator + '_ {
+        (0..self.len).map(|index| self.get(index).expect("index is in bounds"))
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Clone for FixedSizeListSlice<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            list_size: self.list_size,
+            len: self.len,
+            values: self.values.clone(),
+        }
+    }
+}
+//
+impl<'a, T: ArrayElement + ?Sized> Copy for FixedSizeListSlice<'a, T> where T::Slice<'a>: Copy {}
+//
+impl<T: ArrayElement + ?Sized> Debug for FixedSizeListSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedSizeListSlice")
+            .field("list_size", &self.list_size)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> ElementSlice for FixedSizeListSlice<'_, T> {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        Ok(self.len)
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range is out of bounds"
+        );
+        Self {
+            list_size: self.list_size,
+            len: range.len(),
+            values: self
+                .values
+                .slice_range(range.start * self.list_size..range.end * self.list_size),
+        }
+    }
+}
+
+/// Bulk readout of a [`FixedSizeList`] array
+///
+/// This uses the same layout as [`FixedSizeListSlice`], but list items are
+/// read out using the bulk readout layout of `T`, as returned by
+/// [`TypedArray::as_slice()`](crate::TypedArray::as_slice).
+pub struct FixedSizeListArraySlice<'a, T: ArrayElement + ?Sized>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    /// Number of items per list (always nonzero)
+    list_size: usize,
+
+    /// Index of the first list within `items`
+    start: usize,
+
+    /// Number of lists
+    len: usize,
+
+    /// Array of concatenated list items
+    items: &'a ArrayBackend<T>,
+}
+//
+impl<'a, T: ArrayElement + ?Sized> FixedSizeListArraySlice<'a, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    /// Wrap the lists within `range` of a valid fixed-size list array
+    pub(crate) fn new(list_size: usize, range: Range<usize>, items: &'a ArrayBackend<T>) -> Self {
+        debug_assert!(list_size > 0 && range.end * list_size <= items.len());
+        Self {
+            list_size,
+            start: range.start,
+            len: range.len(),
+            items,
+        }
+    }
+
+    /// Number of items per list
+    pub fn list_size(&self) -> usize {
+        self.list_size
+    }
+
+    /// Number of lists in the slice
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Truth that the slice contains no list
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Concatenated list items
+    pub fn values(&self) -> ArraySlice<'a, T> {
+        let start = self.start * self.list_size;
+        self.items
+            .as_subslice(start..start + self.len * self.list_size)
+    }
+
+    /// Items of the `index`-th list, if in bounds
+    pub fn get(&self, index: usize) -> Option<ArraySlice<'a, T>> {
+        (index < self.len).then(|| {
+            let start = (self.start + index) * self.list_size;
+            self.items.as_subslice(start..start + self.list_size)
+        })
+    }
+
+    /// Iterate over the lists of the slice
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = ArraySlice<'a, T>> + ExactSizeIterator + 'a {
+        let slice = *self;
+        (0..self.len).map(move |index| slice.get(index).expect("index is in bounds"))
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Clone for FixedSizeListArraySlice<'_, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+//
+impl<T: ArrayElement + ?Sized> Copy for FixedSizeListArraySlice<'_, T> where
+    ArrayBackend<T>: TypedBackend<T>
+{
+}
+//
+impl<T: ArrayElement + ?Sized> Debug for FixedSizeListArraySlice<'_, T>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
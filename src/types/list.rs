@@ -0,0 +1,393 @@
+//! Strongly typed interface to arrow-rs' [`DataType::List`] and
+//! [`DataType::LargeList`]
+//!
+//! Lists of any [`ArrayElement`] type `T` can be stored as arrays of
+//! [`List<T>`]. Each list is written as a [`T::Slice`](ArrayElement::Slice),
+//! and lists are bulk-inserted using the same offsets + flattened values layout
+//! as arrow, which makes ragged per-row data easy to build.
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::list::{List, ListSlice}};
+//! // Token ids of three documents, the second of which is empty
+//! let mut builder = TypedBuilder::<List<u32>>::new();
+//! builder.push(&[101, 2054, 102]);
+//! builder.extend_from_slice(ListSlice {
+//!     offsets: &[0, 0, 2],
+//!     values: &[101, 102],
+//! })?;
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(array.value(0), &[101, 2054, 102]);
+//! assert_eq!(array.value(1), &[]);
+//! assert_eq!(array.value(2), &[101, 102]);
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! [`LargeList<T>`] provides the same API with 64-bit offsets, for lists
+//! whose items are too numerous to be indexed by `i32` offsets.
+//!
+//! Lists are read out using the [bulk readout
+//! layout](crate::TypedArray::as_slice) of their items. This is the same as
+//! `T::Slice` for primitive types, but may differ for other types: for
+//! example, lists of `bool` or `Option<T>` are read out using bitmaps.
+
+use crate::{
+    array::{backend::TypedBackend, ArrayBackend, ArraySlice},
+    builder::backend::ListBuilder,
+    ArrayElement, ElementSlice, OptionSlice,
+};
+use arrow_array::OffsetSizeTrait;
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Range,
+};
+
+/// List of values of type `T`
+///
+/// This marker element type is stored as [`DataType::List`] arrays, whose items
+/// are arrays of `T`. See the [module-level documentation](self) for more
+/// information.
+///
+/// Lists can be nested and made nullable at any level, as in
+/// `Option<List<List<Option<T>>>>`.
+///
+/// ```rust
+/// # use arrow_typing::{TypedBuilder, types::list::List};
+/// let mut builder = TypedBuilder::<Option<List<String>>>::new();
+/// builder.push(None)?;
+/// builder.push(Some(Default::default()))?;
+/// let array = builder.finish();
+/// assert!(array.value(0).is_none());
+/// assert!(array.value(1).is_some_and(|items| items.is_empty()));
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct List<T: ArrayElement + ?Sized>(PhantomData<T>);
+//
+impl<T: ArrayElement + ?Sized> Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "List<{}>", std::any::type_name::<T>())
+    }
+}
+
+/// List of values of type `T`, with 64-bit offsets
+///
+/// [`List<T>`] is stored as [`DataType::List`] arrays, which use 32-bit
+/// offsets and can therefore only hold up to `i32::MAX` items across all
+/// lists. This marker element type is stored as [`DataType::LargeList`] arrays
+/// instead, which use 64-bit offsets. Its API is otherwise identical to that of
+/// `List<T>`, except that bulk insertion uses [`LargeListSlice`]s.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, TypedBuilder, types::list::{LargeList, LargeListSlice}};
+/// use arrow_schema::{DataType, Field};
+///
+/// let mut builder = TypedBuilder::<LargeList<f32>>::new();
+/// builder.extend_from_slice(LargeListSlice {
+///     offsets: &[0, 2, 3],
+///     values: &[1.0, 2.0, 3.0],
+/// })?;
+/// let array = builder.finish();
+/// assert_eq!(array.value(0), &[1.0, 2.0]);
+/// assert_eq!(array.value(1), &[3.0]);
+/// assert_eq!(
+///     LargeList::<f32>::data_type(&()),
+///     DataType::LargeList(Field::new("item", DataType::Float32, false).into())
+/// );
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct LargeList<T: ArrayElement + ?Sized>(PhantomData<T>);
+//
+impl<T: ArrayElement + ?Sized> Debug for LargeList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LargeList<{}>", std::any::type_name::<T>())
+    }
+}
+
+// Enable strongly typed arrays of lists with 32-bit and 64-bit offsets
+macro_rules! impl_list_element {
+    ($($list:ident => $offset:ty),*) => {
+        $(
+            // SAFETY: This is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl<T: ArrayElement + ?Sized> ArrayElement for $list<T> {
+                type BuilderBackend = ListBuilder<T, $offset>;
+                type Value<'a> = T::Slice<'a>;
+                type Slice<'a> = GenericListSlice<'a, T, $offset>;
+                type PushResult = T::ExtendFromSliceResult;
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = false;
+            }
+            //
+            // SAFETY: Option is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl<T: ArrayElement + ?Sized> ArrayElement for Option<$list<T>> {
+                type BuilderBackend = ListBuilder<T, $offset>;
+                type Value<'a> = Option<T::Slice<'a>>;
+                type Slice<'a> = OptionSlice<'a, $list<T>>;
+                type PushResult = T::ExtendFromSliceResult;
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = true;
+            }
+        )*
+    };
+}
+//
+impl_list_element!(List => i32, LargeList => i64);
+
+/// Columnar alternative to `&[T::Slice]`, using arrow's list layout
+///
+/// The `i`-th list of the slice contains the items within
+/// `offsets[i]..offsets[i + 1]` of `values`. Therefore, a slice of N lists has
+/// N+1 offsets, which must be sorted in increasing order. The first offset does
+/// not need to be zero, items before the first offset are ignored.
+///
+/// As an exception to the above rule, an empty `offsets` slice is also
+/// accepted and denotes a slice with no list.
+///
+/// Offsets are validated before insertion, and invalid offsets will result in
+/// an [`ArrowError`] being returned.
+pub struct GenericListSlice<'a, T: ArrayElement + ?Sized, O: OffsetSizeTrait> {
+    /// Start and end position of each list within `values`
+    pub offsets: &'a [O],
+
+    /// Concatenated list items
+    pub values: T::Slice<'a>,
+}
+//
+/// Columnar alternative to `&[T::Slice]` for [`List<T>`]
+pub type ListSlice<'a, T> = GenericListSlice<'a, T, i32>;
+//
+/// Columnar alternative to `&[T::Slice]` for [`LargeList<T>`]
+pub type LargeListSlice<'a, T> = GenericListSlice<'a, T, i64>;
+//
+impl<'a, T: ArrayElement + ?Sized, O: OffsetSizeTrait> GenericListSlice<'a, T, O> {
+    /// Number of lists in the slice
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Truth that the slice contains no list
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Items of the `index`-th list, if in bounds
+    ///
+    /// # Panics
+    ///
+    /// May panic if this slice is not [valid](Self::validate).
+    pub fn get(&self, index: usize) -> Option<T::Slice<'a>> {
+        if index >= self.len() {
+            return None;
+        }
+        let start = self.offsets[index].as_usize();
+        let end = self.offsets[index + 1].as_usize();
+        Some(self.values.clone().slice_range(start..end))
+    }
+
+    /// Iterate over the lists of the slice
+    ///
+    /// # Panics
+    ///
+    /// Iteration may panic if this slice is not [valid](Self::validate).
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T::Slice<'a>> + ExactSizeIterator + '_ {
+        (0..self.len()).map(|index| self.get(index).expect("index is in bounds"))
+    }
+
+    /// Check that the offsets of this slice are valid
+    ///
+    /// Offsets are valid if they are sorted in increasing order and fall within
+    /// the bounds of `values`. The inner slices of `values`, if any, must also
+    /// have consistent lengths.
+    pub fn validate(&self) -> Result<(), ArrowError> {
+        let values_len = self.values.slice_len()?;
+        let mut last_offset = 0;
+        for (idx, offset) in self.offsets.iter().enumerate() {
+            let Some(offset) = offset.to_usize() else {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "offset #{idx} is negative"
+                )));
+            };
+            if idx > 0 && offset < last_offset {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "offset #{idx} is smaller than the previous offset"
+                )));
+            }
+            if offset > values_len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "offset #{idx} is out of bounds"
+                )));
+            }
+            last_offset = offset;
+        }
+        Ok(())
+    }
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> Clone for GenericListSlice<'_, T, O> {
+    fn clone(&self) -> Self {
+        Self {
+            offsets: self.offsets,
+            values: self.values.clone(),
+        }
+    }
+}
+//
+impl<'a, T: ArrayElement + ?Sized, O: OffsetSizeTrait> Copy for GenericListSlice<'a, T, O> where
+    T::Slice<'a>: Copy
+{
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> Debug for GenericListSlice<'_, T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListSlice")
+            .field("offsets", &self.offsets)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> ElementSlice for GenericListSlice<'_, T, O> {
+    fn slice_len(&self) -> Result<usize, ArrowError> {
+        Ok(self.len())
+    }
+
+    fn slice_range(self, range: Range<usize>) -> Self {
+        Self {
+            offsets: super::slice_offsets(self.offsets, range),
+            values: self.values,
+        }
+    }
+}
+
+/// Bulk readout of a list array
+///
+/// This uses the same offsets + flattened values layout as
+/// [`GenericListSlice`], but list items are read out using the bulk readout
+/// layout of `T`, as returned by [`TypedArray::as_slice()`](crate::TypedArray::as_slice).
+pub struct GenericListArraySlice<'a, T: ArrayElement + ?Sized, O: OffsetSizeTrait>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    /// Start and end position of each list within `items`
+    offsets: &'a [O],
+
+    /// Array of concatenated list items
+    items: &'a ArrayBackend<T>,
+}
+//
+/// Bulk readout of an array of [`List<T>`]
+pub type ListArraySlice<'a, T> = GenericListArraySlice<'a, T, i32>;
+//
+/// Bulk readout of an array of [`LargeList<T>`]
+pub type LargeListArraySlice<'a, T> = GenericListArraySlice<'a, T, i64>;
+//
+impl<'a, T: ArrayElement + ?Sized, O: OffsetSizeTrait> GenericListArraySlice<'a, T, O>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    /// Wrap the offsets and items of a valid list array
+    pub(crate) fn new(offsets: &'a [O], items: &'a ArrayBackend<T>) -> Self {
+        Self { offsets, items }
+    }
+
+    /// Number of lists in the slice
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Truth that the slice contains no list
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Start and end position of each list within [`values()`](Self::values)
+    pub fn offsets(&self) -> &'a [O] {
+        self.offsets
+    }
+
+    /// Concatenated list items
+    pub fn values(&self) -> ArraySlice<'a, T> {
+        self.items.as_slice()
+    }
+
+    /// Items of the `index`-th list, if in bounds
+    pub fn get(&self, index: usize) -> Option<ArraySlice<'a, T>> {
+        if index >= self.len() {
+            return None;
+        }
+        let start = self.offsets[index].as_usize();
+        let end = self.offsets[index + 1].as_usize();
+        Some(self.items.as_subslice(start..end))
+    }
+
+    /// Iterate over the lists of the slice
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = ArraySlice<'a, T>> + ExactSizeIterator + 'a {
+        let slice = *self;
+        (0..self.len()).map(move |index| slice.get(index).expect("index is in bounds"))
+    }
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> Clone for GenericListArraySlice<'_, T, O>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> Copy for GenericListArraySlice<'_, T, O> where
+    ArrayBackend<T>: TypedBackend<T>
+{
+}
+//
+impl<T: ArrayElement + ?Sized, O: OffsetSizeTrait> Debug for GenericListArraySlice<'_, T, O>
+where
+    ArrayBackend<T>: TypedBackend<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::backend::list::tests::{
+        expected_lists, offsets_and_values, offsets_are_valid,
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn slice((offsets, values) in offsets_and_values(), start: usize, len: usize) {
+            let slice = ListSlice::<u8> { offsets: &offsets, values: &values };
+            prop_assert_eq!(slice.len(), offsets.len().saturating_sub(1));
+            prop_assert_eq!(slice.is_empty(), offsets.len() < 2);
+            prop_assert_eq!(slice.slice_len()?, slice.len());
+            let valid = offsets_are_valid(&offsets, values.len());
+            prop_assert_eq!(slice.validate().is_ok(), valid);
+            if !valid {
+                return Ok(());
+            }
+
+            let expected = expected_lists(&offsets, &values);
+            prop_assert!(slice.iter().eq(expected.iter().copied()));
+            prop_assert!(slice.iter().rev().eq(expected.iter().rev().copied()));
+            prop_assert_eq!(slice.get(slice.len()), None);
+
+            let start = start % (slice.len() + 1);
+            let end = start + len % (slice.len() - start + 1);
+            let subslice = slice.slice_range(start..end);
+            prop_assert!(subslice.validate().is_ok());
+            prop_assert!(subslice.iter().eq(expected[start..end].iter().copied()));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Strongly typed interface to arrow-rs' [`DataType::Map`]
+//!
+//! Maps from keys of any non-nullable [`ArrayElement`] type `K` to values of
+//! any `ArrayElement` type `V` can be stored as arrays of [`Map<K, V>`]. Each
+//! map is written as a pair of key and value slices, in the
+//! [bulk insertion layout](ArrayElement::Slice) of `K` and `V`, and maps are
+//! bulk-inserted using the same offsets + flattened entries layout as
+//! [lists](super::list).
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::map::{Map, MapSlice}};
+//! // Per-row attributes
+//! let mut builder = TypedBuilder::<Map<u8, f32>>::new();
+//! builder.push((&[1, 2][..], &[0.5, 1.5][..]))?;
+//! builder.extend_from_slice(MapSlice {
+//!     offsets: &[0, 0, 1],
+//!     values: (&[3][..], &[2.5][..]),
+//! })?;
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(array.value(0), (&[1, 2][..], &[0.5, 1.5][..]));
+//! assert_eq!(array.value(1), (&[][..], &[][..]));
+//! assert_eq!(array.value(2), (&[3][..], &[2.5][..]));
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Maps can also be pushed entry by entry from any iterator of key-value
+//! pairs, which includes Rust maps whose entries are of the right type.
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::map::Map};
+//! use std::collections::{BTreeMap, HashMap};
+//!
+//! let mut builder = TypedBuilder::<Map<String, Option<i64>>>::new();
+//! builder.push_entries(HashMap::from([("answer", Some(42))]))?;
+//! let owned = BTreeMap::from([("none".to_owned(), None), ("one".to_owned(), Some(1))]);
+//! builder.push_entries(owned.iter().map(|(key, value)| (key.as_str(), *value)))?;
+//! let array = builder.finish();
+//! let (keys, values) = array.value(1);
+//! assert!(keys.iter().eq(["none", "one"]));
+//! assert_eq!(values.values[1], 1);
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! The keys of each map can be declared to be sorted using [`MapConfig`]. Maps
+//! are read out using the [bulk readout layout](crate::TypedArray::as_slice)
+//! of their keys and values, as a pair of key and value slices.
+
+use super::list::{ListArraySlice, ListSlice};
+use crate::{
+    builder::{backend::MapBuilder, BackendConfig},
+    ArrayElement, OptionSlice,
+};
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+/// Map from keys of type `K` to values of type `V`
+///
+/// This marker element type is stored as [`DataType::Map`] arrays, whose
+/// entries are struct arrays with a `keys` field of `K` and a `values` field of
+/// `V`. See the [module-level documentation](self) for more information.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, types::map::Map};
+/// use arrow_schema::{DataType, Field, Fields};
+///
+/// assert_eq!(
+///     Map::<String, Option<u32>>::data_type(&Default::default()),
+///     DataType::Map(
+///         Field::new(
+///             "entries",
+///             DataType::Struct(Fields::from(vec![
+///                 Field::new("keys", DataType::Utf8, false),
+///                 Field::new("values", DataType::UInt32, true),
+///             ])),
+///             false
+///         )
+///         .into(),
+///         false
+///     )
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Arrow does not allow map keys to be null, so creating a builder of maps
+/// whose key type is nullable, like `Map<Option<u8>, u8>`, panics.
+pub struct Map<K: ArrayElement, V: ArrayElement>(PhantomData<(K, V)>);
+//
+impl<K: ArrayElement, V: ArrayElement> Debug for Map<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Map<{}, {}>",
+            std::any::type_name::<K>(),
+            std::any::type_name::<V>()
+        )
+    }
+}
+//
+// SAFETY: Map is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<K: ArrayElement, V: ArrayElement> ArrayElement for Map<K, V> {
+    type BuilderBackend = MapBuilder<K, V>;
+    type Value<'a> = (K::Slice<'a>, V::Slice<'a>);
+    type Slice<'a> = MapSlice<'a, K, V>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<K: ArrayElement, V: ArrayElement> ArrayElement for Option<Map<K, V>> {
+    type BuilderBackend = MapBuilder<K, V>;
+    type Value<'a> = Option<(K::Slice<'a>, V::Slice<'a>)>;
+    type Slice<'a> = OptionSlice<'a, Map<K, V>>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Configuration of builders of [`Map`] arrays
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, TypedBuilder, builder::BuilderConfig, types::map::{Map, MapConfig}};
+/// use arrow_schema::DataType;
+/// use std::collections::BTreeMap;
+///
+/// // BTreeMaps iterate over their entries in key order
+/// let config = MapConfig::<String, u8> {
+///     sorted: true,
+///     ..Default::default()
+/// };
+/// assert!(matches!(
+///     Map::<String, u8>::data_type(&config),
+///     DataType::Map(_, true)
+/// ));
+/// let mut builder =
+///     TypedBuilder::<Map<String, u8>>::with_config(BuilderConfig::with_backend(None, config));
+/// let map = BTreeMap::from([("b".to_owned(), 2), ("a".to_owned(), 1)]);
+/// builder.push_entries(map.iter().map(|(key, value)| (key.as_str(), *value)))?;
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct MapConfig<K: ArrayElement, V: ArrayElement> {
+    /// Backend configuration of the keys
+    pub keys: BackendConfig<K>,
+
+    /// Backend configuration of the values
+    pub values: BackendConfig<V>,
+
+    /// Truth that the keys of each map are sorted
+    ///
+    /// This only sets the `keys_sorted` flag of the arrow data type. Builders
+    /// do not sort keys, nor check that they are sorted.
+    pub sorted: bool,
+}
+//
+impl<K: ArrayElement, V: ArrayElement> Clone for MapConfig<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+            sorted: self.sorted,
+        }
+    }
+}
+//
+impl<K: ArrayElement, V: ArrayElement> Debug for MapConfig<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapConfig")
+            .field("keys", &self.keys)
+            .field("values", &self.values)
+            .field("sorted", &self.sorted)
+            .finish()
+    }
+}
+//
+impl<K: ArrayElement, V: ArrayElement> Default for MapConfig<K, V>
+where
+    BackendConfig<K>: Default,
+    BackendConfig<V>: Default,
+{
+    fn default() -> Self {
+        Self {
+            keys: Default::default(),
+            values: Default::default(),
+            sorted: false,
+        }
+    }
+}
+//
+impl<K: ArrayElement, V: ArrayElement> PartialEq for MapConfig<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.values == other.values && self.sorted == other.sorted
+    }
+}
+//
+impl<K: ArrayElement, V: ArrayElement> Eq for MapConfig<K, V> {}
+
+/// Columnar alternative to `&[(K::Slice, V::Slice)]`, using arrow's map layout
+///
+/// This is the [list layout](ListSlice) of key-value tuples, where the
+/// `i`-th map of the slice contains the entries within
+/// `offsets[i]..offsets[i + 1]` of the key and value slices, which must have
+/// the same length. Nullable maps are bulk-inserted as [`OptionSlice`]s of
+/// `MapSlice`s.
+pub type MapSlice<'a, K, V> = ListSlice<'a, (K, V)>;
+
+/// Bulk readout of an array of [`Map<K, V>`]
+///
+/// This is the [list readout layout](ListArraySlice) of key-value tuples,
+/// whose [`values()`](ListArraySlice::values) are read out as a pair of key
+/// and value slices.
+pub type MapArraySlice<'a, K, V> = ListArraySlice<'a, (K, V)>;
@@ -1,3 +1,38 @@
 //! Rust equivalents of Arrow types
 
+pub mod bytes;
+pub mod decimal;
+pub mod dictionary;
+pub mod fixed_size_binary;
+pub mod fixed_size_list;
+pub mod list;
+pub mod map;
 pub mod primitive;
+pub mod run_end;
+pub mod structs;
+pub mod tuple;
+pub mod union;
+
+use std::ops::Range;
+
+/// Offsets of the elements within `range` of a slice that uses an arrow-style
+/// offsets layout, where N elements are delimited by N+1 offsets
+///
+/// This follows the convention that an empty `offsets` slice denotes a slice
+/// with no elements, and therefore returns empty offsets for empty ranges.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds.
+pub(crate) fn slice_offsets<O>(offsets: &[O], range: Range<usize>) -> &[O] {
+    let len = offsets.len().saturating_sub(1);
+    assert!(
+        range.start <= range.end && range.end <= len,
+        "range is out of bounds"
+    );
+    if range.is_empty() {
+        &[]
+    } else {
+        &offsets[range.start..=range.end]
+    }
+}
@@ -12,14 +12,19 @@ use arrow_array::builder::{
 use arrow_array::{
     builder::{NullBuilder, PrimitiveBuilder},
     types::*,
+    PrimitiveArray,
 };
-use arrow_schema::ArrowError;
-#[cfg(doc)]
-use arrow_schema::DataType;
+use arrow_buffer::i256;
+use arrow_schema::{ArrowError, DataType};
 use half::f16;
 #[cfg(any(test, feature = "proptest"))]
 use proptest::prelude::*;
-use std::{fmt::Debug, marker::PhantomData, num::TryFromIntError};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    num::TryFromIntError,
+    sync::Arc,
+};
 
 // === Strong value types matching non-std Arrow DataTypes ===
 
@@ -35,7 +40,9 @@ unsafe impl ArrayElement for Null {
     /// The only information that a slice of null contains is the number of
     /// nulls in it, so we make it literally a count of nulls
     type Slice<'a> = usize;
+    type PushResult = ();
     type ExtendFromSliceResult = ();
+    const NULLABLE: bool = true;
 }
 
 /// Date type representing the elapsed time since the UNIX epoch in days
@@ -78,16 +85,178 @@ impl From<Date64> for i64 {
     }
 }
 
-// TODO: Waiting for adt_const_params rustc feature to be able to expose the
-//       desired strongly typed version of the Decimal type family:
+/// 128-bit decimal whose precision and scale are fixed at compile time
+///
+/// Values are stored as unscaled `i128`s, which are implicitly scaled down by
+/// `10^S` and have at most `P` decimal digits. This is checked when decimals
+/// are created with [`new()`](Self::new) or `TryFrom<i128>`, and when arrow
+/// arrays are converted into arrays of decimals. Arrays of this type have a
+/// [`DataType::Decimal128`] data type with the same precision and scale.
+///
+/// Using a precision of 0 or above 38, or a positive scale above the
+/// precision, is a compile-time error. When precision and scale are only
+/// known at runtime, use [`decimal::Decimal128`](super::decimal::Decimal128)
+/// instead.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, TypedBuilder, types::primitive::Decimal128};
+/// use arrow_schema::DataType;
+///
+/// // Prices in cents, up to 999 999.99
+/// type Price = Decimal128<8, 2>;
+/// assert_eq!(Price::data_type(&()), DataType::Decimal128(8, 2));
+///
+/// let mut builder = TypedBuilder::<Price>::new();
+/// builder.push(Price::new(1999)?);
+/// assert!(Price::new(100_000_000).is_err());
+/// let array = builder.finish();
+/// assert_eq!(array.value(0).to_string(), "19.99");
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+///
+/// ```compile_fail
+/// # use arrow_typing::{TypedBuilder, types::primitive::Decimal128};
+/// // A scale of 3 does not fit in a precision of 2
+/// let builder = TypedBuilder::<Decimal128<2, 3>>::new();
+/// ```
+///
+/// ```compile_fail
+/// # use arrow_typing::types::primitive::Decimal128;
+/// // Decimals must have at least one digit
+/// let zero = Decimal128::<0, 0>::default();
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Decimal128<const P: u8, const S: i8>(i128);
+
+/// 256-bit decimal whose precision and scale are fixed at compile time
+///
+/// This is the [`DataType::Decimal256`] counterpart of [`Decimal128`]. Values
+/// are stored as unscaled [`i256`]s, and have a precision of at most 76
+/// digits.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Decimal256<const P: u8, const S: i8>(i256);
+
+// Common implementation of decimal types
+macro_rules! impl_decimal {
+    ($($decimal:ident => $arrow:ty, $native:ty);*) => {
+        $(
+            impl<const P: u8, const S: i8> $decimal<P, S> {
+                /// Maximal number of decimal digits
+                ///
+                /// Evaluating this constant checks the precision and scale,
+                /// which makes invalid decimal types a compile-time error.
+                pub const PRECISION: u8 = {
+                    assert!(
+                        P > 0 && P <= <$arrow>::MAX_PRECISION,
+                        "decimal precision must be in the 1..=MAX_PRECISION range"
+                    );
+                    assert!(
+                        S <= <$arrow>::MAX_SCALE && (S <= 0 || S as u8 <= P),
+                        "decimal scale must not exceed the precision"
+                    );
+                    P
+                };
+
+                /// Number of decimal digits after the decimal point
+                pub const SCALE: i8 = S;
+
+                /// Wrap an unscaled value, checking that it has at most `P`
+                /// decimal digits
+                #[inline]
+                pub fn new(value: $native) -> Result<Self, ArrowError> {
+                    <$arrow>::validate_decimal_precision(value, Self::PRECISION)?;
+                    Ok(Self(value))
+                }
+
+                /// Unscaled value
+                #[inline]
+                pub fn value(self) -> $native {
+                    self.0
+                }
+            }
+            //
+            impl<const P: u8, const S: i8> Display for $decimal<P, S> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.write_str(&<$arrow>::format_decimal(self.0, Self::PRECISION, S))
+                }
+            }
+            //
+            impl<const P: u8, const S: i8> Default for $decimal<P, S> {
+                #[inline]
+                fn default() -> Self {
+                    let _ = Self::PRECISION;
+                    Self(<$native>::default())
+                }
+            }
+            //
+            impl<const P: u8, const S: i8> TryFrom<$native> for $decimal<P, S> {
+                type Error = ArrowError;
+
+                #[inline]
+                fn try_from(value: $native) -> Result<Self, ArrowError> {
+                    Self::new(value)
+                }
+            }
+            //
+            impl<const P: u8, const S: i8> From<$decimal<P, S>> for $native {
+                #[inline(always)]
+                fn from(value: $decimal<P, S>) -> Self {
+                    value.0
+                }
+            }
+            //
+            // SAFETY: By construction, it is enforced that Slice is &[Self]
+            unsafe impl<const P: u8, const S: i8> ArrayElement for $decimal<P, S> {
+                type BuilderBackend = PrimitiveBuilder<$arrow>;
+                type Value<'a> = Self;
+                type Slice<'a> = &'a [Self];
+                type PushResult = ();
+                type ExtendFromSliceResult = ();
+                const NULLABLE: bool = false;
+            }
+            //
+            // SAFETY: Option is not a primitive type and is therefore not
+            //         affected by the safety precondition of ArrayElement
+            unsafe impl<const P: u8, const S: i8> ArrayElement for Option<$decimal<P, S>> {
+                type BuilderBackend = PrimitiveBuilder<$arrow>;
+                type Value<'a> = Option<$decimal<P, S>>;
+                type Slice<'a> = OptionSlice<'a, $decimal<P, S>>;
+                type PushResult = ();
+                type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = true;
+            }
+        )*
+    };
+}
 //
-//       #[derive(Clone, Copy, Debug)]
-//       #[repr(transparent)]
-//       pub struct Decimal<
-//           T: DecimalRepr,
-//           const PRECISION: Option<u8> = None,
-//           const SCALE: Option<i8> = None,
-//       >(T);
+impl_decimal!(
+    Decimal128 => Decimal128Type, i128;
+    Decimal256 => Decimal256Type, i256
+);
+//
+#[cfg(any(test, feature = "proptest"))]
+impl<const P: u8, const S: i8> Arbitrary for Decimal128<P, S> {
+    type Parameters = <i128 as Arbitrary>::Parameters;
+    type Strategy = prop::strategy::Map<<i128 as Arbitrary>::Strategy, fn(i128) -> Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        i128::arbitrary_with(args)
+            .prop_map(|value| Self(value % 10_i128.pow(u32::from(Self::PRECISION))))
+    }
+}
+//
+#[cfg(any(test, feature = "proptest"))]
+impl<const P: u8, const S: i8> Arbitrary for Decimal256<P, S> {
+    type Parameters = <i128 as Arbitrary>::Parameters;
+    type Strategy = prop::strategy::Map<<i128 as Arbitrary>::Strategy, fn(i128) -> Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        i128::arbitrary_with(args).prop_map(|value| {
+            let precision = Self::PRECISION.min(Decimal128Type::MAX_PRECISION);
+            Self(i256::from_i128(value % 10_i128.pow(u32::from(precision))))
+        })
+    }
+}
 
 /// Measure of elapsed time with a certain integer unit
 #[derive(Clone, Copy, Debug, Default)]
@@ -406,21 +575,223 @@ impl From<Time<Nanosecond>> for i64 {
     }
 }
 
-// TODO: Waiting for adt_const_params rustc feature and a constified Arc
-//       constructor to be able to expose the desired strongly typed version of
-//       the Timestamp type family:
+/// Elapsed time since the UNIX epoch, in a certain unit
+///
+/// Timestamps are stored as [`DataType::Timestamp`] arrays, with an optional
+/// timezone. When a timezone is set, timestamps count time elapsed since the
+/// UNIX epoch in UTC, and the timezone is only used to convert them to local
+/// time. Otherwise, timestamps count the elapsed time since the UNIX epoch in
+/// some unknown local timezone.
+///
+/// By default, the timezone is provided at runtime through a
+/// [`TimestampConfig`], and arrays are accepted regardless of their timezone.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, TypedBuilder, builder::BuilderConfig, types::primitive::{Millisecond, Timestamp, TimestampConfig}};
+/// use arrow_schema::{DataType, TimeUnit};
+///
+/// let config = TimestampConfig::with_timezone("Europe/Paris");
+/// assert_eq!(
+///     Timestamp::<Millisecond>::data_type(&config),
+///     DataType::Timestamp(TimeUnit::Millisecond, Some("Europe/Paris".into()))
+/// );
+///
+/// let mut builder =
+///     TypedBuilder::<Timestamp<Millisecond>>::with_config(BuilderConfig::with_backend(None, config));
+/// builder.push(Timestamp::from(1_700_000_000_000));
+/// let array = builder.finish();
+/// assert_eq!(i64::from(array.value(0)), 1_700_000_000_000);
+/// ```
+///
+/// Alternatively, the timezone can be encoded in the type through a
+/// [`TimeZone`] marker, in which case no configuration is needed, and arrays
+/// with any other timezone are rejected.
+///
+/// ```rust
+/// # use arrow_typing::{TypedArray, TypedBuilder, types::primitive::{Nanosecond, Timestamp, Utc}};
+/// let mut builder = TypedBuilder::<Timestamp<Nanosecond, Utc>>::new();
+/// builder.push(Timestamp::from(1_700_000_000_000_000_000));
+/// let array = builder.finish().into_array_ref();
+/// assert!(TypedArray::<Timestamp<Nanosecond, Utc>>::try_from(array.clone()).is_ok());
+///
+/// arrow_typing::time_zone! {
+///     /// Japan Standard Time
+///     pub struct Tokyo = "Asia/Tokyo";
+/// }
+/// assert!(TypedArray::<Timestamp<Nanosecond, Tokyo>>::try_from(array).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Timestamp<Unit: TimeUnit, Tz = RuntimeTimeZone>(i64, PhantomData<(Unit, Tz)>);
+//
+#[cfg(any(test, feature = "proptest"))]
+impl<Unit: TimeUnit, Tz: Debug> Arbitrary for Timestamp<Unit, Tz> {
+    type Parameters = <i64 as Arbitrary>::Parameters;
+    type Strategy = prop::strategy::Map<<i64 as Arbitrary>::Strategy, fn(i64) -> Self>;
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        i64::arbitrary_with(args).prop_map(|inner| Self(inner, PhantomData))
+    }
+}
+//
+impl<Unit: TimeUnit, Tz> From<i64> for Timestamp<Unit, Tz> {
+    #[inline(always)]
+    fn from(value: i64) -> Self {
+        Self(value, PhantomData)
+    }
+}
 //
-//       #[derive(Clone, Copy, Debug)]
-//       #[repr(transparent)]
-//       pub struct Timestamp<
-//           Unit: TimeUnit,
-//           const TIMESTAMP: Option<Arc<str>> = None,
-//       >(i64);
+impl<Unit: TimeUnit, Tz> From<Timestamp<Unit, Tz>> for i64 {
+    #[inline(always)]
+    fn from(value: Timestamp<Unit, Tz>) -> Self {
+        value.0
+    }
+}
+//
+// SAFETY: Timestamps with a runtime timezone are not a PrimitiveType and are
+//         therefore not concerned by ArrayElement's safety contract.
+unsafe impl<Unit: TimeUnit> ArrayElement for Timestamp<Unit, RuntimeTimeZone> {
+    type BuilderBackend = PrimitiveBuilder<Unit::ArrowTimestamp>;
+    type Value<'a> = Self;
+    type Slice<'a> = &'a [Self];
+    type PushResult = ();
+    type ExtendFromSliceResult = ();
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<Unit: TimeUnit> ArrayElement for Option<Timestamp<Unit, RuntimeTimeZone>> {
+    type BuilderBackend = PrimitiveBuilder<Unit::ArrowTimestamp>;
+    type Value<'a> = Self;
+    type Slice<'a> = OptionSlice<'a, Timestamp<Unit, RuntimeTimeZone>>;
+    type PushResult = ();
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+//
+// SAFETY: By construction, it is enforced that Slice is &[Self]
+unsafe impl<Unit: TimeUnit, Tz: TimeZone> ArrayElement for Timestamp<Unit, Tz> {
+    type BuilderBackend = PrimitiveBuilder<Unit::ArrowTimestamp>;
+    type Value<'a> = Self;
+    type Slice<'a> = &'a [Self];
+    type PushResult = ();
+    type ExtendFromSliceResult = ();
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<Unit: TimeUnit, Tz: TimeZone> ArrayElement for Option<Timestamp<Unit, Tz>> {
+    type BuilderBackend = PrimitiveBuilder<Unit::ArrowTimestamp>;
+    type Value<'a> = Self;
+    type Slice<'a> = OptionSlice<'a, Timestamp<Unit, Tz>>;
+    type PushResult = ();
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Configuration of builders of [`Timestamp`] arrays with a
+/// [`RuntimeTimeZone`]
+///
+/// The default configuration produces timestamps without a timezone.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TimestampConfig {
+    /// Timezone of the timestamps, if any
+    timezone: Option<Arc<str>>,
+}
+//
+impl TimestampConfig {
+    /// Configure builders to produce timestamps with a timezone
+    ///
+    /// The timezone can either be an IANA timezone name like `"Europe/Paris"`
+    /// or a fixed offset like `"+07:30"`. It is not checked at this point,
+    /// but arrow-rs will reject invalid timezones when timestamps are
+    /// converted to local time.
+    pub fn with_timezone(timezone: impl Into<Arc<str>>) -> Self {
+        Self {
+            timezone: Some(timezone.into()),
+        }
+    }
+
+    /// Timezone of the timestamps, if any
+    pub fn timezone(&self) -> Option<&str> {
+        self.timezone.as_deref()
+    }
+
+    /// Arrow data type of timestamps in unit `Unit` with this timezone
+    pub(crate) fn data_type<Unit: TimeUnit>(&self) -> DataType {
+        DataType::Timestamp(Unit::ArrowTimestamp::UNIT, self.timezone.clone())
+    }
+}
+
+/// Timezone of [`Timestamp`]s whose timezone is provided at runtime
+///
+/// This is the default timezone parameter of `Timestamp`, which makes builders
+/// take their timezone from a [`TimestampConfig`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RuntimeTimeZone;
+
+/// Timezone of [`Timestamp`]s that is known at compile time
+///
+/// Markers for this trait are declared with the [`time_zone!`](crate::time_zone)
+/// macro, and [`Utc`] is provided.
+pub trait TimeZone: Debug + Send + Sync + 'static {
+    /// Timezone name, as stored in arrow data types
+    ///
+    /// This is either an IANA timezone name like `"Europe/Paris"`, or a fixed
+    /// offset like `"+07:30"`.
+    const NAME: &'static str;
+}
+
+/// Declare [`TimeZone`] markers from their name
+///
+/// The timezone name is not checked at this point, but arrow-rs will reject
+/// invalid timezones when timestamps are converted to local time.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, types::primitive::{Second, Timestamp}};
+/// use arrow_schema::{DataType, TimeUnit};
+///
+/// arrow_typing::time_zone! {
+///     /// Central European Time
+///     pub struct Paris = "Europe/Paris";
+///
+///     /// Indian Standard Time
+///     pub struct Kolkata = "Asia/Kolkata";
+/// }
+///
+/// assert_eq!(
+///     Timestamp::<Second, Paris>::data_type(&()),
+///     DataType::Timestamp(TimeUnit::Second, Some("Europe/Paris".into()))
+/// );
+/// ```
+#[macro_export]
+macro_rules! time_zone {
+    ($($(#[$attr:meta])* $vis:vis struct $name:ident = $tz:literal;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            $vis struct $name;
+            //
+            impl $crate::types::primitive::TimeZone for $name {
+                const NAME: &'static str = $tz;
+            }
+        )*
+    };
+}
+
+time_zone! {
+    /// Coordinated Universal Time
+    pub struct Utc = "UTC";
+}
 
 /// Unit of time
-pub trait TimeUnit: Debug {
+pub trait TimeUnit: Debug + Send + Sync + 'static {
     /// Storage format for time since midnight in this unit
     type TimeStorage: Clone + Copy + Debug + Default;
+
+    /// Equivalent Arrow timestamp type
+    type ArrowTimestamp: ArrowTimestampType + Debug;
 }
 
 /// Second duration storage granularity
@@ -429,6 +800,7 @@ pub struct Second;
 //
 impl TimeUnit for Second {
     type TimeStorage = i32;
+    type ArrowTimestamp = TimestampSecondType;
 }
 
 /// Millisecond duration storage granularity
@@ -437,6 +809,7 @@ pub struct Millisecond;
 //
 impl TimeUnit for Millisecond {
     type TimeStorage = i32;
+    type ArrowTimestamp = TimestampMillisecondType;
 }
 
 /// Microsecond duration storage granularity
@@ -445,6 +818,7 @@ pub struct Microsecond;
 //
 impl TimeUnit for Microsecond {
     type TimeStorage = i64;
+    type ArrowTimestamp = TimestampMicrosecondType;
 }
 
 /// Nanosecond duration storage granularity
@@ -453,6 +827,7 @@ pub struct Nanosecond;
 //
 impl TimeUnit for Nanosecond {
     type TimeStorage = i64;
+    type ArrowTimestamp = TimestampNanosecondType;
 }
 
 // === Equivalent of ArrowPrimitiveType for the types defined in this module ===
@@ -467,10 +842,36 @@ pub unsafe trait PrimitiveType:
     // TODO: Once Rust's trait solver supports it, use an ArrayElement<Value<'_>
     //       = Self, Slice<'_> = &[Self]> bound to simplify downstream usage and
     //       remove the unsafe contract of ArrayElement.
-    ArrayElement<BuilderBackend = PrimitiveBuilder<Self::Arrow>, ExtendFromSliceResult = ()> + Debug + From<NativeType<Self>> + Into<NativeType<Self>>
+    ArrayElement<BuilderBackend = PrimitiveBuilder<Self::Arrow>, PushResult = (), ExtendFromSliceResult = ()> + Debug + Into<NativeType<Self>>
 {
     /// Equivalent Arrow primitive type
     type Arrow: ArrowPrimitiveType + Debug;
+
+    /// Wrap a native value that was read from a validated array
+    ///
+    /// This does not check the value, as [`Self::validate_values()`] has
+    /// already been called on the array that it comes from.
+    #[doc(hidden)]
+    fn from_native(native: NativeType<Self>) -> Self;
+
+    /// Check that the valid values of an array are valid values of this type
+    ///
+    /// This accepts any value by default, except for types which do not use
+    /// the full range of their native type, like decimals whose values are
+    /// limited to their precision.
+    #[doc(hidden)]
+    fn validate_values(_array: &PrimitiveArray<Self::Arrow>) -> Result<(), ArrowError> {
+        Ok(())
+    }
+
+    /// Arrow data type of arrays of this type
+    ///
+    /// This is the default data type of [`Self::Arrow`], except for types
+    /// which carry extra information in their data type, like decimals with
+    /// a compile-time precision and scale.
+    fn arrow_data_type() -> DataType {
+        Self::Arrow::DATA_TYPE
+    }
 }
 //
 macro_rules! unsafe_impl_primitive_type {
@@ -478,6 +879,11 @@ macro_rules! unsafe_impl_primitive_type {
         $(
             unsafe impl PrimitiveType for $local {
                 type Arrow = $arrow;
+
+                #[inline(always)]
+                fn from_native(native: NativeType<Self>) -> Self {
+                    native.into()
+                }
             }
         )*
     };
@@ -488,7 +894,6 @@ macro_rules! unsafe_impl_primitive_type {
 unsafe_impl_primitive_type!(
     Date32 => Date32Type,
     Date64 => Date64Type,
-    // TODO: Support decimals, see above for rustc blocker info.
     Duration<Microsecond> => DurationMicrosecondType,
     Duration<Millisecond> => DurationMillisecondType,
     Duration<Nanosecond> => DurationNanosecondType,
@@ -507,12 +912,65 @@ unsafe_impl_primitive_type!(
     Time<Second> => Time32SecondType,
     Time<Microsecond> => Time64MicrosecondType,
     Time<Nanosecond> => Time64NanosecondType,
-    // TODO: Support timestamps, see above for rustc blocker info.
     u8 => UInt8Type,
     u16 => UInt16Type,
     u32 => UInt32Type,
     u64 => UInt64Type
 );
+//
+// SAFETY: Decimal128 is a repr(transparent) wrapper over i128
+unsafe impl<const P: u8, const S: i8> PrimitiveType for Decimal128<P, S> {
+    type Arrow = Decimal128Type;
+
+    #[inline(always)]
+    fn from_native(native: NativeType<Self>) -> Self {
+        Self(native)
+    }
+
+    fn validate_values(array: &PrimitiveArray<Decimal128Type>) -> Result<(), ArrowError> {
+        array.iter().flatten().try_for_each(|value| {
+            Decimal128Type::validate_decimal_precision(value, Self::PRECISION)
+        })
+    }
+
+    fn arrow_data_type() -> DataType {
+        DataType::Decimal128(Self::PRECISION, S)
+    }
+}
+//
+// SAFETY: Decimal256 is a repr(transparent) wrapper over i256
+unsafe impl<const P: u8, const S: i8> PrimitiveType for Decimal256<P, S> {
+    type Arrow = Decimal256Type;
+
+    #[inline(always)]
+    fn from_native(native: NativeType<Self>) -> Self {
+        Self(native)
+    }
+
+    fn validate_values(array: &PrimitiveArray<Decimal256Type>) -> Result<(), ArrowError> {
+        array.iter().flatten().try_for_each(|value| {
+            Decimal256Type::validate_decimal_precision(value, Self::PRECISION)
+        })
+    }
+
+    fn arrow_data_type() -> DataType {
+        DataType::Decimal256(Self::PRECISION, S)
+    }
+}
+//
+// SAFETY: Timestamp is a repr(transparent) wrapper over i64
+unsafe impl<Unit: TimeUnit, Tz: TimeZone> PrimitiveType for Timestamp<Unit, Tz> {
+    type Arrow = Unit::ArrowTimestamp;
+
+    #[inline(always)]
+    fn from_native(native: i64) -> Self {
+        native.into()
+    }
+
+    fn arrow_data_type() -> DataType {
+        DataType::Timestamp(Unit::ArrowTimestamp::UNIT, Some(Tz::NAME.into()))
+    }
+}
 
 // Easy access to the NativeType backing a PrimitiveType
 pub(crate) type NativeType<T> = <<T as PrimitiveType>::Arrow as ArrowPrimitiveType>::Native;
@@ -526,7 +984,9 @@ macro_rules! impl_primitive_element {
                 type BuilderBackend = $builder;
                 type Value<'a> = Self;
                 type Slice<'a> = &'a [Self];
+                type PushResult = ();
                 type ExtendFromSliceResult = ();
+                const NULLABLE: bool = false;
             }
 
             // NOTE: I tried to make this blanket-impl'd for Option<T> where
@@ -542,7 +1002,9 @@ macro_rules! impl_primitive_element {
                 type BuilderBackend = $builder;
                 type Value<'a> = Option<$element>;
                 type Slice<'a> = OptionSlice<'a, $element>;
+                type PushResult = ();
                 type ExtendFromSliceResult = Result<(), ArrowError>;
+                const NULLABLE: bool = true;
             }
         )*
     };
@@ -552,7 +1014,6 @@ impl_primitive_element!(
     bool => BooleanBuilder,
     Date32 => Date32Builder,
     Date64 => Date64Builder,
-    // TODO: Support decimals, see types module for rustc blocker info.
     Duration<Microsecond> => DurationMicrosecondBuilder,
     Duration<Millisecond> => DurationMillisecondBuilder,
     Duration<Nanosecond> => DurationNanosecondBuilder,
@@ -571,7 +1032,6 @@ impl_primitive_element!(
     Time<Second> => Time32SecondBuilder,
     Time<Microsecond> => Time64MicrosecondBuilder,
     Time<Nanosecond> => Time64NanosecondBuilder,
-    // TODO: Support timestamps, see types module for rustc blocker info.
     u8 => UInt8Builder,
     u16 => UInt16Builder,
     u32 => UInt32Builder,
@@ -0,0 +1,429 @@
+//! Strongly typed interface to arrow-rs' [`DataType::RunEndEncoded`]
+//!
+//! Columns where the same value is often repeated many times in a row, like
+//! sensor status readings, can be stored as arrays of [`RunEnd<R, V>`]. Each
+//! run of identical values of type `V` is then only stored once, along with
+//! the logical index of type `R` where the run ends.
+//!
+//! Run-end encoded arrays are built from plain values, and pushing the same
+//! value as the previous one automatically extends the current run. Runs can
+//! also be appended in bulk using [`TypedBuilder::extend_run()`].
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::run_end::RunEnd};
+//! let mut builder = TypedBuilder::<RunEnd<i32, String>>::new();
+//! builder.push("idle")?;
+//! builder.push("idle")?;
+//! builder.extend_run("busy", 1000)?;
+//! builder.push("idle")?;
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 1003);
+//! assert_eq!(array.value(500), "busy");
+//! assert!(array.as_slice().runs().eq([("idle", 2), ("busy", 1000), ("idle", 1)]));
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Values are read out as decoded values, or in bulk as a sequence of runs
+//! using [`RunEndArraySlice`]. Null values of `Option<RunEnd<R, V>>` are also
+//! run-end encoded.
+//!
+//! ```rust
+//! # use arrow_typing::{TypedBuilder, types::run_end::RunEnd};
+//! let mut builder = TypedBuilder::<Option<RunEnd<i16, f32>>>::new();
+//! builder.extend_from_value_slice(&[1.0, 1.0, 2.0])?;
+//! builder.extend_with_nulls(3);
+//! let array = builder.finish();
+//! assert_eq!(array.null_count(), 3);
+//! let slice = array.as_slice();
+//! assert_eq!(slice.num_runs(), 3);
+//! assert!(slice.runs().eq([(Some(1.0), 2), (Some(2.0), 1), (None, 3)]));
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Values are considered identical when they have the same binary
+//! representation. For example, `-0.0` does not extend a run of `0.0`, but a
+//! `NaN` does extend a run of identical `NaN`s.
+//!
+//! Insertion fails if the length of the array would exceed the maximal value
+//! of `R`, in which case nothing is inserted. Since
+//! [`TypedBuilder::extend_with_nulls()`] cannot fail, it panics instead, as
+//! detailed in the [`RunEnd`] documentation.
+
+#[cfg(doc)]
+use crate::TypedBuilder;
+use crate::{
+    array::{backend::TypedBackend, ArrayBackend, ArrayValue},
+    builder::backend::RunEndBuilder,
+    types::{
+        bytes::{self, LargeBinary, LargeUtf8},
+        primitive::{NativeType, PrimitiveType},
+    },
+    ArrayElement, OptionSlice,
+};
+use arrow_array::{types::RunEndIndexType, Array, RunArray};
+use arrow_buffer::{ArrowNativeType, ToByteSlice};
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Range,
+};
+
+/// Run-end encoded values of type `V`, with run ends of type `R`
+///
+/// This marker element type is stored as [`DataType::RunEndEncoded`] arrays.
+/// It is written and read out like `V`, but repeated values are only stored
+/// once. See the [module-level documentation](self) for more information.
+///
+/// ```rust
+/// # use arrow_typing::{ArrayElement, types::run_end::RunEnd};
+/// use arrow_schema::{DataType, Field};
+///
+/// assert_eq!(
+///     RunEnd::<i64, u8>::data_type(&()),
+///     DataType::RunEndEncoded(
+///         Field::new("run_ends", DataType::Int64, false).into(),
+///         Field::new("values", DataType::UInt8, true).into(),
+///     )
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Appending nulls to a `TypedBuilder<Option<RunEnd<R, V>>>` with
+/// [`TypedBuilder::extend_with_nulls()`] panics if the length of the array
+/// would then exceed the maximal value of `R`, as that method cannot fail. So
+/// do the builders of containers like `Option<(RunEnd<R, V>,)>` when they pad
+/// run-end encoded children with nulls. Use [`TypedBuilder::extend_run()`]
+/// with a `None` value to get an error instead.
+pub struct RunEnd<R: RunEndIndex, V: RunEndValue + ?Sized>(PhantomData<R>, PhantomData<V>);
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> Debug for RunEnd<R, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RunEnd<{}, {}>",
+            std::any::type_name::<R>(),
+            std::any::type_name::<V>()
+        )
+    }
+}
+//
+// SAFETY: RunEnd is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<R: RunEndIndex, V: RunEndValue + ?Sized> ArrayElement for RunEnd<R, V> {
+    type BuilderBackend = RunEndBuilder<R, V>;
+    type Value<'a> = V::Value<'a>;
+    type Slice<'a> = V::Slice<'a>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<R: RunEndIndex, V: RunEndValue + ?Sized> ArrayElement for Option<RunEnd<R, V>> {
+    type BuilderBackend = RunEndBuilder<R, V>;
+    type Value<'a> = Option<V::Value<'a>>;
+    type Slice<'a> = OptionSlice<'a, RunEnd<R, V>>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Integer type that can be used as the run ends of a [`RunEnd`] array
+///
+/// This is implemented for `i16`, `i32` and `i64`.
+pub trait RunEndIndex: PrimitiveType<Arrow: RunEndIndexType> + Copy {}
+//
+impl<T: PrimitiveType<Arrow: RunEndIndexType> + Copy> RunEndIndex for T {}
+
+/// Element type that can be used as the values of a [`RunEnd`] array
+///
+/// This is implemented for `bool`, [primitive types](PrimitiveType) and
+/// variable-length strings and binary data.
+pub trait RunEndValue: ArrayElement<PushResult = ()> {
+    /// Owned copy of a value, used to detect repeated values
+    #[doc(hidden)]
+    type Owned: Debug + Send + Sync + 'static;
+
+    /// Make an owned copy of a value
+    #[doc(hidden)]
+    fn to_owned_value(value: &Self::Value<'_>) -> Self::Owned;
+
+    /// Truth that a value is identical to a previously copied value
+    #[doc(hidden)]
+    fn is_same(owned: &Self::Owned, value: &Self::Value<'_>) -> bool;
+
+    /// Validate a slice of values and iterate over them
+    #[doc(hidden)]
+    fn slice_values<'a>(
+        slice: Self::Slice<'a>,
+    ) -> Result<impl Iterator<Item = Self::Value<'a>> + 'a, ArrowError>;
+}
+//
+impl<T: PrimitiveType> RunEndValue for T
+where
+    // FIXME: Remove these bounds once the Rust trait system supports adding
+    //        the appropriate bounds on PrimitiveType to let rustc figure out
+    //        that T::Value<'_> is just T for primitive types.
+    for<'a> T::Value<'a>: Copy + Into<NativeType<T>>,
+    for<'a> T: Into<T::Value<'a>>,
+{
+    type Owned = NativeType<T>;
+
+    fn to_owned_value(value: &T::Value<'_>) -> NativeType<T> {
+        (*value).into()
+    }
+
+    fn is_same(owned: &NativeType<T>, value: &T::Value<'_>) -> bool {
+        let value: NativeType<T> = (*value).into();
+        owned.to_byte_slice() == value.to_byte_slice()
+    }
+
+    fn slice_values<'a>(
+        slice: T::Slice<'a>,
+    ) -> Result<impl Iterator<Item = T::Value<'a>> + 'a, ArrowError> {
+        // SAFETY: This transmute is safe because...
+        //         - T::Slice is &[T] for all primitive types
+        //         - Primitive types are repr(transparent) wrappers over the
+        //           corresponding Arrow native types, so it is safe to
+        //           transmute &[T] into &[NativeType<T>].
+        let native_slice =
+            unsafe { std::mem::transmute_copy::<T::Slice<'a>, &'a [NativeType<T>]>(&slice) };
+        Ok(native_slice
+            .iter()
+            .map(|&native| T::from_native(native).into()))
+    }
+}
+//
+impl RunEndValue for bool {
+    type Owned = bool;
+
+    fn to_owned_value(value: &bool) -> bool {
+        *value
+    }
+
+    fn is_same(owned: &bool, value: &bool) -> bool {
+        owned == value
+    }
+
+    fn slice_values<'a>(
+        slice: Self::Slice<'a>,
+    ) -> Result<impl Iterator<Item = Self::Value<'a>> + 'a, ArrowError> {
+        Ok(slice.iter().copied())
+    }
+}
+//
+macro_rules! impl_byte_run_end_value {
+    ($($element:ty),*) => {
+        $(
+            impl RunEndValue for $element {
+                type Owned = Box<bytes::NativeType<$element>>;
+
+                fn to_owned_value(value: &&bytes::NativeType<$element>) -> Self::Owned {
+                    Box::from(*value)
+                }
+
+                fn is_same(owned: &Self::Owned, value: &&bytes::NativeType<$element>) -> bool {
+                    **owned == **value
+                }
+
+                fn slice_values<'a>(
+                    slice: Self::Slice<'a>,
+                ) -> Result<impl Iterator<Item = Self::Value<'a>> + 'a, ArrowError> {
+                    slice.validate()?;
+                    Ok(slice.iter())
+                }
+            }
+        )*
+    };
+}
+//
+impl_byte_run_end_value!(str, String, [u8], Vec<u8>, LargeUtf8, LargeBinary);
+
+/// [`RunEnd`] or `Option<RunEnd>`, i.e. an element type that is stored as a
+/// [`RunArray`]
+pub trait RunEndElement: ArrayElement {
+    /// Type of the run ends
+    type Index: RunEndIndex;
+
+    /// Type of the run values
+    type Values: RunEndValue + ?Sized;
+
+    /// Value of a run, as read out from the array
+    type RunValue<'a>: Debug;
+
+    /// Read the value of the `index`-th run from the values of a run array,
+    /// without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `index` must be smaller than the length of `values`.
+    #[doc(hidden)]
+    unsafe fn run_value(values: &ArrayBackend<Self::Values>, index: usize) -> Self::RunValue<'_>;
+}
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> RunEndElement for RunEnd<R, V>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Index = R;
+    type Values = V;
+    type RunValue<'a> = ArrayValue<'a, V>;
+
+    #[inline]
+    unsafe fn run_value(values: &ArrayBackend<V>, index: usize) -> Self::RunValue<'_> {
+        unsafe { values.value_unchecked(index) }
+    }
+}
+//
+impl<R: RunEndIndex, V: RunEndValue + ?Sized> RunEndElement for Option<RunEnd<R, V>>
+where
+    ArrayBackend<V>: TypedBackend<V>,
+{
+    type Index = R;
+    type Values = V;
+    type RunValue<'a> = Option<ArrayValue<'a, V>>;
+
+    #[inline]
+    unsafe fn run_value(values: &ArrayBackend<V>, index: usize) -> Self::RunValue<'_> {
+        values
+            .is_valid(index)
+            .then(|| unsafe { values.value_unchecked(index) })
+    }
+}
+
+/// Bulk readout of a run-end encoded array
+///
+/// This gives access to the runs of the array, each of which is made of a
+/// value and a number of repetitions. When the array has been sliced, runs
+/// that straddle the slice boundaries are truncated accordingly.
+pub struct RunEndArraySlice<'a, T: RunEndElement>
+where
+    ArrayBackend<T::Values>: TypedBackend<T::Values>,
+{
+    /// Logical end of each run that overlaps with the slice
+    run_ends: &'a [NativeType<T::Index>],
+
+    /// Array of run values, starting with the value of the first run
+    values: &'a ArrayBackend<T::Values>,
+
+    /// Index of the first run's value within `values`
+    first_run: usize,
+
+    /// Logical range of the slice, in the coordinate system of `run_ends`
+    range: Range<usize>,
+}
+//
+impl<'a, T: RunEndElement> RunEndArraySlice<'a, T>
+where
+    ArrayBackend<T::Values>: TypedBackend<T::Values>,
+{
+    /// Slice the elements within `range` of a valid run array
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub(crate) fn new(
+        array: &'a RunArray<<T::Index as PrimitiveType>::Arrow>,
+        values: &'a ArrayBackend<T::Values>,
+        range: Range<usize>,
+    ) -> Self {
+        let run_ends = array.run_ends();
+        assert!(
+            range.start <= range.end && range.end <= run_ends.len(),
+            "range is out of bounds"
+        );
+        let runs = if range.is_empty() {
+            0..0
+        } else {
+            run_ends.get_physical_index(range.start)..run_ends.get_physical_index(range.end - 1) + 1
+        };
+        Self {
+            run_ends: &run_ends.values()[runs.clone()],
+            values,
+            first_run: runs.start,
+            range: run_ends.offset() + range.start..run_ends.offset() + range.end,
+        }
+    }
+
+    /// Number of elements in the slice
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Truth that the slice contains no element
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Number of runs in the slice
+    pub fn num_runs(&self) -> usize {
+        self.run_ends.len()
+    }
+
+    /// Value of the `index`-th element, if in bounds
+    ///
+    /// This needs a binary search through the runs of the slice, so
+    /// [`runs()`](Self::runs) should be preferred when reading many elements.
+    pub fn get(&self, index: usize) -> Option<T::RunValue<'a>> {
+        if index >= self.len() {
+            return None;
+        }
+        let position = self.range.start + index;
+        let run = self
+            .run_ends
+            .partition_point(|&run_end| run_end.as_usize() <= position);
+        // SAFETY: Run arrays have as many values as runs, and the position is
+        //         in bounds so it must belong to one of the runs.
+        Some(unsafe { T::run_value(self.values, self.first_run + run) })
+    }
+
+    /// Iterate over the runs of the slice, as `(value, run_length)` pairs
+    pub fn runs(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (T::RunValue<'a>, usize)> + ExactSizeIterator + 'a {
+        let Self {
+            run_ends,
+            values,
+            first_run,
+            range,
+        } = self.clone();
+        (0..run_ends.len()).map(move |run| {
+            let start = match run {
+                0 => range.start,
+                _ => run_ends[run - 1].as_usize(),
+            };
+            let end = run_ends[run].as_usize().min(range.end);
+            // SAFETY: Run arrays have as many values as runs
+            let value = unsafe { T::run_value(values, first_run + run) };
+            (value, end - start)
+        })
+    }
+}
+//
+impl<T: RunEndElement> Clone for RunEndArraySlice<'_, T>
+where
+    ArrayBackend<T::Values>: TypedBackend<T::Values>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            run_ends: self.run_ends,
+            values: self.values,
+            first_run: self.first_run,
+            range: self.range.clone(),
+        }
+    }
+}
+//
+impl<T: RunEndElement> Debug for RunEndArraySlice<'_, T>
+where
+    ArrayBackend<T::Values>: TypedBackend<T::Values>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.runs()).finish()
+    }
+}
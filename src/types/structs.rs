@@ -0,0 +1,365 @@
+//! Strongly typed interface to arrow-rs' [`DataType::Struct`], for Rust structs
+//!
+//! Rust structs can be used as array elements by deriving [`ArrayElement`] for
+//! them, which implements the [`StructElement`] trait. They are stored as
+//! struct arrays whose fields are named after the fields of the Rust struct,
+//! and bulk-inserted using a generated columnar slice type, named after the
+//! struct with a `Slice` suffix, which holds one
+//! [`ArrayElement::Slice`] per field.
+//!
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+//! # use arrow_typing::{ArrayElement, OptionSlice, TypedBuilder};
+//! # use arrow_typing::types::{bytes::StringSlice, primitive::Date64};
+//! #[derive(ArrayElement, Clone, Debug, PartialEq)]
+//! pub struct Trade {
+//!     ts: Date64,
+//!     venue: String,
+//!     px: f64,
+//!     qty: Option<u32>,
+//! }
+//!
+//! let mut builder = TypedBuilder::<Trade>::new();
+//! builder.push(Trade {
+//!     ts: Date64::from(1_700_000_000_000),
+//!     venue: "XNAS".to_owned(),
+//!     px: 101.5,
+//!     qty: Some(30),
+//! })?;
+//! builder.extend_from_slice(TradeSlice {
+//!     ts: &[Date64::from(1_700_000_001_000), Date64::from(1_700_000_002_000)],
+//!     venue: StringSlice {
+//!         offsets: &[0, 4, 8],
+//!         values: "XNASXPAR",
+//!     },
+//!     px: &[101.25, 101.75],
+//!     qty: OptionSlice {
+//!         values: &[10, 0],
+//!         is_valid: &[true, false],
+//!     },
+//! })?;
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(
+//!     array.value(2),
+//!     Trade {
+//!         ts: Date64::from(1_700_000_002_000),
+//!         venue: "XPAR".to_owned(),
+//!         px: 101.75,
+//!         qty: None,
+//!     }
+//! );
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Arrow field names and metadata can be adjusted using `rename` and `metadata`
+//! attributes. Field names are otherwise taken from the Rust field names.
+//!
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+//! # use arrow_typing::ArrayElement;
+//! use arrow_schema::{DataType, Field};
+//! use std::collections::HashMap;
+//!
+//! #[derive(ArrayElement, Debug)]
+//! struct Quote {
+//!     #[array_element(rename = "bid_price")]
+//!     bid: f32,
+//!     #[array_element(metadata("unit" = "USD", "source" = "exchange"))]
+//!     ask: f32,
+//! }
+//!
+//! let DataType::Struct(fields) = Quote::data_type(&((), ())) else {
+//!     unreachable!()
+//! };
+//! assert_eq!(fields[0].as_ref(), &Field::new("bid_price", DataType::Float32, false));
+//! assert_eq!(fields[1].name(), "ask");
+//! assert_eq!(
+//!     fields[1].metadata(),
+//!     &HashMap::from([
+//!         ("unit".to_string(), "USD".to_string()),
+//!         ("source".to_string(), "exchange".to_string()),
+//!     ])
+//! );
+//! ```
+//!
+//! The derive macro supports non-generic structs with named fields, of which
+//! there must be between 1 and 12. Struct fields are written by reference and
+//! read back by value, so their type must implement [`StructField`], like
+//! primitive types, booleans, `String`, `Vec<u8>`, fixed-size arrays, tuples
+//! of such types, other structs and enums that implement [`Clone`], and
+//! options thereof.
+//!
+//! Like [tuples](super::tuple), structs can be made nullable as a whole by
+//! using `Option<T>`, and their insertions return a `Result`. Structs are read
+//! out by value, whereas their bulk readout layout is the tuple of the [bulk
+//! readout layouts](crate::TypedArray::as_slice) of their fields. Struct
+//! arrays can only be read out as a struct type whose Arrow field names match
+//! theirs.
+
+use super::{
+    fixed_size_list::FixedSizeListItem,
+    primitive::{
+        Date32, Date64, Decimal128, Decimal256, Duration, IntervalDayTime, IntervalMonthDayNano,
+        IntervalYearMonth, Microsecond, Millisecond, Nanosecond, Null, RuntimeTimeZone, Second,
+        Time, TimeUnit, TimeZone, Timestamp,
+    },
+    tuple::{for_each_tuple, TupleElement},
+};
+use crate::{
+    array::{backend::TypedBackend, ArrayBackend, ArrayValue},
+    builder::backend::StructBuilder,
+    ArrayElement, ElementSlice, OptionSlice,
+};
+use arrow_array::StructArray;
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use half::f16;
+use std::fmt::Debug;
+
+/// Rust struct which is stored as a struct array
+///
+/// This trait should not be implemented manually, but via
+/// `#[derive(ArrayElement)]`. See the [module-level documentation](self) for
+/// more information.
+pub trait StructElement: Debug + Send + Sync + Sized + 'static {
+    /// Tuple of the field types, in declaration order
+    type Fields: TupleElement;
+
+    /// Columnar slice of structs, with one slice per field
+    type Slice<'a>: ElementSlice;
+
+    /// Arrow name of each field
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// Arrow metadata of each field, as key-value pairs
+    const FIELD_METADATA: &'static [&'static [(&'static str, &'static str)]];
+
+    /// View the fields of a struct as the values that are written to an array
+    fn as_fields(&self) -> <Self::Fields as ArrayElement>::Value<'_>;
+
+    /// Assemble a struct from the values of its fields in an array
+    fn from_fields(fields: ArrayValue<'_, Self::Fields>) -> Self
+    where
+        ArrayBackend<Self::Fields>: TypedBackend<Self::Fields>;
+
+    /// Split a columnar slice of structs into its field slices
+    fn slice_fields(slice: Self::Slice<'_>) -> <Self::Fields as ArrayElement>::Slice<'_>;
+}
+
+// SAFETY: Struct elements are stored using a StructBuilder, and can therefore
+//         not be primitive types, whose builder is a PrimitiveBuilder.
+unsafe impl<T: StructElement> ArrayElement for T {
+    type BuilderBackend = StructBuilder<<T::Fields as TupleElement>::Builders>;
+    type Value<'a> = Self;
+    type Slice<'a> = T::Slice<'a>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = false;
+}
+//
+// SAFETY: Option is not a primitive type and is therefore not affected by the
+//         safety precondition of ArrayElement
+unsafe impl<T: StructElement> ArrayElement for Option<T> {
+    type BuilderBackend = StructBuilder<<T::Fields as TupleElement>::Builders>;
+    type Value<'a> = Self;
+    type Slice<'a> = OptionSlice<'a, T>;
+    type PushResult = Result<(), ArrowError>;
+    type ExtendFromSliceResult = Result<(), ArrowError>;
+    const NULLABLE: bool = true;
+}
+
+/// Readout value of the fields of a [`StructElement`]
+#[doc(hidden)]
+pub type FieldValues<'a, T> = ArrayValue<'a, <T as StructElement>::Fields>;
+
+/// [`ArrayElement`] type that can be used as the type of a [`StructElement`]
+/// field
+///
+/// Struct fields are written to arrays by reference, as the
+/// [`Value`](ArrayElement::Value) that they hold, and read back by value, so
+/// their type must be able to hold an element of its own. This is the case of
+/// primitive types, booleans, `String`, `Vec<u8>`, fixed-size arrays, tuples
+/// of such types, other structs and enums that implement [`Clone`], and options
+/// thereof. Types that only exist at the type level, like
+/// [`List`](super::list::List), cannot be used as struct fields.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as the type of a struct element field",
+    label = "this type cannot hold the values of its array elements",
+    note = "struct fields must be primitive types, booleans, `String`, `Vec<u8>`, \
+            fixed-size arrays, tuples of such types, `Clone` structs or enums, or options thereof"
+)]
+pub trait StructField: ArrayElement
+where
+    ArrayBackend<Self>: TypedBackend<Self>,
+{
+    /// View a struct field as the value that is written to an array
+    fn as_value(&self) -> Self::Value<'_>;
+
+    /// Turn a value that was read from an array back into a struct field
+    fn from_array_value(value: ArrayValue<'_, Self>) -> Self;
+}
+
+// Enable struct fields whose values are written and read by copy
+macro_rules! impl_copy_field {
+    ($([$($generics:tt)*] $field:ty),*) => {
+        $(
+            impl<$($generics)*> StructField for $field {
+                #[inline]
+                fn as_value(&self) -> Self {
+                    *self
+                }
+
+                #[inline]
+                fn from_array_value(value: Self) -> Self {
+                    value
+                }
+            }
+            //
+            impl<$($generics)*> StructField for Option<$field> {
+                #[inline]
+                fn as_value(&self) -> Self {
+                    *self
+                }
+
+                #[inline]
+                fn from_array_value(value: Self) -> Self {
+                    value
+                }
+            }
+        )*
+    };
+}
+//
+impl_copy_field!(
+    [] bool,
+    [] Date32,
+    [] Date64,
+    [const P: u8, const S: i8] Decimal128<P, S>,
+    [const P: u8, const S: i8] Decimal256<P, S>,
+    [] Duration<Microsecond>,
+    [] Duration<Millisecond>,
+    [] Duration<Nanosecond>,
+    [] Duration<Second>,
+    [] f16,
+    [] f32,
+    [] f64,
+    [] i8,
+    [] i16,
+    [] i32,
+    [] i64,
+    [] IntervalDayTime,
+    [] IntervalMonthDayNano,
+    [] IntervalYearMonth,
+    [] Time<Millisecond>,
+    [] Time<Second>,
+    [] Time<Microsecond>,
+    [] Time<Nanosecond>,
+    [Unit: TimeUnit + Copy] Timestamp<Unit, RuntimeTimeZone>,
+    [Unit: TimeUnit + Copy, Tz: TimeZone + Copy] Timestamp<Unit, Tz>,
+    [] u8,
+    [] u16,
+    [] u32,
+    [] u64,
+    [const N: usize] [u8; N],
+    [T: FixedSizeListItem, const N: usize] [T; N]
+);
+//
+impl StructField for Null {
+    #[inline]
+    fn as_value(&self) -> Self {
+        *self
+    }
+
+    #[inline]
+    fn from_array_value(value: Self) -> Self {
+        value
+    }
+}
+
+// Enable struct fields whose values are written and read as borrowed slices
+macro_rules! impl_owned_field {
+    ($($field:ty => $borrowed:ty),*) => {
+        $(
+            impl StructField for $field {
+                #[inline]
+                fn as_value(&self) -> &$borrowed {
+                    self
+                }
+
+                #[inline]
+                fn from_array_value(value: &$borrowed) -> Self {
+                    value.to_owned()
+                }
+            }
+            //
+            impl StructField for Option<$field> {
+                #[inline]
+                fn as_value(&self) -> Option<&$borrowed> {
+                    self.as_deref()
+                }
+
+                #[inline]
+                fn from_array_value(value: Option<&$borrowed>) -> Self {
+                    value.map(ToOwned::to_owned)
+                }
+            }
+        )*
+    };
+}
+//
+impl_owned_field!(String => str, Vec<u8> => [u8]);
+
+// Enable tuple struct fields
+macro_rules! impl_tuple_field {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t: StructField),+> StructField for ($($t,)+)
+        where
+            $(ArrayBackend<$t>: TypedBackend<$t>,)+
+        {
+            #[inline]
+            fn as_value(&self) -> Self::Value<'_> {
+                ($(self.$idx.as_value(),)+)
+            }
+
+            #[inline]
+            fn from_array_value(value: ($(ArrayValue<'_, $t>,)+)) -> Self {
+                ($($t::from_array_value(value.$idx),)+)
+            }
+        }
+        //
+        impl<$($t: StructField),+> StructField for Option<($($t,)+)>
+        where
+            $(ArrayBackend<$t>: TypedBackend<$t>,)+
+        {
+            #[inline]
+            fn as_value(&self) -> Self::Value<'_> {
+                self.as_ref().map(|value| ($(value.$idx.as_value(),)+))
+            }
+
+            #[inline]
+            fn from_array_value(value: Option<($(ArrayValue<'_, $t>,)+)>) -> Self {
+                value.map(|value| ($($t::from_array_value(value.$idx),)+))
+            }
+        }
+    };
+}
+//
+for_each_tuple!(impl_tuple_field);
+
+// Enable nullable nested struct fields, which are written and read by value
+impl<T: StructElement + Clone> StructField for Option<T>
+where
+    for<'a> StructArray: TypedBackend<Option<T>, Value<'a> = Option<T>>,
+{
+    #[inline]
+    fn as_value(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn from_array_value(value: Self) -> Self {
+        value
+    }
+}
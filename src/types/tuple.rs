@@ -0,0 +1,175 @@
+//! Strongly typed interface to arrow-rs' [`DataType::Struct`]
+//!
+//! Tuples of up to 12 [`ArrayElement`] types can be used as array elements.
+//! They are stored as struct arrays, whose fields are named after the position
+//! of the matching tuple element (`"0"`, `"1"`, ...). Tuples are written and
+//! read element by element, and bulk-manipulated in a columnar fashion, with
+//! one [`ArrayElement::Slice`] per tuple element, so that `(T, U)` is
+//! bulk-inserted from `(&[T], &[U])` slices.
+//!
+//! ```rust
+//! # use arrow_typing::{ArrayElement, TypedBuilder, types::bytes::StringSlice};
+//! use arrow_schema::{DataType, Field};
+//!
+//! // Word counts
+//! let mut builder = TypedBuilder::<(String, u32)>::new();
+//! builder.push(("hello", 3))?;
+//! builder.extend_from_slice((
+//!     StringSlice {
+//!         offsets: &[0, 5, 8],
+//!         values: "worldfoo",
+//!     },
+//!     &[2, 1][..],
+//! ))?;
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(array.value(0), ("hello", 3));
+//! assert_eq!(array.value(2), ("foo", 1));
+//! assert_eq!(
+//!     <(String, u32)>::data_type(&((), ())),
+//!     DataType::Struct(
+//!         vec![
+//!             Field::new("0", DataType::Utf8, false),
+//!             Field::new("1", DataType::UInt32, false),
+//!         ]
+//!         .into()
+//!     )
+//! );
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Because the field slices of a bulk insertion may not have the same length,
+//! and because some tuple elements may reject the values that they are given,
+//! tuple insertions return a `Result`. If any tuple element is rejected,
+//! nothing is inserted.
+//!
+//! Nullability can be specified at the struct level by using `Option<(T, U)>`,
+//! at the field level by using `(Option<T>, Option<U>)`, or both.
+//!
+//! ```rust
+//! # use arrow_typing::{OptionSlice, TypedBuilder};
+//! let mut builder = TypedBuilder::<Option<(f32, Option<bool>)>>::new();
+//! builder.push(Some((1.0, None)))?;
+//! builder.push(None)?;
+//! builder.extend_from_slice(OptionSlice {
+//!     values: (
+//!         &[2.0, 3.0][..],
+//!         OptionSlice {
+//!             values: &[true, false][..],
+//!             is_valid: &[true, true],
+//!         },
+//!     ),
+//!     is_valid: &[true, false],
+//! })?;
+//! let array = builder.finish();
+//! assert_eq!(array.value(0), Some((1.0, None)));
+//! assert_eq!(array.value(1), None);
+//! assert_eq!(array.value(2), Some((2.0, Some(true))));
+//! assert_eq!(array.value(3), None);
+//!
+//! // Field slices must have the same length
+//! let mut builder = TypedBuilder::<(f32, bool)>::new();
+//! assert!(builder.extend_from_slice((&[1.0, 2.0][..], &[true][..])).is_err());
+//! assert!(builder.is_empty());
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Tuples are read out using the [bulk readout
+//! layout](crate::TypedArray::as_slice) of their elements, with one readout
+//! slice per tuple element.
+
+use crate::{
+    builder::backend::{StructBuilder, TupleBuilders},
+    ArrayElement, ElementSlice, OptionSlice,
+};
+use arrow_schema::ArrowError;
+#[cfg(doc)]
+use arrow_schema::DataType;
+use std::ops::Range;
+
+/// Invoke a macro once per supported tuple arity
+///
+/// The macro is invoked with a comma-separated list of `Type: index` pairs,
+/// like `A: 0, B: 1, C: 2`, where `Type` is a generic parameter name and
+/// `index` is the position of the matching tuple element.
+macro_rules! for_each_tuple {
+    ($mac:ident) => {
+        $crate::types::tuple::for_each_tuple!(
+            $mac; [];
+            A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11
+        );
+    };
+    ($mac:ident; [$($done:tt)*]; $t:ident: $idx:tt $(, $rest_t:ident: $rest_idx:tt)*) => {
+        $mac!($($done)* $t: $idx);
+        $crate::types::tuple::for_each_tuple!($mac; [$($done)* $t: $idx,]; $($rest_t: $rest_idx),*);
+    };
+    ($mac:ident; [$($done:tt)*];) => {};
+}
+pub(crate) use for_each_tuple;
+
+/// Tuple of [`ArrayElement`]s, which is stored as a struct array
+///
+/// This trait is implemented for every tuple of up to 12 elements. It is used
+/// to specify the fields of [`StructElement`](super::structs::StructElement)s.
+pub trait TupleElement:
+    Sized
+    + ArrayElement<
+        BuilderBackend = StructBuilder<Self::Builders>,
+        PushResult = Result<(), ArrowError>,
+        ExtendFromSliceResult = Result<(), ArrowError>,
+    >
+{
+    /// Tuple of the builder backends of the tuple elements
+    #[doc(hidden)]
+    type Builders: TupleBuilders<Self>;
+}
+
+// Enable strongly typed arrays of tuples
+macro_rules! impl_tuple_element {
+    ($($t:ident: $idx:tt),+) => {
+        // SAFETY: Tuples are not primitive types and are therefore not
+        //         affected by the safety precondition of ArrayElement
+        unsafe impl<$($t: ArrayElement),+> ArrayElement for ($($t,)+) {
+            type BuilderBackend = StructBuilder<($($t::BuilderBackend,)+)>;
+            type Value<'a> = ($($t::Value<'a>,)+);
+            type Slice<'a> = ($($t::Slice<'a>,)+);
+            type PushResult = Result<(), ArrowError>;
+            type ExtendFromSliceResult = Result<(), ArrowError>;
+            const NULLABLE: bool = false;
+        }
+        //
+        impl<$($t: ArrayElement),+> TupleElement for ($($t,)+) {
+            type Builders = ($($t::BuilderBackend,)+);
+        }
+        //
+        // SAFETY: Option is not a primitive type and is therefore not affected
+        //         by the safety precondition of ArrayElement
+        unsafe impl<$($t: ArrayElement),+> ArrayElement for Option<($($t,)+)> {
+            type BuilderBackend = StructBuilder<($($t::BuilderBackend,)+)>;
+            type Value<'a> = Option<($($t::Value<'a>,)+)>;
+            type Slice<'a> = OptionSlice<'a, ($($t,)+)>;
+            type PushResult = Result<(), ArrowError>;
+            type ExtendFromSliceResult = Result<(), ArrowError>;
+            const NULLABLE: bool = true;
+        }
+        //
+        /// Tuples of slices are the bulk insertion layout of tuples
+        impl<$($t: ElementSlice),+> ElementSlice for ($($t,)+) {
+            fn slice_len(&self) -> Result<usize, ArrowError> {
+                let lens = [$(self.$idx.slice_len()?),+];
+                if lens.iter().any(|&len| len != lens[0]) {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "tuple element slices must have the same length, got lengths {lens:?}"
+                    )));
+                }
+                Ok(lens[0])
+            }
+
+            fn slice_range(self, range: Range<usize>) -> Self {
+                ($(self.$idx.slice_range(range.clone()),)+)
+            }
+        }
+    };
+}
+//
+for_each_tuple!(impl_tuple_element);
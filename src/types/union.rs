@@ -0,0 +1,216 @@
+//! Strongly typed interface to arrow-rs' [`DataType::Union`], for Rust enums
+//!
+//! Rust enums can be used as array elements by deriving [`ArrayElement`] for
+//! them, which implements the [`UnionElement`] trait. They are stored as union
+//! arrays with one child array per enum variant, named after the variant.
+//! Variants can carry no data, which is stored as a [`Null`] child, a single
+//! value of some [`ArrayElement`] type `T`, which is stored as a child of `T`,
+//! or several values, which are stored as a child of the matching
+//! [tuple](super::tuple) type.
+//!
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+//! # use arrow_typing::{ArrayElement, TypedBuilder};
+//! #[derive(ArrayElement, Clone, Debug, PartialEq)]
+//! enum Event {
+//!     Heartbeat,
+//!     Trade(f64, u32),
+//!     Cancel(u64),
+//! }
+//!
+//! let mut builder = TypedBuilder::<Event>::new();
+//! builder.push(Event::Trade(101.5, 30))?;
+//! builder.extend_from_slice(&[Event::Heartbeat, Event::Cancel(42)])?;
+//! let array = builder.finish();
+//! assert_eq!(array.len(), 3);
+//! assert_eq!(array.value(0), Event::Trade(101.5, 30));
+//! assert_eq!(array.value(2), Event::Cancel(42));
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+//!
+//! Enums are stored as dense unions by default, where each child array only
+//! contains the values of the matching variant. They can be stored as sparse
+//! unions, where every child array has the length of the union, by adding an
+//! `#[array_element(sparse)]` attribute to the enum.
+//!
+//! The union type id of a variant is its position in the enum declaration by
+//! default. It can be pinned using a `type_id` attribute, so that reordering
+//! or adding variants does not change how existing variants are stored.
+//! Variants can also be renamed and given Arrow metadata, like struct fields.
+//!
+#![cfg_attr(feature = "derive", doc = "```rust")]
+#![cfg_attr(not(feature = "derive"), doc = "```rust,ignore")]
+//! # use arrow_typing::ArrayElement;
+//! use arrow_schema::{DataType, Field, UnionFields, UnionMode};
+//!
+//! #[derive(ArrayElement, Clone, Debug)]
+//! #[array_element(sparse)]
+//! enum Value {
+//!     #[array_element(type_id = 5, rename = "int")]
+//!     Integer(i64),
+//!     #[array_element(type_id = 2)]
+//!     Real(f64),
+//! }
+//!
+//! assert_eq!(
+//!     Value::data_type(&((), ())),
+//!     DataType::Union(
+//!         UnionFields::new(
+//!             [5, 2],
+//!             [
+//!                 // Nullable, as explained below
+//!                 Field::new("int", DataType::Int64, true),
+//!                 Field::new("Real", DataType::Float64, false),
+//!             ]
+//!         ),
+//!         UnionMode::Sparse
+//!     )
+//! );
+//! ```
+//!
+//! The derive macro supports non-generic enums with between 1 and 12 variants,
+//! which are unit variants or tuple variants. Enums must implement [`Clone`],
+//! as they are bulk-inserted from slices of enums. Variant values are written
+//! and read by value, so their type must be an [`ArrayElement`] type whose
+//! [`Value`](ArrayElement::Value) and readout value are the type itself.
+//! Variants with named fields can be modeled as a tuple variant that holds a
+//! [struct](super::structs) instead.
+//!
+//! Arrow unions do not have a validity bitmap, so enums cannot be made nullable
+//! as a whole. Use a unit variant to model missing values instead. When an
+//! enum is used inside of a nullable container, like the field of an
+//! `Option<(T, U)>`, the null entries of the container are stored as nulls of
+//! the first nullable variant. If no variant is nullable, they are stored as
+//! nulls of the first variant, whose field is therefore declared nullable. Such
+//! nulls cannot be read back as values of a non-nullable variant, so arrays
+//! that contain them cannot be read back from an untyped arrow array, and
+//! giving such enums a unit variant is recommended.
+
+#[cfg(doc)]
+use super::primitive::Null;
+use super::tuple::{for_each_tuple, TupleElement};
+use crate::{
+    array::{backend::TypedBackend, ArrayBackend, ArraySlice, ArrayValue},
+    builder::backend::{UnionBuilder, UnionBuilders},
+    ArrayElement,
+};
+#[cfg(doc)]
+use arrow_schema::DataType;
+use arrow_schema::{ArrowError, UnionMode};
+use std::fmt::{self, Debug};
+
+/// Rust enum which is stored as a union array
+///
+/// This trait should not be implemented manually, but via
+/// `#[derive(ArrayElement)]`. See the [module-level documentation](self) for
+/// more information.
+pub trait UnionElement:
+    ArrayElement<
+        BuilderBackend = UnionBackend<Self>,
+        PushResult = Result<(), ArrowError>,
+        ExtendFromSliceResult = Result<(), ArrowError>,
+    > + Clone
+    + Sized
+{
+    /// Tuple of the variant types, in declaration order
+    type Variants: UnionVariants;
+
+    /// Arrow name of each variant
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Arrow metadata of each variant, as key-value pairs
+    const VARIANT_METADATA: &'static [&'static [(&'static str, &'static str)]];
+
+    /// Union type id of each variant
+    const TYPE_IDS: &'static [i8];
+
+    /// Union layout
+    const MODE: UnionMode;
+
+    /// Split an enum into the value of its variant
+    fn into_variant<'a>(self) -> <Self::Variants as UnionVariants>::Values<'a>;
+
+    /// Enum that is inserted by [`TypedBuilder::push()`](crate::TypedBuilder::push)
+    fn from_value(value: Self::Value<'_>) -> Self;
+
+    /// Borrowed version of [`from_value()`](Self::from_value)
+    fn from_value_ref<'b>(value: &'b Self::Value<'_>) -> &'b Self;
+
+    /// Enums that are inserted by
+    /// [`TypedBuilder::extend_from_slice()`](crate::TypedBuilder::extend_from_slice)
+    fn slice_values(slice: Self::Slice<'_>) -> &[Self];
+
+    /// Assemble an enum from the value of its variant in an array
+    fn from_variant(variant: <Self::Variants as UnionVariants>::ArrayValues<'_>) -> Self;
+}
+
+/// Builder backend of [`UnionElement`]s
+#[doc(hidden)]
+pub type UnionBackend<T> = UnionBuilder<<<T as UnionElement>::Variants as TupleElement>::Builders>;
+
+/// Tuple of the variant types of a [`UnionElement`]
+///
+/// This trait is implemented for every tuple of up to 12 [`ArrayElement`]s.
+pub trait UnionVariants: TupleElement<Builders: UnionBuilders<Self>> {
+    /// Value of a single variant, as a tuple where the option that matches
+    /// the variant is `Some` and all other options are `None`
+    type Values<'a>: Debug;
+
+    /// Readout value of a single variant, in the same format as `Values`
+    type ArrayValues<'a>: Debug;
+
+    /// Bulk readout of the child arrays of a union, with one
+    /// [bulk readout layout](crate::TypedArray::as_slice) per variant
+    type ArraySlices<'a>: Debug;
+
+    /// Truth that each variant type is nullable
+    #[doc(hidden)]
+    const NULLABLE: &'static [bool];
+}
+
+// Enable tuples of variant types
+macro_rules! impl_union_variants {
+    ($($t:ident: $idx:tt),+) => {
+        impl<$($t: ArrayElement),+> UnionVariants for ($($t,)+)
+        where
+            $(ArrayBackend<$t>: TypedBackend<$t>,)+
+        {
+            type Values<'a> = ($(Option<$t::Value<'a>>,)+);
+            type ArrayValues<'a> = ($(Option<ArrayValue<'a, $t>>,)+);
+            type ArraySlices<'a> = ($(ArraySlice<'a, $t>,)+);
+            const NULLABLE: &'static [bool] = &[$($t::NULLABLE),+];
+        }
+    };
+}
+//
+for_each_tuple!(impl_union_variants);
+
+/// Columnar view of an array of [`UnionElement`]s
+///
+/// This is the [bulk readout layout](crate::TypedArray::as_slice) of enums.
+pub struct UnionArraySlice<'a, T: UnionElement> {
+    /// Type id of each element, which identifies its variant
+    pub type_ids: &'a [i8],
+
+    /// Offset of each element within the child array of its variant, or
+    /// `None` if the union is sparse, in which case this offset is the
+    /// position of the element in the union
+    pub offsets: Option<&'a [i32]>,
+
+    /// Child arrays, in the order of [`UnionElement::Variants`]
+    ///
+    /// For sparse unions, these only contain the elements within the range of
+    /// the union that is being read out. For dense unions, they contain all
+    /// the elements of the child array.
+    pub variants: <T::Variants as UnionVariants>::ArraySlices<'a>,
+}
+//
+impl<T: UnionElement> Debug for UnionArraySlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnionArraySlice")
+            .field("type_ids", &self.type_ids)
+            .field("offsets", &self.offsets)
+            .field("variants", &self.variants)
+            .finish()
+    }
+}
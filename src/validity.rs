@@ -1,16 +1,28 @@
 //! Strongly typed array validity bitmaps
 
+use arrow_buffer::BooleanBuffer;
 use std::{
     cmp::Ordering,
+    hash::{Hash, Hasher},
     iter::{FusedIterator, Take},
+    ops::Range,
 };
 
 /// Strongly typed view of an Arrow validity bitmap
-#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+///
+/// Comparisons and hashing only consider the validity bits of the elements,
+/// not the underlying bitmap bytes, so that equal validity taken from
+/// different offsets of a bitmap compares equal.
+#[derive(Copy, Clone, Debug, Default)]
 pub struct ValiditySlice<'array> {
     /// Validity bitmap
     bitmap: &'array [u8],
 
+    /// Number of leading bits that have no associated array element
+    ///
+    /// Guaranteed to be in `0..=7`, will be 0 when `bitmap` is empty.
+    header_len: u8,
+
     /// Number of trailing bits that have no associated array element
     ///
     /// Guaranteed to be in `0..=7`, will be 0 when `bitmap` is empty.
@@ -30,13 +42,53 @@ impl<'array> ValiditySlice<'array> {
         assert!(trailer_len < 8, "{error}");
         Self {
             bitmap,
+            header_len: 0,
             trailer_len: trailer_len as u8,
         }
     }
 
+    /// Decode a validity slice from an `arrow-rs` boolean buffer
+    ///
+    /// Unlike [`ValiditySlice::new()`], this supports buffers whose first
+    /// element is not aligned on a byte boundary, as found in sliced arrays.
+    pub(crate) fn from_buffer(buffer: &'array BooleanBuffer) -> Self {
+        let start_bit = buffer.offset();
+        Self::from_bits(buffer.values(), start_bit..start_bit + buffer.len())
+    }
+
+    /// Validity slice covering the bits within `bit_range` of `bytes`
+    fn from_bits(bytes: &'array [u8], bit_range: Range<usize>) -> Self {
+        if bit_range.is_empty() {
+            return Self::default();
+        }
+        let end_byte = bit_range.end.div_ceil(8);
+        Self {
+            bitmap: &bytes[bit_range.start / 8..end_byte],
+            header_len: (bit_range.start % 8) as u8,
+            trailer_len: (end_byte * 8 - bit_range.end) as u8,
+        }
+    }
+
+    /// Sub-slice covering the elements within `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range is out of bounds"
+        );
+        let header_len = self.header_len as usize;
+        Self::from_bits(
+            self.bitmap,
+            header_len + range.start..header_len + range.end,
+        )
+    }
+
     /// Number of elements in the validity bitmap
     pub const fn len(&self) -> usize {
-        self.bitmap.len() * 8 - self.trailer_len as usize
+        self.bitmap.len() * 8 - self.header_len as usize - self.trailer_len as usize
     }
 
     /// Returns `true` if the source array contains no element.
@@ -59,7 +111,8 @@ impl<'array> ValiditySlice<'array> {
     /// `index` must be in bounds or undefined behavior will ensue.
     #[inline]
     pub unsafe fn get_unchecked(&self, index: usize) -> bool {
-        self.bitmap.get_unchecked(index / 8) & (1 << (index % 8)) != 0
+        let bit = index + self.header_len as usize;
+        self.bitmap.get_unchecked(bit / 8) & (1 << (bit % 8)) != 0
     }
 
     /// Value of the `index`-th, with panic-based bounds checking
@@ -79,7 +132,7 @@ impl<'array> ValiditySlice<'array> {
         (BitmapIter {
             bytes,
             current_byte,
-            bit: 1,
+            bit: 1 << self.header_len,
         })
         .take(self.len())
     }
@@ -93,6 +146,35 @@ impl<'slice> IntoIterator for &'slice ValiditySlice<'slice> {
     }
 }
 //
+impl PartialEq for ValiditySlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+//
+impl Eq for ValiditySlice<'_> {}
+//
+impl Hash for ValiditySlice<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for bit in self.iter() {
+            bit.hash(state);
+        }
+    }
+}
+//
+impl PartialOrd for ValiditySlice<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+//
+impl Ord for ValiditySlice<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+//
 impl PartialEq<&[bool]> for ValiditySlice<'_> {
     fn eq(&self, other: &&[bool]) -> bool {
         self.iter().eq(other.iter().copied())
@@ -147,6 +229,7 @@ impl<'bytes> Iterator for BitmapIter<'bytes> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::vec_and_subslice;
     use proptest::prelude::*;
 
     /// Generate validity bitmap building blocks
@@ -194,6 +277,87 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_buffer((bits, offset, len) in vec_and_subslice::<bool>()) {
+            let buffer = BooleanBuffer::from(bits.clone()).slice(offset, len);
+            let validity = ValiditySlice::from_buffer(&buffer);
+            let expected = &bits[offset..offset + len];
+            prop_assert_eq!(validity.len(), len);
+            prop_assert_eq!(validity.is_empty(), len == 0);
+            prop_assert_eq!(validity, expected);
+            for (idx, &bit) in expected.iter().enumerate() {
+                prop_assert_eq!(validity.get(idx), Some(bit));
+            }
+            prop_assert_eq!(validity.get(len), None);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn slice(
+            (bits, offset, len) in vec_and_subslice::<bool>(),
+            (start, end) in (0..=32usize, 0..=32usize),
+        ) {
+            let buffer = BooleanBuffer::from(bits.clone()).slice(offset, len);
+            let validity = ValiditySlice::from_buffer(&buffer);
+            let expected = &bits[offset..offset + len];
+            let result = std::panic::catch_unwind(|| validity.slice(start..end));
+            let Some(expected) = expected.get(start..end) else {
+                prop_assert!(result.is_err());
+                return Ok(());
+            };
+            let slice = result.unwrap();
+            prop_assert_eq!(slice.len(), expected.len());
+            prop_assert_eq!(slice.is_empty(), expected.is_empty());
+            prop_assert_eq!(slice, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn compare_across_offsets(
+            (bits, offset1, len) in vec_and_subslice::<bool>(),
+            shift in 1..=16usize,
+        ) {
+            // Put the same bits at two different offsets of a larger bitmap
+            let padding = vec![true; shift];
+            let shifted = padding.iter().chain(&bits).copied().collect::<Vec<_>>();
+            let buffer1 = BooleanBuffer::from(bits.clone()).slice(offset1, len);
+            let buffer2 = BooleanBuffer::from(shifted).slice(offset1 + shift, len);
+            let validity1 = ValiditySlice::from_buffer(&buffer1);
+            let validity2 = ValiditySlice::from_buffer(&buffer2);
+            prop_assert_eq!(validity1, validity2);
+            prop_assert_eq!(validity1.cmp(&validity2), Ordering::Equal);
+
+            let hash = |validity: &ValiditySlice| {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                validity.hash(&mut hasher);
+                hasher.finish()
+            };
+            prop_assert_eq!(hash(&validity1), hash(&validity2));
+
+            let expected = &bits[offset1..offset1 + len];
+            let shorter = validity1.slice(0..len.saturating_sub(1));
+            prop_assert_eq!(shorter == validity1, expected.is_empty());
+            prop_assert_eq!(
+                validity1.cmp(&shorter),
+                expected.iter().cmp(&expected[..len.saturating_sub(1)])
+            );
+        }
+    }
+
+    #[test]
+    fn compare_ignores_padding_bits() {
+        // Same [valid, valid] readout, from different bit offsets of one byte
+        let bitmap = [0b0011_0110];
+        let validity1 = ValiditySlice::from_bits(&bitmap, 1..3);
+        let validity2 = ValiditySlice::from_bits(&bitmap, 4..6);
+        assert_eq!(validity1, &[true, true][..]);
+        assert_eq!(validity1, validity2);
+        assert_ne!(validity1, ValiditySlice::from_bits(&bitmap, 0..2));
+    }
+
     /// Generate a validity bitmap, its unpacked bits, and an index into it
     fn bitmap_bits_index() -> impl Strategy<Value = ((Vec<u8>, usize), Vec<bool>, usize)> {
         let bits = any::<Vec<bool>>();